@@ -49,6 +49,27 @@ fn main() {
         &mut output,
         &[],
     );
+    visit_dirs(
+        Path::new("../software-renderer/src"),
+        "raui_software_renderer",
+        Some("software"),
+        &mut output,
+        &["canvas.rs", "raster.rs", "resources.rs"],
+    );
+    visit_dirs(
+        Path::new("../svg-renderer/src"),
+        "raui_svg_renderer",
+        Some("svg"),
+        &mut output,
+        &[],
+    );
+    visit_dirs(
+        Path::new("../terminal-renderer/src"),
+        "raui_terminal_renderer",
+        Some("terminal"),
+        &mut output,
+        &["buffer.rs", "interactions.rs", "text.rs"],
+    );
     visit_dirs(
         Path::new("../json-renderer/src"),
         "raui_json_renderer",
@@ -76,6 +76,7 @@ fn app(mut ctx: WidgetContext) -> WidgetNode {
                     from: -10.0,
                     to: 10.0,
                     direction: SliderViewDirection::BottomToTop,
+                    ..Default::default()
                 }),
         )
         .listed_slot(
@@ -98,6 +99,7 @@ fn app(mut ctx: WidgetContext) -> WidgetNode {
                     from: -3.0,
                     to: 7.0,
                     direction: SliderViewDirection::RightToLeft,
+                    ..Default::default()
                 })),
         )
         .into()
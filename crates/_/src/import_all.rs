@@ -1,18 +1,27 @@
 #![allow(ambiguous_glob_reexports)]
 #![allow(unused_variables)]
+pub use raui_core::accessibility::*;
 pub use raui_core::animator::*;
 pub use raui_core::application::*;
+pub use raui_core::binary::*;
+pub use raui_core::binding::*;
+pub use raui_core::hot_reload::*;
 pub use raui_core::interactive::*;
 pub use raui_core::interactive::default_interactions_engine::*;
 pub use raui_core::layout::*;
 pub use raui_core::layout::default_layout_engine::*;
 pub use raui_core::*;
+pub use raui_core::markup::*;
 pub use raui_core::messenger::*;
 pub use raui_core::props::*;
+pub use raui_core::registration::*;
 pub use raui_core::renderer::*;
 pub use raui_core::signals::*;
 pub use raui_core::state::*;
+pub use raui_core::style::*;
+pub use raui_core::template::*;
 pub use raui_core::tester::*;
+pub use raui_core::validation::*;
 pub use raui_core::view_model::*;
 pub use raui_core::widget::*;
 pub use raui_core::widget::component::*;
@@ -119,6 +128,12 @@ pub use raui_immediate::*;
 pub use raui_immediate_widgets::*;
 #[cfg(feature = "tesselate")]
 pub use raui_tesselate_renderer::*;
+#[cfg(feature = "software")]
+pub use raui_software_renderer::*;
+#[cfg(feature = "svg")]
+pub use raui_svg_renderer::*;
+#[cfg(feature = "terminal")]
+pub use raui_terminal_renderer::*;
 #[cfg(feature = "json")]
 pub use raui_json_renderer::*;
 #[cfg(feature = "app")]
@@ -132,6 +132,22 @@ pub mod renderer {
     pub mod json {
         pub use raui_json_renderer::*;
     }
+
+    #[cfg(feature = "software")]
+    pub mod software {
+        pub use raui_software_renderer::*;
+    }
+
+    #[cfg(feature = "svg")]
+    pub mod svg {
+        pub use raui_svg_renderer::*;
+    }
+
+    #[cfg(feature = "terminal")]
+    pub mod terminal {
+        pub use raui_terminal_renderer::*;
+    }
+
     #[cfg(feature = "tesselate")]
     pub mod tesselate {
         pub use raui_tesselate_renderer::*;
@@ -145,3 +161,32 @@ pub use raui_app as app;
 #[doc(hidden)]
 #[cfg(feature = "import-all")]
 pub mod import_all;
+
+/// Registers components and properties of all enabled RAUI crates, together with ones
+/// collected from [`register_component`][core::register_component] and
+/// [`register_props`][core::register_props] attribute macros.
+///
+/// ```
+/// use raui::core::{
+///     application::Application,
+///     register_component,
+///     widget::{context::WidgetContext, node::WidgetNode},
+/// };
+///
+/// #[register_component]
+/// fn empty(_: WidgetContext) -> WidgetNode {
+///     Default::default()
+/// }
+///
+/// let mut application = Application::default();
+/// application.setup(raui::setup_all);
+/// assert!(application.deserialize_markup("<empty/>").is_ok());
+/// ```
+pub fn setup_all(app: &mut core::application::Application) {
+    raui_core::widget::setup(app);
+    #[cfg(feature = "material")]
+    raui_material::setup(app);
+    #[cfg(feature = "app")]
+    raui_app::setup(app);
+    raui_core::registration::setup(app);
+}
@@ -271,6 +271,7 @@ impl SharedApp {
             missing_texture: self.missing_texutre.as_ref().unwrap(),
             assets: &self.assets,
             clip_stack: Vec::with_capacity(64),
+            blend_stack: Vec::with_capacity(64),
             viewport_height: graphics.state.main_camera.screen_size.y as _,
             projection_view_matrix: matrix,
         };
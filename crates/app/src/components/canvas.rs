@@ -6,6 +6,7 @@ use raui_core::{
     messenger::MessageData,
     pre_hooks,
     props::PropsData,
+    schemars::JsonSchema,
     widget::{
         component::{
             ResizeListenerSignal,
@@ -22,7 +23,9 @@ use serde::{Deserialize, Serialize};
 use spitfire_glow::renderer::GlowTextureFormat;
 use std::sync::Arc;
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
+#[prefab_schema]
 pub struct CanvasProps {
     #[serde(default)]
     pub color: Color,
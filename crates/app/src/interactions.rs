@@ -11,7 +11,7 @@ use raui_core::{
     },
     layout::CoordsMapping,
     widget::{
-        component::interactive::navigation::{NavJump, NavScroll, NavSignal, NavTextChange},
+        component::interactive::navigation::{NavSignal, NavTextChange},
         utils::Vec2,
     },
 };
@@ -102,9 +102,7 @@ impl AppInteractionsEngine {
                         },
                     };
                     self.engine
-                        .interact(Interaction::Navigate(NavSignal::Jump(NavJump::Scroll(
-                            NavScroll::Units(value, true),
-                        ))));
+                        .interact(Interaction::PointerWheel(self.pointer_position, value));
                 }
                 WindowEvent::MouseInput { state, button, .. } => match state {
                     ElementState::Pressed => match button {
@@ -12,7 +12,7 @@ use crate::{
 use bytemuck::{Pod, Zeroable};
 use raui_core::{
     application::Application,
-    widget::{FnWidget, utils::Color},
+    widget::{FnWidget, unit::BlendMode, utils::Color},
 };
 use raui_tesselate_renderer::{TesselateBatch, TesselateBatchConverter, TesselateVertex};
 use spitfire_fontdue::TextVertex;
@@ -81,6 +81,9 @@ impl GlowVertexAttribs for Vertex {
 }
 
 impl TesselateVertex for Vertex {
+    const CAN_MULTIPLY_ALPHA: bool = true;
+    const CAN_CLIP: bool = true;
+
     fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: [f32; 4]) {
         self.position = position;
         self.uv = tex_coord;
@@ -96,6 +99,26 @@ impl TesselateVertex for Vertex {
         self.position[0] = result.x;
         self.position[1] = result.y;
     }
+
+    fn multiply_alpha(&mut self, factor: f32) {
+        self.color[3] *= factor;
+    }
+
+    fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    fn lerp(&self, other: &Self, factor: f32) -> Self {
+        fn lerp<const N: usize>(from: [f32; N], to: [f32; N], factor: f32) -> [f32; N] {
+            std::array::from_fn(|index| from[index] + (to[index] - from[index]) * factor)
+        }
+
+        Self {
+            position: lerp(self.position, other.position, factor),
+            uv: lerp(self.uv, other.uv, factor),
+            color: lerp(self.color, other.color, factor),
+        }
+    }
 }
 
 impl TextVertex<Color> for Vertex {
@@ -116,16 +139,28 @@ pub(crate) struct TesselateToGraphics<'a> {
     missing_texture: &'a Texture,
     assets: &'a AssetsManager,
     clip_stack: Vec<Rect<i32, i32>>,
+    blend_stack: Vec<BlendMode>,
     viewport_height: i32,
     projection_view_matrix: [f32; 16],
 }
 
+impl TesselateToGraphics<'_> {
+    // Screen blending has no fixed-function equivalent here, so it brightens additively.
+    fn blending(&self) -> GlowBlending {
+        match self.blend_stack.last().copied().unwrap_or_default() {
+            BlendMode::Normal => GlowBlending::Alpha,
+            BlendMode::Additive | BlendMode::Screen => GlowBlending::Additive,
+            BlendMode::Multiply => GlowBlending::Multiply,
+        }
+    }
+}
+
 impl TesselateBatchConverter<GraphicsBatch> for TesselateToGraphics<'_> {
     fn convert(&mut self, batch: TesselateBatch) -> Option<GraphicsBatch> {
         match batch {
             TesselateBatch::Color => Some(GraphicsBatch {
                 shader: Some(self.colored_shader.clone()),
-                blending: GlowBlending::Alpha,
+                blending: self.blending(),
                 scissor: self.clip_stack.last().copied(),
                 ..Default::default()
             }),
@@ -141,7 +176,7 @@ impl TesselateBatchConverter<GraphicsBatch> for TesselateToGraphics<'_> {
                             .unwrap_or_else(|| self.missing_texture.clone()),
                         GlowTextureFiltering::Linear,
                     )],
-                    blending: GlowBlending::Alpha,
+                    blending: self.blending(),
                     scissor: self.clip_stack.last().copied(),
                     ..Default::default()
                 })
@@ -149,7 +184,7 @@ impl TesselateBatchConverter<GraphicsBatch> for TesselateToGraphics<'_> {
             TesselateBatch::Text => Some(GraphicsBatch {
                 shader: Some(self.text_shader.clone()),
                 textures: vec![(self.glyphs_texture.clone(), GlowTextureFiltering::Linear)],
-                blending: GlowBlending::Alpha,
+                blending: self.blending(),
                 scissor: self.clip_stack.last().copied(),
                 ..Default::default()
             }),
@@ -206,6 +241,18 @@ impl TesselateBatchConverter<GraphicsBatch> for TesselateToGraphics<'_> {
                 self.clip_stack.pop();
                 None
             }
+            // Layers are not rendered offscreen, so their opacity is left for vertex alpha
+            // fallback and blend mode applies to each of their batches.
+            // There is no stencil buffer to render clip masks with, so renderer clips on CPU.
+            TesselateBatch::ClipMaskPush { .. } | TesselateBatch::ClipMaskPop { .. } => None,
+            TesselateBatch::LayerPush { blend, .. } => {
+                self.blend_stack.push(blend);
+                None
+            }
+            TesselateBatch::LayerPop => {
+                self.blend_stack.pop();
+                None
+            }
             TesselateBatch::Debug => Some(GraphicsBatch {
                 shader: self.debug_shader.cloned(),
                 wireframe: true,
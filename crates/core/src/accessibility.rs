@@ -0,0 +1,458 @@
+//! Accessibility semantics
+//!
+//! Widgets describe what they are to assistive technologies with [`AccessibilityProps`] attached
+//! to their props. After processing, [`Application::accessibility_tree`] combines those with
+//! layout bounds into an [`AccessibilityTree`] that hosts can bridge to platform accessibility
+//! layer, and actions requested by that layer are sent back to widgets as [`AccessibilityAction`]
+//! messages with [`Application::accessibility_action`].
+//!
+//! [`Application::accessibility_tree`]: crate::application::Application::accessibility_tree
+//! [`Application::accessibility_action`]: crate::application::Application::accessibility_action
+
+use crate::{
+    MessageData, PropsData, Scalar,
+    layout::{CoordsMapping, Layout},
+    widget::{WidgetId, utils::Rect},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+fn is_false(v: &bool) -> bool {
+    !*v
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum AccessibilityRole {
+    #[default]
+    Generic,
+    Group,
+    Label,
+    Image,
+    Button,
+    CheckBox,
+    RadioButton,
+    Switch,
+    Slider,
+    TextField,
+    List,
+    ListItem,
+    ScrollView,
+    Dialog,
+    Tooltip,
+    Custom(String),
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AccessibilityRange {
+    #[serde(default)]
+    pub value: Scalar,
+    #[serde(default)]
+    pub min: Scalar,
+    #[serde(default)]
+    pub max: Scalar,
+    /// Amount applied by increment and decrement actions. Zero means 10% of range.
+    #[serde(default)]
+    pub step: Scalar,
+}
+
+impl AccessibilityRange {
+    pub fn step_or_default(&self) -> Scalar {
+        if self.step.abs() > 1.0e-6 {
+            self.step
+        } else {
+            (self.max - self.min) * 0.1
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct AccessibilityStates {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub selected: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub focused: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub pressed: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub disabled: bool,
+    /// Hidden widgets are excluded from the tree, their children are attached to the closest
+    /// visible ancestor.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub hidden: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expanded: Option<bool>,
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct AccessibilityProps {
+    #[serde(default)]
+    pub role: AccessibilityRole,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub label: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<AccessibilityRange>,
+    #[serde(default)]
+    pub states: AccessibilityStates,
+}
+
+impl AccessibilityProps {
+    pub fn new(role: AccessibilityRole) -> Self {
+        Self {
+            role,
+            ..Default::default()
+        }
+    }
+
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn value(mut self, value: impl ToString) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    pub fn range(mut self, range: AccessibilityRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    pub fn states(mut self, states: AccessibilityStates) -> Self {
+        self.states = states;
+        self
+    }
+}
+
+/// Action requested by assistive technology, sent as message to the target widget.
+#[derive(MessageData, Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[message_data(crate::messenger::MessageData)]
+pub enum AccessibilityAction {
+    #[default]
+    None,
+    Focus,
+    Click,
+    ContextClick,
+    Increment,
+    Decrement,
+    SetValue(Scalar),
+    SetText(String),
+    Custom(String),
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccessibilityNode {
+    #[serde(default)]
+    pub id: WidgetId,
+    #[serde(default)]
+    pub props: AccessibilityProps,
+    #[serde(default)]
+    pub bounds: Rect,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityNode {
+    pub fn find(&self, id: &WidgetId) -> Option<&AccessibilityNode> {
+        if &self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// Depth-first iteration over this node and all its descendants.
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    fn virtual_to_real(&mut self, mapping: &CoordsMapping) {
+        self.bounds = mapping.virtual_to_real_rect(self.bounds, false);
+        for child in &mut self.children {
+            child.virtual_to_real(mapping);
+        }
+    }
+}
+
+/// Snapshot of accessibility semantics of processed widgets, with bounds in UI space.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccessibilityTree {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityTree {
+    /// Builds tree out of accessible widgets listed in reversed processing order.
+    pub(crate) fn build(entries: &[(WidgetId, AccessibilityProps)], layout: &Layout) -> Self {
+        let entries = entries
+            .iter()
+            .rev()
+            .filter(|(_, props)| !props.states.hidden)
+            .collect::<Vec<_>>();
+        let indices = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (id, _))| (id.path(), index))
+            .collect::<HashMap<_, _>>();
+        // Wrapper components usually pass their props down to the component they are made of,
+        // so direct child carrying the same semantics takes place of its parent.
+        let forwarded = entries
+            .iter()
+            .filter_map(|(id, props)| {
+                let path = id.path();
+                let parent = *indices.get(&path[..path.rfind('/')?])?;
+                let parent_props = &entries[parent].1;
+                (parent_props.role == props.role
+                    && parent_props.label == props.label
+                    && parent_props.description == props.description)
+                    .then_some(parent)
+            })
+            .collect::<HashSet<_>>();
+        let mut children = vec![vec![]; entries.len()];
+        let mut roots = vec![];
+        for (index, (id, _)) in entries.iter().enumerate() {
+            if forwarded.contains(&index) {
+                continue;
+            }
+            let mut path = id.path();
+            let mut parent = None;
+            while let Some(found) = path.rfind('/') {
+                path = &path[..found];
+                if let Some(found) = indices.get(path)
+                    && !forwarded.contains(found)
+                {
+                    parent = Some(*found);
+                    break;
+                }
+            }
+            match parent {
+                Some(parent) => children[parent].push(index),
+                None => roots.push(index),
+            }
+        }
+        Self {
+            roots: roots
+                .into_iter()
+                .map(|index| Self::make_node(index, &entries, &children, layout))
+                .collect(),
+        }
+    }
+
+    fn make_node(
+        index: usize,
+        entries: &[&(WidgetId, AccessibilityProps)],
+        children: &[Vec<usize>],
+        layout: &Layout,
+    ) -> AccessibilityNode {
+        let (id, props) = entries[index];
+        AccessibilityNode {
+            id: id.to_owned(),
+            props: props.to_owned(),
+            bounds: Self::widget_bounds(id, layout),
+            children: children[index]
+                .iter()
+                .map(|index| Self::make_node(*index, entries, children, layout))
+                .collect(),
+        }
+    }
+
+    // Components that do not produce unit with their own id are measured by their descendants.
+    fn widget_bounds(id: &WidgetId, layout: &Layout) -> Rect {
+        if let Some(item) = layout.items.get(id) {
+            return item.ui_space;
+        }
+        layout
+            .items
+            .iter()
+            .filter(|(item_id, _)| item_id.is_superset_of(id))
+            .map(|(_, item)| item.ui_space)
+            .reduce(|a, b| Rect {
+                left: a.left.min(b.left),
+                right: a.right.max(b.right),
+                top: a.top.min(b.top),
+                bottom: a.bottom.max(b.bottom),
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn find(&self, id: &WidgetId) -> Option<&AccessibilityNode> {
+        self.roots.iter().find_map(|node| node.find(id))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        self.roots.iter().flat_map(|node| node.iter())
+    }
+
+    pub fn virtual_to_real(mut self, mapping: &CoordsMapping) -> Self {
+        for node in &mut self.roots {
+            node.virtual_to_real(mapping);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        make_widget,
+        tester::AppCycleTester,
+        widget::{
+            WidgetRef,
+            component::{
+                containers::content_box::nav_content_box,
+                interactive::{
+                    button::button,
+                    navigation::NavItemActive,
+                    slider_view::{SliderViewProps, slider_view},
+                },
+            },
+        },
+    };
+    use intuicio_data::managed::Managed;
+
+    #[test]
+    fn test_accessibility_tree() {
+        let ok = WidgetRef::default();
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 200.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+        tester.application.apply(
+            make_widget!(nav_content_box)
+                .key("dialog")
+                .with_props(AccessibilityProps::new(AccessibilityRole::Dialog).label("Settings"))
+                .listed_slot(
+                    make_widget!(button)
+                        .key("hidden")
+                        .with_props(AccessibilityProps {
+                            states: AccessibilityStates {
+                                hidden: true,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .named_slot(
+                            "content",
+                            make_widget!(button)
+                                .idref(ok.clone())
+                                .with_props(NavItemActive)
+                                .with_props(
+                                    AccessibilityProps::new(AccessibilityRole::Button).label("OK"),
+                                ),
+                        ),
+                ),
+        );
+        tester.run_frame(());
+        tester.run_frame(());
+
+        let tree = tester.application.accessibility_tree();
+        assert_eq!(tree.roots.len(), 1);
+        let dialog = &tree.roots[0];
+        assert_eq!(dialog.props.role, AccessibilityRole::Dialog);
+        assert_eq!(dialog.props.label, "Settings");
+        assert_eq!(dialog.bounds.right, 200.0);
+        assert_eq!(dialog.children.len(), 1);
+        let id = ok.read().unwrap();
+        let node = tree.find(&id).unwrap();
+        assert_eq!(node.props.label, "OK");
+        assert!(!node.props.states.selected);
+        assert_eq!(tree.iter().count(), 2);
+
+        tester
+            .application
+            .accessibility_action(&id, AccessibilityAction::Focus);
+        tester.run_frame(());
+        tester.run_frame(());
+        assert_eq!(tester.interactions_engine.selected_item(), Some(&id));
+        tester.run_frame(());
+        let tree = tester.application.accessibility_tree();
+        assert!(tree.find(&id).unwrap().props.states.selected);
+    }
+
+    #[test]
+    fn test_slider_step() {
+        let increment = |step: Scalar, accessibility_step: Scalar| {
+            let mut value = Managed::new(1.0 as Scalar);
+            let slider = WidgetRef::default();
+            let mut tester = AppCycleTester::new(CoordsMapping::new(Default::default()), ());
+            tester.application.apply(
+                make_widget!(nav_content_box).listed_slot(
+                    make_widget!(slider_view)
+                        .idref(slider.clone())
+                        .with_props(SliderViewProps {
+                            input: Some(value.lazy().into()),
+                            to: 10.0,
+                            step,
+                            ..Default::default()
+                        })
+                        .with_props(AccessibilityProps {
+                            range: Some(AccessibilityRange {
+                                step: accessibility_step,
+                                ..Default::default()
+                            }),
+                            ..AccessibilityProps::new(AccessibilityRole::Slider)
+                        }),
+                ),
+            );
+            tester.run_frame(());
+            let id = slider.read().unwrap();
+            let reported = tester
+                .application
+                .accessibility_tree()
+                .find(&id)
+                .unwrap()
+                .props
+                .range;
+            tester
+                .application
+                .accessibility_action(&id, AccessibilityAction::Increment);
+            tester.run_frame(());
+            tester.run_frame(());
+            let result = *value.read().unwrap();
+            (reported.unwrap().step, result)
+        };
+
+        assert_eq!(increment(0.5, 0.0), (0.5, 1.5));
+        assert_eq!(increment(0.5, 2.0), (0.5, 1.5));
+        assert_eq!(increment(0.0, 2.0), (2.0, 3.0));
+        assert_eq!(increment(0.0, 0.0), (0.0, 2.0));
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! [`WidgetContext`]: crate::widget::context::WidgetContext
 use crate::{MessageData, Scalar, messenger::MessageSender, widget::WidgetId};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::mpsc::Sender};
 
@@ -142,7 +143,7 @@ pub struct AnimatedValueProgress {
 /// values has a `value_name` that can be used to get the animated value.
 ///
 /// [`WidgetContext`]: crate::widget::context::WidgetContext
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AnimatorStates(
     #[serde(default)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
@@ -249,7 +250,7 @@ impl AnimatorStates {
 /// This is most often accessed though [`AnimatorStates`] in the [`WidgetContext`].
 ///
 /// [`WidgetContext`]: crate::widget::context::WidgetContext
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AnimatorState {
     #[serde(default)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
@@ -404,7 +405,7 @@ impl AnimatorState {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 struct AnimationPhase {
     #[serde(default)]
     pub start: Scalar,
@@ -424,7 +425,7 @@ struct AnimationPhase {
 /// to track the _progress_ of an animated value using the
 /// [`value_progress`][AnimatorStates::value_progress] function. This allows you to use the progress
 /// to calculate how to interpolate the real values when you build your widget.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum Animation {
     /// A single animated value with a name and a duration
     Value(AnimatedValue),
@@ -447,7 +448,7 @@ impl Default for Animation {
 }
 
 /// A single, animated value with a name and a duration
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AnimatedValue {
     /// The name of the animated value
     ///
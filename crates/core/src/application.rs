@@ -13,15 +13,22 @@
 //! ```
 
 use crate::{
-    Prefab, PrefabError, PrefabValue, Scalar,
+    LogKind, Logger, Prefab, PrefabError, PrefabValue, Scalar,
+    accessibility::{AccessibilityAction, AccessibilityProps, AccessibilityTree},
     animator::{AnimationUpdate, Animator, AnimatorStates},
+    binary,
+    binding::{self, BindingContext, BindingError, PropsBindings},
     interactive::InteractionsEngine,
     layout::{CoordsMapping, Layout, LayoutEngine},
+    markup::{self, MarkupAttribute, MarkupElement, MarkupError, MarkupErrorKind},
     messenger::{Message, MessageData, MessageSender, Messages, Messenger},
-    props::{Props, PropsData, PropsRegistry},
+    props::{Props, PropsData, PropsGroupPrefab, PropsRegistry},
     renderer::Renderer,
     signals::{Signal, SignalSender},
     state::{State, StateChange, StateUpdate},
+    style::{StyleError, StyleSelector, StyleSheet, StyleTarget},
+    template::{PrefabTemplate, TemplateError, WidgetTemplatePrefab},
+    validation::{PrefabIssue, PrefabIssueKind, PrefabValidator, pointer_join},
     view_model::{ViewModel, ViewModelCollection, ViewModelCollectionView},
     widget::{
         FnWidget, WidgetId, WidgetIdCommon, WidgetLifeCycle,
@@ -53,9 +60,12 @@ use crate::{
         },
     },
 };
+use schemars::SchemaGenerator;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::{
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     convert::TryInto,
     sync::{
         Arc, RwLock,
@@ -68,6 +78,7 @@ use std::{
 pub enum ApplicationError {
     Prefab(PrefabError),
     ComponentMappingNotFound(String),
+    Template(TemplateError),
 }
 
 impl From<PrefabError> for ApplicationError {
@@ -76,6 +87,28 @@ impl From<PrefabError> for ApplicationError {
     }
 }
 
+impl From<TemplateError> for ApplicationError {
+    fn from(error: TemplateError) -> Self {
+        Self::Template(error)
+    }
+}
+
+/// Serializable widget states and animations of an [`Application`], keyed by widget id
+///
+/// Taken with [`Application::snapshot`] and re-hydrated with [`Application::restore`].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ApplicationSnapshot {
+    /// Widget states serialized as [`PropsGroupPrefab`].
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub states: HashMap<WidgetId, PrefabValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub animators: HashMap<WidgetId, AnimatorStates>,
+}
+
+impl Prefab for ApplicationSnapshot {}
+
 /// Indicates the reason that an [`Application`] state was invalidated and had to be re-rendered
 ///
 /// You can get the last invalidation cause of an application using [`last_invalidation_cause`]
@@ -107,16 +140,25 @@ impl ChangeNotifier {
 pub struct Application {
     component_mappings: HashMap<String, FnWidget>,
     props_registry: PropsRegistry,
+    templates: HashMap<String, PrefabTemplate>,
+    style_sheet: StyleSheet,
+    /// Parsed selectors with indices of their rules, in order rules apply.
+    style_selectors: Vec<(StyleSelector, usize)>,
     tree: WidgetNode,
     rendered_tree: WidgetUnit,
     layout: Layout,
     states: HashMap<WidgetId, Props>,
+    restored_states: HashMap<WidgetId, Props>,
+    restored_animators: HashMap<WidgetId, AnimatorStates>,
     state_changes: HashMap<WidgetId, Vec<StateChange>>,
     animators: HashMap<WidgetId, AnimatorStates>,
     messages: HashMap<WidgetId, Messages>,
     pending_stack: Vec<WidgetStackItem>,
     done_stack: Vec<WidgetNode>,
     signals: Vec<Signal>,
+    binding_errors: Vec<(WidgetId, String, BindingError)>,
+    style_errors: Vec<(WidgetId, String, PrefabError)>,
+    accessibility: Vec<(WidgetId, AccessibilityProps)>,
     pub view_models: ViewModelCollection,
     changes: ChangeNotifier,
     #[allow(clippy::type_complexity)]
@@ -138,16 +180,24 @@ impl Default for Application {
         Self {
             component_mappings: Default::default(),
             props_registry: Default::default(),
+            templates: Default::default(),
+            style_sheet: Default::default(),
+            style_selectors: Default::default(),
             tree: Default::default(),
             rendered_tree: Default::default(),
             layout: Default::default(),
             states: Default::default(),
+            restored_states: Default::default(),
+            restored_animators: Default::default(),
             state_changes: Default::default(),
             animators: Default::default(),
             messages: Default::default(),
             pending_stack: Default::default(),
             done_stack: Default::default(),
             signals: Default::default(),
+            binding_errors: Default::default(),
+            style_errors: Default::default(),
+            accessibility: Default::default(),
             view_models,
             changes: ChangeNotifier(Default::default()),
             unmount_closures: Default::default(),
@@ -218,6 +268,62 @@ impl Application {
         self.props_registry.unregister_factory(name);
     }
 
+    /// Register a [template][crate::template] under a given name
+    ///
+    /// Prefabs instantiate it with `Template` nodes, expanded on deserialization.
+    pub fn register_template(&mut self, name: &str, template: PrefabTemplate) {
+        self.templates.insert(name.to_owned(), template);
+    }
+
+    /// Unregister a [template][crate::template] with the given name
+    pub fn unregister_template(&mut self, name: &str) {
+        self.templates.remove(name);
+    }
+
+    /// Register all [templates][crate::template] of a serialized map of templates by their names
+    pub fn register_templates(&mut self, data: PrefabValue) -> Result<(), PrefabError> {
+        let templates = serde_json::from_value::<HashMap<String, PrefabTemplate>>(data)
+            .map_err(|error| PrefabError::CouldNotDeserialize(error.to_string()))?;
+        self.templates.extend(templates);
+        Ok(())
+    }
+
+    /// Set the [style sheet][crate::style] applied to processed components
+    ///
+    /// Fails with index of the first rule that has invalid selector or properties, keeping
+    /// the current style sheet.
+    pub fn set_style_sheet(&mut self, style_sheet: StyleSheet) -> Result<(), (usize, StyleError)> {
+        let mut selectors = Vec::with_capacity(style_sheet.rules.len());
+        for (index, rule) in style_sheet.rules.iter().enumerate() {
+            let selector = rule
+                .selector
+                .parse::<StyleSelector>()
+                .map_err(|error| (index, error))?;
+            for (group, data) in [("props", &rule.props), ("shared_props", &rule.shared_props)] {
+                for (name, value) in data {
+                    self.props_registry
+                        .validate(name, value.to_owned())
+                        .map_err(|issue| {
+                            let path = pointer_join(&format!("/{group}"), name) + &issue.path;
+                            (index, StyleError::Props(PrefabIssue { path, ..issue }))
+                        })?;
+                }
+            }
+            selectors.push((selector, index));
+        }
+        selectors.sort_by_key(|(selector, _)| selector.specificity());
+        self.style_sheet = style_sheet;
+        self.style_selectors = selectors;
+        self.mark_dirty();
+        Ok(())
+    }
+
+    /// Get the [style sheet][crate::style] applied to processed components
+    #[inline]
+    pub fn style_sheet(&self) -> &StyleSheet {
+        &self.style_sheet
+    }
+
     /// Serialize the given [`Props`] to a [`PrefabValue`]
     #[inline]
     pub fn serialize_props(&self, props: &Props) -> Result<PrefabValue, PrefabError> {
@@ -242,6 +348,165 @@ impl Application {
         self.node_from_prefab(WidgetNodePrefab::from_prefab(data)?)
     }
 
+    /// Serialize the given [`Props`] to [binary prefab][crate::binary]
+    pub fn serialize_props_binary(&self, props: &Props) -> Result<Vec<u8>, PrefabError> {
+        Ok(binary::encode(&self.serialize_props(props)?))
+    }
+
+    /// Deserialize [`Props`] from [binary prefab][crate::binary]
+    pub fn deserialize_props_binary(&self, bytes: &[u8]) -> Result<Props, PrefabError> {
+        self.deserialize_props(Self::decode_binary(bytes)?)
+    }
+
+    /// Serialize a [`WidgetNode`] to [binary prefab][crate::binary]
+    pub fn serialize_node_binary(&self, data: &WidgetNode) -> Result<Vec<u8>, ApplicationError> {
+        Ok(binary::encode(&self.serialize_node(data)?))
+    }
+
+    /// Deserialize a [`WidgetNode`] from [binary prefab][crate::binary]
+    pub fn deserialize_node_binary(&self, bytes: &[u8]) -> Result<WidgetNode, ApplicationError> {
+        self.deserialize_node(Self::decode_binary(bytes)?)
+    }
+
+    /// Check a serialized [`WidgetNode`] and report all of its [issues][crate::validation]
+    ///
+    /// Unlike [`deserialize_node`][Self::deserialize_node] it does not stop at the first
+    /// problem, and every issue points at offending value with JSON pointer.
+    pub fn validate_node(&self, data: &PrefabValue) -> Vec<PrefabIssue> {
+        self.validate_prefab(&mut data.clone(), false).0
+    }
+
+    /// Deserialize a [`WidgetNode`] from a [`PrefabValue`], reporting all of its issues if it
+    /// is invalid
+    pub fn deserialize_node_validated(
+        &self,
+        mut data: PrefabValue,
+    ) -> Result<WidgetNode, Vec<PrefabIssue>> {
+        let (issues, _) = self.validate_prefab(&mut data, false);
+        self.deserialize_node_checked(data, issues)
+    }
+
+    /// Deserialize a [`WidgetNode`] from a [`PrefabValue`], skipping unknown properties
+    ///
+    /// Skipped properties are logged as warnings, other issues fail like in
+    /// [`deserialize_node_validated`][Self::deserialize_node_validated].
+    pub fn deserialize_node_lenient(
+        &self,
+        mut data: PrefabValue,
+        logger: &mut dyn Logger,
+    ) -> Result<WidgetNode, Vec<PrefabIssue>> {
+        let (issues, warnings) = self.validate_prefab(&mut data, true);
+        for warning in warnings {
+            logger.log(LogKind::Warning, &warning.to_string());
+        }
+        self.deserialize_node_checked(data, issues)
+    }
+
+    /// Deserialize a [`WidgetNode`] from [markup][crate::markup]
+    ///
+    /// Unlike going through [`markup_to_prefab`][crate::markup::markup_to_prefab] and
+    /// [`deserialize_node`][Self::deserialize_node], unknown components and invalid properties
+    /// are reported with their line and column in markup too.
+    pub fn deserialize_markup(&self, source: &str) -> Result<WidgetNode, MarkupError> {
+        let mut elements = markup::parse(source)?;
+        Ok(match elements.len() {
+            0 => WidgetNode::None,
+            1 => WidgetNode::Component(self.component_from_markup(source, elements.remove(0))?),
+            _ => WidgetNode::Tuple(
+                elements
+                    .into_iter()
+                    .map(|v| {
+                        Ok(WidgetNode::Component(
+                            self.component_from_markup(source, v)?,
+                        ))
+                    })
+                    .collect::<Result<_, MarkupError>>()?,
+            ),
+        })
+    }
+
+    /// JSON Schema of serialized [`WidgetNode`]s, as accepted by
+    /// [`deserialize_node`][Self::deserialize_node]
+    ///
+    /// Properties are described with schemas of [registered][Self::register_props] types and
+    /// component type names are limited to [registered][Self::register_component] ones, so
+    /// external tools can validate and autocomplete prefab files. Any properties field can also
+    /// be given [binding][crate::binding] placeholder.
+    pub fn prefab_schema(&self) -> PrefabValue {
+        let mut generator = SchemaGenerator::default();
+        let props = self.props_registry.schemas(&mut generator);
+        let mut schema = generator.root_schema_for::<WidgetNodePrefab>().to_value();
+        let definitions = &mut schema["$defs"];
+        let mut data = json!({
+            "type": "object",
+            "properties": props,
+            "additionalProperties": false,
+        });
+        binding::allow_placeholders(&mut data, definitions);
+        definitions["PropsGroupPrefab"]["properties"]["data"] = data;
+        let mut type_names = self.component_mappings.keys().collect::<Vec<_>>();
+        type_names.sort();
+        definitions["WidgetComponentPrefab"]["properties"]["type_name"]["enum"] = json!(type_names);
+        schema
+    }
+
+    /// Take a snapshot of widget states and animations, to [restore][Self::restore] them later
+    ///
+    /// States are serialized with [registered][Self::register_props] properties types; state
+    /// properties of unregistered types are skipped and reported to `logger` as warnings.
+    /// Pending state changes and messages are not part of the snapshot.
+    pub fn snapshot(&self, logger: &mut dyn Logger) -> Result<ApplicationSnapshot, PrefabError> {
+        let mut states = HashMap::with_capacity(self.states.len());
+        for (id, state) in &self.states {
+            let (data, skipped) = self.props_registry.serialize_registered(state)?;
+            if skipped > 0 {
+                logger.log(
+                    LogKind::Warning,
+                    &format!("Skipped {skipped} unregistered properties of widget state: {id}"),
+                );
+            }
+            states.insert(id.to_owned(), data);
+        }
+        Ok(ApplicationSnapshot {
+            states,
+            animators: self.animators.clone(),
+        })
+    }
+
+    /// Restore widget states and animations from a [snapshot][Self::snapshot]
+    ///
+    /// States and animations of mounted widgets are replaced, other widgets get them when they
+    /// mount during the next [process][Self::process], before their mount hooks run. Restored
+    /// animations take precedence, so changes mount hooks make to animations of widgets with
+    /// restored animations are ignored. Restored states and animations of widgets that do not
+    /// mount then are dropped.
+    pub fn restore(&mut self, snapshot: ApplicationSnapshot) -> Result<(), PrefabError> {
+        let states = snapshot
+            .states
+            .into_iter()
+            .map(|(id, data)| Ok((id, self.props_registry.deserialize(data)?)))
+            .collect::<Result<Vec<_>, PrefabError>>()?;
+        for (id, state) in states {
+            if self.states.contains_key(&id) {
+                self.state_changes
+                    .entry(id)
+                    .or_default()
+                    .push(StateChange::Set(state));
+            } else {
+                self.restored_states.insert(id, state);
+            }
+        }
+        for (id, animator) in snapshot.animators {
+            if self.states.contains_key(&id) {
+                self.animators.insert(id, animator);
+            } else {
+                self.restored_animators.insert(id, animator);
+            }
+        }
+        self.mark_dirty();
+        Ok(())
+    }
+
     /// Get the reason that the application state was last invalidated and caused to re-process
     #[inline]
     pub fn last_invalidation_cause(&self) -> &InvalidationCause {
@@ -288,6 +553,19 @@ impl Application {
         self.layout.items.keys().any(|k| k == id)
     }
 
+    /// Build [`AccessibilityTree`] of widgets that were given [`AccessibilityProps`]
+    ///
+    /// Bounds are taken from the last computed [`Layout`], in UI space.
+    pub fn accessibility_tree(&self) -> AccessibilityTree {
+        AccessibilityTree::build(&self.accessibility, &self.layout)
+    }
+
+    /// Send an action requested by assistive technology to the widget
+    #[inline]
+    pub fn accessibility_action(&mut self, id: &WidgetId, action: AccessibilityAction) {
+        self.send_message(id, action);
+    }
+
     /// Update the application widget tree
     #[inline]
     pub fn apply(&mut self, tree: impl Into<WidgetNode>) {
@@ -386,6 +664,21 @@ impl Application {
         }
     }
 
+    /// Get the [bindings][crate::binding] that could not be resolved during last processing,
+    /// with ids of their widgets and names of their properties, followed by JSON pointer to
+    /// failed placeholder (`TextBoxProps/text`), if there is one
+    #[inline]
+    pub fn binding_errors(&self) -> &[(WidgetId, String, BindingError)] {
+        &self.binding_errors
+    }
+
+    /// Get the [style][crate::style] rule properties that could not be applied during last
+    /// processing, with ids of their widgets and names of their properties
+    #[inline]
+    pub fn style_errors(&self) -> &[(WidgetId, String, PrefabError)] {
+        &self.style_errors
+    }
+
     /// Get the list of [signals][crate::signals] that have been sent by widgets
     #[inline]
     pub fn signals(&self) -> &[Signal] {
@@ -458,6 +751,8 @@ impl Application {
             }
         }
         let (signal_sender, signal_receiver) = channel();
+        self.binding_errors.clear();
+        self.style_errors.clear();
         let tree = self.tree.clone();
         let mut used_ids = HashSet::new();
         let mut new_states = HashMap::new();
@@ -470,6 +765,8 @@ impl Application {
             &message_sender,
             &signal_sender,
         );
+        self.restored_states.clear();
+        self.restored_animators.clear();
         self.states = states
             .into_iter()
             .chain(new_states)
@@ -536,9 +833,11 @@ impl Application {
         signal_sender: &Sender<Signal>,
     ) -> WidgetNode {
         self.pending_stack.clear();
+        self.accessibility.clear();
         self.pending_stack.push(WidgetStackItem::Node {
             node: root_node,
             path: vec![],
+            type_names: vec![],
             possible_key: "<*>".to_string(),
             master_shared_props: None,
         });
@@ -548,6 +847,7 @@ impl Application {
                 WidgetStackItem::Node {
                     node,
                     mut path,
+                    mut type_names,
                     possible_key,
                     master_shared_props,
                 } => match node {
@@ -578,16 +878,27 @@ impl Application {
                             None => possible_key.to_owned(),
                         };
                         path.push(key.clone().into());
+                        type_names.push(type_name.to_owned());
                         let id = WidgetId::new(&type_name, &path);
                         used_ids.insert(id.clone());
                         if let Some(idref) = &mut idref {
                             idref.write(id.to_owned());
                         }
+                        self.resolve_bindings(&id, &mut props);
+                        self.resolve_bindings(&id, &mut shared_props);
+                        self.apply_style(&type_names, &id, &mut props, &mut shared_props);
                         let (state_sender, state_receiver) = channel();
                         let (animation_sender, animation_receiver) = channel();
                         let messages_list = messages.remove(&id).unwrap_or_default();
                         let mut life_cycle = WidgetLifeCycle::default();
                         let default_animator_state = AnimatorStates::default();
+                        let restored_animator = match self.restored_animators.remove(&id) {
+                            Some(animator) if !states.contains_key(&id) => {
+                                self.animators.insert(id.to_owned(), animator);
+                                true
+                            }
+                            _ => false,
+                        };
                         let (new_node, mounted) = match states.get(&id) {
                             Some(state) => {
                                 let state =
@@ -612,7 +923,8 @@ impl Application {
                                 (processor.call(context), false)
                             }
                             None => {
-                                let state_data = Props::default();
+                                let state_data =
+                                    self.restored_states.remove(&id).unwrap_or_default();
                                 let state =
                                     State::new(&state_data, StateUpdate::new(state_sender.clone()));
                                 let animator =
@@ -637,6 +949,10 @@ impl Application {
                                 (node, true)
                             }
                         };
+                        if let Ok(accessibility) = props.read::<AccessibilityProps>() {
+                            self.accessibility
+                                .push((id.to_owned(), accessibility.to_owned()));
+                        }
                         let (mount, change, unmount) = life_cycle.unwrap();
                         if mounted {
                             if !mount.is_empty()
@@ -700,6 +1016,9 @@ impl Application {
                             self.unmount_closures.insert(id.clone(), unmount);
                         }
                         while let Ok((name, data)) = animation_receiver.try_recv() {
+                            if restored_animator {
+                                continue;
+                            }
                             if let Some(states) = self.animators.get_mut(&id) {
                                 states.change(name, data);
                             } else if let Some(data) = data {
@@ -716,6 +1035,7 @@ impl Application {
                         self.pending_stack.push(WidgetStackItem::Node {
                             node: new_node,
                             path,
+                            type_names,
                             possible_key,
                             master_shared_props: Some(shared_props),
                         });
@@ -733,6 +1053,7 @@ impl Application {
                             self.pending_stack.push(WidgetStackItem::Node {
                                 node: slot,
                                 path,
+                                type_names,
                                 possible_key: ".".to_owned(),
                                 master_shared_props,
                             });
@@ -745,6 +1066,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node: slot,
                                     path,
+                                    type_names,
                                     possible_key: ".".to_owned(),
                                     master_shared_props,
                                 });
@@ -756,6 +1078,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node: slot,
                                     path,
+                                    type_names,
                                     possible_key: ".".to_owned(),
                                     master_shared_props,
                                 });
@@ -767,6 +1090,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node: slot,
                                     path,
+                                    type_names,
                                     possible_key: ".".to_owned(),
                                     master_shared_props,
                                 });
@@ -778,6 +1102,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node: slot,
                                     path,
+                                    type_names,
                                     possible_key: ".".to_owned(),
                                     master_shared_props,
                                 });
@@ -795,6 +1120,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node,
                                     path: path.clone(),
+                                    type_names: type_names.clone(),
                                     possible_key: format!("<{index}>"),
                                     master_shared_props: master_shared_props.clone(),
                                 });
@@ -812,6 +1138,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node,
                                     path: path.clone(),
+                                    type_names: type_names.clone(),
                                     possible_key: format!("<{index}>"),
                                     master_shared_props: master_shared_props.clone(),
                                 });
@@ -829,6 +1156,7 @@ impl Application {
                                 self.pending_stack.push(WidgetStackItem::Node {
                                     node,
                                     path: path.clone(),
+                                    type_names: type_names.clone(),
                                     possible_key: format!("<{index}>"),
                                     master_shared_props: master_shared_props.clone(),
                                 });
@@ -841,6 +1169,7 @@ impl Application {
                             self.pending_stack.push(WidgetStackItem::Node {
                                 node: slot,
                                 path,
+                                type_names,
                                 possible_key: ".".to_owned(),
                                 master_shared_props,
                             });
@@ -1246,7 +1575,9 @@ impl Application {
                 })
                 .collect::<Result<_, ApplicationError>>()?,
             clipping: data.clipping,
+            clip_shape: data.clip_shape.clone(),
             content_reposition: data.content_reposition,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1271,6 +1602,7 @@ impl Application {
             direction: data.direction,
             separation: data.separation,
             wrap: data.wrap,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1294,6 +1626,7 @@ impl Application {
                 .collect::<Result<_, ApplicationError>>()?,
             cols: data.cols,
             rows: data.rows,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1325,6 +1658,7 @@ impl Application {
             height: data.height,
             content_keep_aspect_ratio: data.content_keep_aspect_ratio,
             material: data.material.clone(),
+            shadows: data.shadows.clone(),
             transform: data.transform,
         })
     }
@@ -1344,10 +1678,174 @@ impl Application {
             direction: data.direction,
             font: data.font.clone(),
             color: data.color,
+            gradient: data.gradient.clone(),
+            shadows: data.shadows.clone(),
             transform: data.transform,
         })
     }
 
+    fn resolve_bindings(&mut self, id: &WidgetId, props: &mut Props) {
+        let Ok(bindings) = props.consume_unwrap_cloned::<PropsBindings>() else {
+            return;
+        };
+        let mut context = BindingContext::new(&self.view_models);
+        for (name, data) in bindings.data {
+            // Fields with failed placeholders are left out, so the rest still applies.
+            let mut errors = Vec::new();
+            let resolved = context.resolve_partial(&data, "", &mut errors);
+            for (path, error) in errors {
+                self.binding_errors
+                    .push((id.to_owned(), format!("{name}{path}"), error));
+            }
+            let Some(data) = resolved else {
+                continue;
+            };
+            let group = PropsGroupPrefab {
+                data: HashMap::from([(name.to_owned(), data)]),
+            };
+            match group
+                .to_prefab()
+                .and_then(|data| self.props_registry.deserialize(data))
+            {
+                Ok(resolved) => props.merge_from(resolved),
+                Err(error) => self.binding_errors.push((
+                    id.to_owned(),
+                    name,
+                    BindingError::InvalidProps(error.to_string()),
+                )),
+            }
+        }
+        for (view_model, property) in context.bound {
+            if let Some(view_model) = self.view_models.get_mut(&view_model)
+                && let Some(mut bindings) = view_model.properties.bindings(property)
+                && !bindings.is_bound(id)
+            {
+                bindings.bind(id.to_owned());
+            }
+        }
+    }
+
+    /// Patch properties of component with ones of matching style rules
+    fn apply_style(
+        &mut self,
+        type_names: &[String],
+        id: &WidgetId,
+        props: &mut Props,
+        shared_props: &mut Props,
+    ) {
+        if self.style_selectors.is_empty() {
+            return;
+        }
+        let targets = type_names
+            .iter()
+            .zip(id.parts_key_meta())
+            .map(|(type_name, (key, meta))| StyleTarget {
+                type_name,
+                key,
+                meta,
+            })
+            .collect::<Vec<_>>();
+        // Patches by shared flag and properties name, as defaults and overrides.
+        let mut patches = BTreeMap::<_, (Vec<_>, Vec<_>)>::new();
+        for (selector, index) in &self.style_selectors {
+            if !selector.matches(&targets) {
+                continue;
+            }
+            let rule = &self.style_sheet.rules[*index];
+            for (shared, group) in [(false, &rule.props), (true, &rule.shared_props)] {
+                for (name, patch) in group {
+                    let (defaults, overrides) = patches.entry((shared, name)).or_default();
+                    if rule.important {
+                        overrides.push(patch);
+                    } else {
+                        defaults.push(patch);
+                    }
+                }
+            }
+        }
+        let mut errors = vec![];
+        for ((shared, name), (defaults, overrides)) in patches {
+            let props = if shared {
+                &mut *shared_props
+            } else {
+                &mut *props
+            };
+            // Rules are validated when set, but merged with component properties they can
+            // still fail, like internally tagged enums given other variant.
+            if let Err(error) = self
+                .props_registry
+                .patch(name, &defaults, &overrides, props)
+            {
+                errors.push((id.to_owned(), name.to_owned(), error));
+            }
+        }
+        self.style_errors.extend(errors);
+    }
+
+    /// Deserialize component properties, keeping ones with [binding][crate::binding]
+    /// placeholders to be resolved during processing
+    fn deserialize_component_props(&self, data: PrefabValue) -> Result<Props, PrefabError> {
+        let data = if data.is_null() {
+            PropsGroupPrefab::default()
+        } else {
+            PropsGroupPrefab::from_prefab(data)?
+        };
+        let (bound, data): (HashMap<_, _>, HashMap<_, _>) = data
+            .data
+            .into_iter()
+            .partition(|(_, value)| binding::has_bindings(value));
+        for (name, value) in &bound {
+            if !self.props_registry.has_factory(name) {
+                return Err(PrefabError::CouldNotDeserialize(format!(
+                    "Could not find properties factory: {name:?}"
+                )));
+            }
+            if let Err((path, error)) = binding::check(value) {
+                return Err(PrefabError::CouldNotDeserialize(format!(
+                    "Invalid binding of {name:?} properties at {path:?}: {error}"
+                )));
+            }
+        }
+        let mut props = self.deserialize_props(PropsGroupPrefab { data }.to_prefab()?)?;
+        if !bound.is_empty() {
+            props.write(PropsBindings { data: bound });
+        }
+        Ok(props)
+    }
+
+    fn decode_binary(bytes: &[u8]) -> Result<PrefabValue, PrefabError> {
+        binary::decode(bytes).map_err(|error| PrefabError::CouldNotDeserialize(error.to_string()))
+    }
+
+    fn validate_prefab(
+        &self,
+        data: &mut PrefabValue,
+        lenient: bool,
+    ) -> (Vec<PrefabIssue>, Vec<PrefabIssue>) {
+        let has_component = |type_name: &str| self.component_mappings.contains_key(type_name);
+        let has_template = |name: &str| self.templates.contains_key(name);
+        let mut validator =
+            PrefabValidator::new(&has_component, &has_template, &self.props_registry, lenient);
+        validator.validate(data);
+        (validator.issues, validator.warnings)
+    }
+
+    fn deserialize_node_checked(
+        &self,
+        data: PrefabValue,
+        issues: Vec<PrefabIssue>,
+    ) -> Result<WidgetNode, Vec<PrefabIssue>> {
+        if !issues.is_empty() {
+            return Err(issues);
+        }
+        self.deserialize_node(data).map_err(|error| {
+            vec![PrefabIssue {
+                path: Default::default(),
+                kind: PrefabIssueKind::InvalidNode(format!("{error:?}")),
+            }]
+        })
+    }
+
     fn node_from_prefab(&self, data: WidgetNodePrefab) -> Result<WidgetNode, ApplicationError> {
         Ok(match data {
             WidgetNodePrefab::None => WidgetNode::None,
@@ -1356,9 +1854,71 @@ impl Application {
             }
             WidgetNodePrefab::Unit(data) => WidgetNode::Unit(self.unit_from_prefab(data)?),
             WidgetNodePrefab::Tuple(data) => WidgetNode::Tuple(self.tuple_from_prefab(data)?),
+            WidgetNodePrefab::Template(data) => {
+                self.node_from_prefab(self.expand_template(data, &mut vec![])?)?
+            }
         })
     }
 
+    fn expand_template(
+        &self,
+        data: WidgetTemplatePrefab,
+        stack: &mut Vec<String>,
+    ) -> Result<WidgetNodePrefab, ApplicationError> {
+        let WidgetTemplatePrefab {
+            name,
+            key,
+            params,
+            listed_slots,
+            named_slots,
+        } = data;
+        let Some(template) = self.templates.get(&name) else {
+            return Err(TemplateError::NotFound(name).into());
+        };
+        if stack.contains(&name) {
+            return Err(TemplateError::Recursion(name).into());
+        }
+        // Slots belong to the instance site, so templates in them do not count as recursion.
+        let listed_slots = listed_slots
+            .into_iter()
+            .map(|mut slot| {
+                self.expand_templates(&mut slot, stack)?;
+                Ok(slot.to_prefab()?)
+            })
+            .collect::<Result<Vec<_>, ApplicationError>>()?;
+        let named_slots = named_slots
+            .into_iter()
+            .map(|(name, mut slot)| {
+                self.expand_templates(&mut slot, stack)?;
+                Ok((name, slot.to_prefab()?))
+            })
+            .collect::<Result<HashMap<_, _>, ApplicationError>>()?;
+        let data = template.instantiate(&name, params, &listed_slots, &named_slots)?;
+        let mut result = WidgetNodePrefab::from_prefab(data)?;
+        if let (Some(key), WidgetNodePrefab::Component(component)) = (key, &mut result) {
+            component.key = Some(key);
+        }
+        stack.push(name);
+        self.expand_templates(&mut result, stack)?;
+        stack.pop();
+        Ok(result)
+    }
+
+    fn expand_templates(
+        &self,
+        data: &mut WidgetNodePrefab,
+        stack: &mut Vec<String>,
+    ) -> Result<(), ApplicationError> {
+        if let WidgetNodePrefab::Template(instance) = data {
+            *data = self.expand_template(std::mem::take(instance), stack)?;
+            return Ok(());
+        }
+        for child in data.children_mut() {
+            self.expand_templates(child, stack)?;
+        }
+        Ok(())
+    }
+
     fn component_from_prefab(
         &self,
         data: WidgetComponentPrefab,
@@ -1369,9 +1929,9 @@ impl Application {
                 type_name: data.type_name,
                 key: data.key,
                 idref: Default::default(),
-                props: self.deserialize_props(data.props)?,
+                props: self.deserialize_component_props(data.props)?,
                 shared_props: match data.shared_props {
-                    Some(p) => Some(self.deserialize_props(p)?),
+                    Some(p) => Some(self.deserialize_component_props(p)?),
                     None => None,
                 },
                 listed_slots: data
@@ -1392,6 +1952,72 @@ impl Application {
         }
     }
 
+    fn component_from_markup(
+        &self,
+        source: &str,
+        data: MarkupElement,
+    ) -> Result<WidgetComponent, MarkupError> {
+        if let Some(processor) = self.component_mappings.get(&data.type_name) {
+            Ok(WidgetComponent {
+                processor: processor.clone(),
+                type_name: data.type_name,
+                key: data.key,
+                idref: Default::default(),
+                props: self.props_from_markup(source, data.props)?,
+                shared_props: if data.shared_props.is_empty() {
+                    None
+                } else {
+                    Some(self.props_from_markup(source, data.shared_props)?)
+                },
+                listed_slots: data
+                    .listed_slots
+                    .into_iter()
+                    .map(|v| {
+                        Ok(WidgetNode::Component(
+                            self.component_from_markup(source, v)?,
+                        ))
+                    })
+                    .collect::<Result<_, MarkupError>>()?,
+                named_slots: data
+                    .named_slots
+                    .into_iter()
+                    .map(|(k, v)| {
+                        Ok((
+                            k,
+                            WidgetNode::Component(self.component_from_markup(source, v)?),
+                        ))
+                    })
+                    .collect::<Result<_, MarkupError>>()?,
+            })
+        } else {
+            Err(MarkupError::new(
+                source,
+                data.offset,
+                MarkupErrorKind::ComponentMappingNotFound(data.type_name),
+            ))
+        }
+    }
+
+    fn props_from_markup(
+        &self,
+        source: &str,
+        data: Vec<MarkupAttribute>,
+    ) -> Result<Props, MarkupError> {
+        data.into_iter()
+            .try_fold(Props::default(), |props, attribute| {
+                let group = PropsGroupPrefab {
+                    data: HashMap::from([(attribute.name, attribute.value)]),
+                };
+                group
+                    .to_prefab()
+                    .and_then(|data| self.deserialize_component_props(data))
+                    .map(|other| props.merge(other))
+                    .map_err(|error| {
+                        MarkupError::new(source, attribute.offset, MarkupErrorKind::Prefab(error))
+                    })
+            })
+    }
+
     fn unit_from_prefab(
         &self,
         data: WidgetUnitNodePrefab,
@@ -1495,7 +2121,9 @@ impl Application {
                 })
                 .collect::<Result<_, ApplicationError>>()?,
             clipping: data.clipping,
+            clip_shape: data.clip_shape,
             content_reposition: data.content_reposition,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1520,6 +2148,7 @@ impl Application {
             direction: data.direction,
             separation: data.separation,
             wrap: data.wrap,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1543,6 +2172,7 @@ impl Application {
                 .collect::<Result<_, ApplicationError>>()?,
             cols: data.cols,
             rows: data.rows,
+            layer: data.layer,
             transform: data.transform,
         })
     }
@@ -1574,6 +2204,7 @@ impl Application {
             height: data.height,
             content_keep_aspect_ratio: data.content_keep_aspect_ratio,
             material: data.material,
+            shadows: data.shadows,
             transform: data.transform,
         })
     }
@@ -1593,6 +2224,8 @@ impl Application {
             direction: data.direction,
             font: data.font,
             color: data.color,
+            gradient: data.gradient,
+            shadows: data.shadows,
             transform: data.transform,
         })
     }
@@ -1603,6 +2236,8 @@ enum WidgetStackItem {
     Node {
         node: WidgetNode,
         path: Vec<Cow<'static, str>>,
+        /// Type names of components along the path.
+        type_names: Vec<String>,
         possible_key: String,
         master_shared_props: Option<Props>,
     },
@@ -1625,3 +2260,149 @@ enum WidgetStackItem {
         node: SizeBoxNode,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        RecordingLogger,
+        animator::{AnimatedValue, Animation},
+        make_widget,
+    };
+
+    #[test]
+    fn test_prefab_schema() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application.register_component("custom", FnWidget::pointer(|_| Default::default()));
+        let schema = application.prefab_schema();
+        assert_eq!(schema["title"], "WidgetNodePrefab");
+        let definitions = &schema["$defs"];
+        let type_names = definitions["WidgetComponentPrefab"]["properties"]["type_name"]["enum"]
+            .as_array()
+            .unwrap();
+        assert!(type_names.contains(&json!("custom")));
+        assert!(type_names.contains(&json!("text_box")));
+        let props = &definitions["PropsGroupPrefab"]["properties"]["data"]["properties"];
+        assert_eq!(props["f32"]["anyOf"][0]["type"], "number");
+        assert_eq!(
+            props["TextBoxProps"]["anyOf"][0]["$ref"],
+            "#/$defs/TextBoxProps"
+        );
+        assert_eq!(
+            definitions["TextBoxProps"]["properties"]["text"]["anyOf"][0]["type"],
+            "string"
+        );
+        assert!(definitions["WidgetUnitNodePrefab"].is_object());
+
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        let node = |text| {
+            json!({
+                "Component": {
+                    "type_name": "text_box",
+                    "props": { "data": { "TextBoxProps": { "text": text, "color": {
+                        "r": { "$bind": "theme.red" }, "g": 0.0, "b": 0.0, "a": 1.0
+                    } } } }
+                }
+            })
+        };
+        assert!(validator.is_valid(&node(json!("Score"))));
+        assert!(validator.is_valid(&node(json!({ "$format": "Score: {stats.score}" }))));
+        assert!(!validator.is_valid(&node(json!(42))));
+        assert!(!validator.is_valid(&node(json!({ "$bind": 42 }))));
+        assert!(!validator.is_valid(&node(json!({ "$bind": "a", "$format": "b" }))));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        fn counter(context: WidgetContext) -> WidgetNode {
+            context.life_cycle.mount(|context| {
+                let mounts = context.state.read_cloned_or_default::<f32>() + 1.0;
+                let _ = context.state.write_with(mounts);
+                let _ = context.state.write_with(7usize);
+                // Restored mounts keep animation from snapshot instead of restarting it.
+                let _ = context.animator.change(
+                    "fade",
+                    Some(Animation::Value(AnimatedValue {
+                        name: "fade".to_owned(),
+                        duration: 100.0,
+                    })),
+                );
+            });
+            Default::default()
+        }
+
+        let make_application = || {
+            let mut application = Application::default();
+            application.setup(crate::widget::setup);
+            application.register_component("counter", FnWidget::pointer(counter));
+            application.apply(make_widget!(counter).key("a"));
+            application
+        };
+        let mut application = make_application();
+        application.animations_delta_time = 10.0;
+        application.process();
+        application.process();
+        application.process();
+        let id = application.states.keys().next().unwrap().to_owned();
+        let mut logger = RecordingLogger::default();
+        let snapshot = application.snapshot(&mut logger).unwrap();
+        assert_eq!(logger.0.len(), 1);
+        assert_eq!(logger.0[0].0, LogKind::Warning);
+        assert_eq!(snapshot.states[&id], json!({"data": {"f32": 1.0}}));
+        let progress = snapshot.animators[&id].value_progress_factor_or_zero("fade", "fade");
+        assert!(progress > 0.0);
+        let snapshot = ApplicationSnapshot::from_prefab(snapshot.to_prefab().unwrap()).unwrap();
+
+        let mut application = make_application();
+        let mut stale_snapshot = snapshot.clone();
+        let stale = WidgetId::new("counter", &["b".into()]);
+        stale_snapshot
+            .animators
+            .insert(stale.to_owned(), snapshot.animators[&id].clone());
+        application.restore(stale_snapshot).unwrap();
+        application.process();
+        application.process();
+        let state = &application.states[&id];
+        assert_eq!(*state.read::<f32>().unwrap(), 2.0);
+        assert_eq!(
+            application.animators[&id].value_progress_factor_or_zero("fade", "fade"),
+            progress
+        );
+        assert!(!application.animators.contains_key(&stale));
+        assert!(application.restored_animators.is_empty());
+
+        application.restore(snapshot).unwrap();
+        application.process();
+        let state = &application.states[&id];
+        assert_eq!(*state.read::<f32>().unwrap(), 1.0);
+        assert!(!state.has::<usize>());
+    }
+
+    #[test]
+    fn test_binary_prefab() {
+        use crate::widget::component::{
+            containers::content_box::content_box,
+            text_box::{TextBoxProps, text_box},
+        };
+
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let node: WidgetNode = make_widget!(content_box)
+            .key("root")
+            .listed_slots((0..10).map(|index| {
+                make_widget!(text_box).key(index).with_props(TextBoxProps {
+                    text: format!("Item {index}"),
+                    ..Default::default()
+                })
+            }))
+            .into();
+        let json = application.serialize_node(&node).unwrap();
+        let bytes = application.serialize_node_binary(&node).unwrap();
+        assert!(bytes.len() * 2 < serde_json::to_vec(&json).unwrap().len());
+        assert_eq!(binary::decode(&bytes).unwrap(), json);
+        let loaded = application.deserialize_node_binary(&bytes).unwrap();
+        assert_eq!(application.serialize_node(&loaded).unwrap(), json);
+        assert!(application.deserialize_node_binary(&bytes[1..]).is_err());
+    }
+}
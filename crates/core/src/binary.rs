@@ -0,0 +1,348 @@
+//! Compact binary encoding of prefabs.
+//!
+//! Encodes any [`PrefabValue`], so serialized widget nodes and properties groups round-trip
+//! with their JSON representation. Strings are interned into a table written once in front of
+//! the data, which makes repeated component type names, properties names and field names take
+//! only few bytes each:
+//!
+//! ```
+//! # use raui_core::binary::{decode, encode};
+//! # use serde_json::json;
+//! let prefab = json!({
+//!     "Tuple": [
+//!         { "Component": { "type_name": "text_box", "props": { "data": { "f32": 1.5 } } } },
+//!         { "Component": { "type_name": "text_box", "props": { "data": { "f32": -2 } } } },
+//!     ]
+//! });
+//! let bytes = encode(&prefab);
+//! assert!(bytes.len() < serde_json::to_vec(&prefab).unwrap().len());
+//! assert_eq!(decode(&bytes).unwrap(), prefab);
+//! ```
+//!
+//! Layout is `RAUI` magic, format version byte, string table (count, then length prefixed
+//! UTF-8 strings) and root value. Values start with a tag byte; lengths, string indices and
+//! integers are LEB128 varints, floats are little-endian `f32`
+//! when that keeps their value and `f64` otherwise.
+
+use crate::PrefabValue;
+use serde_json::{Map, Number};
+use std::collections::HashMap;
+
+const MAGIC: &[u8; 4] = b"RAUI";
+const VERSION: u8 = 1;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_UNSIGNED: u8 = 3;
+const TAG_NEGATIVE: u8 = 4;
+const TAG_FLOAT: u8 = 5;
+const TAG_SINGLE_FLOAT: u8 = 6;
+const TAG_STRING: u8 = 7;
+const TAG_ARRAY: u8 = 8;
+const TAG_OBJECT: u8 = 9;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryPrefabError {
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    UnexpectedEnd,
+    InvalidTag(u8),
+    InvalidVarint,
+    InvalidUtf8,
+    InvalidStringIndex(usize),
+    InvalidNumber,
+    TrailingBytes(usize),
+}
+
+impl std::fmt::Display for BinaryPrefabError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "Data is not binary prefab"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported binary prefab version: {version}")
+            }
+            Self::UnexpectedEnd => write!(f, "Unexpected end of binary prefab"),
+            Self::InvalidTag(tag) => write!(f, "Invalid value tag: {tag}"),
+            Self::InvalidVarint => write!(f, "Invalid variable length integer"),
+            Self::InvalidUtf8 => write!(f, "String is not valid UTF-8"),
+            Self::InvalidStringIndex(index) => write!(f, "Invalid string index: {index}"),
+            Self::InvalidNumber => write!(f, "Number is not finite"),
+            Self::TrailingBytes(count) => write!(f, "Unexpected {count} bytes after root value"),
+        }
+    }
+}
+
+/// Encodes prefab into binary form.
+pub fn encode(value: &PrefabValue) -> Vec<u8> {
+    let mut strings = StringTable::default();
+    strings.collect(value);
+    let mut result = Vec::with_capacity(1024);
+    result.extend_from_slice(MAGIC);
+    result.push(VERSION);
+    write_varint(&mut result, strings.list.len() as u64);
+    for string in &strings.list {
+        write_varint(&mut result, string.len() as u64);
+        result.extend_from_slice(string.as_bytes());
+    }
+    strings.write_value(&mut result, value);
+    result
+}
+
+/// Decodes prefab from binary form produced by [`encode`].
+pub fn decode(bytes: &[u8]) -> Result<PrefabValue, BinaryPrefabError> {
+    let mut reader = Reader { bytes, position: 0 };
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(BinaryPrefabError::InvalidMagic);
+    }
+    let version = reader.byte()?;
+    if version != VERSION {
+        return Err(BinaryPrefabError::UnsupportedVersion(version));
+    }
+    let count = reader.length()?;
+    let mut strings = Vec::with_capacity(count);
+    for _ in 0..count {
+        let length = reader.length()?;
+        let string = std::str::from_utf8(reader.take(length)?)
+            .map_err(|_| BinaryPrefabError::InvalidUtf8)?;
+        strings.push(string.to_owned());
+    }
+    let result = reader.value(&strings)?;
+    match bytes.len() - reader.position {
+        0 => Ok(result),
+        count => Err(BinaryPrefabError::TrailingBytes(count)),
+    }
+}
+
+#[derive(Default)]
+struct StringTable<'a> {
+    list: Vec<&'a str>,
+    indices: HashMap<&'a str, u64>,
+}
+
+impl<'a> StringTable<'a> {
+    fn intern(&mut self, string: &'a str) {
+        if !self.indices.contains_key(string) {
+            self.indices.insert(string, self.list.len() as u64);
+            self.list.push(string);
+        }
+    }
+
+    fn collect(&mut self, value: &'a PrefabValue) {
+        match value {
+            PrefabValue::String(string) => self.intern(string),
+            PrefabValue::Array(items) => {
+                for item in items {
+                    self.collect(item);
+                }
+            }
+            PrefabValue::Object(object) => {
+                for (key, item) in object {
+                    self.intern(key);
+                    self.collect(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn write_value(&self, result: &mut Vec<u8>, value: &PrefabValue) {
+        match value {
+            PrefabValue::Null => result.push(TAG_NULL),
+            PrefabValue::Bool(false) => result.push(TAG_FALSE),
+            PrefabValue::Bool(true) => result.push(TAG_TRUE),
+            PrefabValue::Number(number) => {
+                if let Some(number) = number.as_u64() {
+                    result.push(TAG_UNSIGNED);
+                    write_varint(result, number);
+                } else if let Some(number) = number.as_i64() {
+                    // Only negative numbers get here, stored as their magnitude minus one.
+                    result.push(TAG_NEGATIVE);
+                    write_varint(result, !(number as u64));
+                } else {
+                    let number = number.as_f64().unwrap_or_default();
+                    // Most of properties are `Scalar`s, these take half of the space.
+                    if number as f32 as f64 == number {
+                        result.push(TAG_SINGLE_FLOAT);
+                        result.extend_from_slice(&(number as f32).to_le_bytes());
+                    } else {
+                        result.push(TAG_FLOAT);
+                        result.extend_from_slice(&number.to_le_bytes());
+                    }
+                }
+            }
+            PrefabValue::String(string) => {
+                result.push(TAG_STRING);
+                write_varint(result, self.indices[string.as_str()]);
+            }
+            PrefabValue::Array(items) => {
+                result.push(TAG_ARRAY);
+                write_varint(result, items.len() as u64);
+                for item in items {
+                    self.write_value(result, item);
+                }
+            }
+            PrefabValue::Object(object) => {
+                result.push(TAG_OBJECT);
+                write_varint(result, object.len() as u64);
+                for (key, item) in object {
+                    write_varint(result, self.indices[key.as_str()]);
+                    self.write_value(result, item);
+                }
+            }
+        }
+    }
+}
+
+fn write_varint(result: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        result.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    result.push(value as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], BinaryPrefabError> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(BinaryPrefabError::UnexpectedEnd)?;
+        let result = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(result)
+    }
+
+    fn byte(&mut self) -> Result<u8, BinaryPrefabError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64, BinaryPrefabError> {
+        let mut result = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7f) as u64;
+            if shift == 63 && bits > 1 {
+                return Err(BinaryPrefabError::InvalidVarint);
+            }
+            result |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(BinaryPrefabError::InvalidVarint)
+    }
+
+    /// Reads length of a sequence, bounded by remaining bytes to not over-allocate on
+    /// corrupted data, since every element takes at least one byte.
+    fn length(&mut self) -> Result<usize, BinaryPrefabError> {
+        let length = self.varint()?;
+        if length > (self.bytes.len() - self.position) as u64 {
+            return Err(BinaryPrefabError::UnexpectedEnd);
+        }
+        Ok(length as usize)
+    }
+
+    fn string(&mut self, strings: &[String]) -> Result<String, BinaryPrefabError> {
+        let index = self.varint()? as usize;
+        strings
+            .get(index)
+            .cloned()
+            .ok_or(BinaryPrefabError::InvalidStringIndex(index))
+    }
+
+    fn value(&mut self, strings: &[String]) -> Result<PrefabValue, BinaryPrefabError> {
+        Ok(match self.byte()? {
+            TAG_NULL => PrefabValue::Null,
+            TAG_FALSE => PrefabValue::Bool(false),
+            TAG_TRUE => PrefabValue::Bool(true),
+            TAG_UNSIGNED => PrefabValue::Number(self.varint()?.into()),
+            TAG_NEGATIVE => PrefabValue::Number((!self.varint()? as i64).into()),
+            TAG_FLOAT => {
+                let bytes = self.take(8)?.try_into().unwrap();
+                PrefabValue::Number(
+                    Number::from_f64(f64::from_le_bytes(bytes))
+                        .ok_or(BinaryPrefabError::InvalidNumber)?,
+                )
+            }
+            TAG_SINGLE_FLOAT => {
+                let bytes = self.take(4)?.try_into().unwrap();
+                PrefabValue::Number(
+                    Number::from_f64(f32::from_le_bytes(bytes) as f64)
+                        .ok_or(BinaryPrefabError::InvalidNumber)?,
+                )
+            }
+            TAG_STRING => PrefabValue::String(self.string(strings)?),
+            TAG_ARRAY => {
+                let length = self.length()?;
+                let mut items = Vec::with_capacity(length);
+                for _ in 0..length {
+                    items.push(self.value(strings)?);
+                }
+                PrefabValue::Array(items)
+            }
+            TAG_OBJECT => {
+                let length = self.length()?;
+                let mut object = Map::new();
+                for _ in 0..length {
+                    let key = self.string(strings)?;
+                    object.insert(key, self.value(strings)?);
+                }
+                PrefabValue::Object(object)
+            }
+            tag => return Err(BinaryPrefabError::InvalidTag(tag)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip() {
+        let value = json!({
+            "null": null,
+            "bools": [true, false],
+            "numbers": [0, 127, 128, u64::MAX, -1, i64::MIN, 0.5, 0.1, -1.0e100],
+            "strings": ["", "zażółć", "null", "null"],
+            "nested": { "": [[], {}] },
+        });
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+
+        let bytes = encode(&json!(["text_box", "text_box", "text_box"]));
+        assert_eq!(bytes.len(), 4 + 1 + 10 + 2 + 3 * 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(decode(b"JSON\x01"), Err(BinaryPrefabError::InvalidMagic));
+        assert_eq!(
+            decode(b"RAUI\x02"),
+            Err(BinaryPrefabError::UnsupportedVersion(2))
+        );
+        let bytes = encode(&json!({"a": [1, 2, 3]}));
+        for length in 0..bytes.len() {
+            assert!(decode(&bytes[..length]).is_err());
+        }
+        assert_eq!(
+            decode(b"RAUI\x01\x00\x07\x00"),
+            Err(BinaryPrefabError::InvalidStringIndex(0))
+        );
+        assert_eq!(
+            decode(b"RAUI\x01\x00\x00\x00"),
+            Err(BinaryPrefabError::TrailingBytes(1))
+        );
+        assert_eq!(
+            decode(b"RAUI\x01\x00\x0a"),
+            Err(BinaryPrefabError::InvalidTag(10))
+        );
+    }
+}
@@ -0,0 +1,1068 @@
+//! Properties of prefab components bound to view models.
+//!
+//! Value of any property field can be replaced with a placeholder, resolved against
+//! [`ViewModelCollection`] whenever the component gets processed:
+//!
+//! - `{"$bind": "expression"}` is replaced with value of expression.
+//! - `{"$format": "text {expression} text"}` is replaced with text where each expression in
+//!   braces is replaced with its value. Numbers can be given precision: `{player.speed:.2}`, and
+//!   braces are escaped by doubling them.
+//!
+//! Expressions are made of:
+//!
+//! - Literals: `null`, `true`, `false`, numbers and strings in single or double quotes.
+//! - Property paths: `view_model.field.0.field`, reading from view model
+//!   [serialized][crate::view_model::ViewModel::serializable] value. Missing fields read
+//!   as `null`.
+//! - Operators, from lowest precedence: conditional `a ? b : c`, `||`, `&&`, comparisons
+//!   (`==`, `!=`, `<`, `<=`, `>`, `>=`), `+` (which also joins text), `-`, `*`, `/`, `%`,
+//!   unary `!` and `-`, and parentheses. `||` and `&&` give one of their operands, so
+//!   `player.name || 'Anonymous'` falls back to default text.
+//!
+//! Component gets bound to every view model property it reads, so notifying that property
+//! re-renders it. Property is the first field of a path, and since notifying whole view model
+//! with [`write_notified`][crate::view_model::ViewModel::write_notified] uses unnamed
+//! property, component gets bound to that one too.
+//!
+//! ```
+//! # use raui_core::{
+//! #     application::Application,
+//! #     view_model::{ViewModel, ViewModelValue},
+//! # };
+//! # use serde::Serialize;
+//! # use serde_json::json;
+//! #[derive(Serialize)]
+//! struct Player {
+//!     name: String,
+//!     health: ViewModelValue<f32>,
+//! }
+//!
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! application.view_models.insert(
+//!     "player".to_owned(),
+//!     ViewModel::produce(|properties| Player {
+//!         name: "Hero".to_owned(),
+//!         health: ViewModelValue::new(0.5, properties.notifier("health")),
+//!     })
+//!     .serializable::<Player>(),
+//! );
+//! let node = application
+//!     .deserialize_node(json!({
+//!         "Component": {
+//!             "type_name": "text_box",
+//!             "props": { "data": { "TextBoxProps": {
+//!                 "text": {
+//!                     "$format": "{player.name}: {player.health > 0.25 ? 'fine' : 'hurt'}"
+//!                 }
+//!             } } }
+//!         }
+//!     }))
+//!     .unwrap();
+//! application.apply(node);
+//! application.process();
+//! assert!(application.binding_errors().is_empty());
+//! ```
+
+use crate::{PrefabValue, PropsData, view_model::ViewModelCollection};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, json};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingError {
+    /// Expression could not be parsed, with byte offset into its source.
+    Syntax {
+        offset: usize,
+        message: String,
+    },
+    ViewModelNotFound(String),
+    /// View model is not [serializable][crate::view_model::ViewModel::serializable].
+    ViewModelNotSerializable(String),
+    InvalidOperands(String),
+    InvalidPlaceholder(String),
+    /// Resolved value does not deserialize into bound properties.
+    InvalidProps(String),
+}
+
+impl std::fmt::Display for BindingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax { offset, message } => write!(f, "{offset}: {message}"),
+            Self::ViewModelNotFound(name) => write!(f, "Could not find view model: {name:?}"),
+            Self::ViewModelNotSerializable(name) => {
+                write!(f, "View model is not serializable: {name:?}")
+            }
+            Self::InvalidOperands(message) => write!(f, "{message}"),
+            Self::InvalidPlaceholder(message) => write!(f, "{message}"),
+            Self::InvalidProps(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Properties with binding placeholders, by their registered names.
+///
+/// Application puts them into component properties when deserializing prefab, and replaces
+/// them with resolved properties when processing component.
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct PropsBindings {
+    #[serde(default)]
+    pub data: HashMap<String, PrefabValue>,
+}
+
+/// Tells if value contains any binding placeholder.
+pub fn has_bindings(value: &PrefabValue) -> bool {
+    match value {
+        PrefabValue::Array(items) => items.iter().any(has_bindings),
+        PrefabValue::Object(object) => {
+            placeholder(value).is_some() || object.values().any(has_bindings)
+        }
+        _ => false,
+    }
+}
+
+/// Parses all placeholders of value, reporting the first invalid one with JSON pointer to it.
+pub fn check(value: &PrefabValue) -> Result<(), (String, BindingError)> {
+    fn walk(value: &PrefabValue, path: &str) -> Result<(), (String, BindingError)> {
+        if let Some(placeholder) = placeholder(value) {
+            return Placeholder::parse(placeholder)
+                .map(|_| ())
+                .map_err(|error| (path.to_owned(), error));
+        }
+        match value {
+            PrefabValue::Array(items) => items.iter().enumerate().try_for_each(|(index, item)| {
+                walk(
+                    item,
+                    &crate::validation::pointer_join(path, &index.to_string()),
+                )
+            }),
+            PrefabValue::Object(object) => object.iter().try_for_each(|(key, item)| {
+                walk(item, &crate::validation::pointer_join(path, key))
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    walk(value, "")
+}
+
+/// Replaces all placeholders of value with their resolved values.
+pub fn resolve(
+    value: &PrefabValue,
+    view_models: &ViewModelCollection,
+) -> Result<PrefabValue, BindingError> {
+    BindingContext::new(view_models).resolve(value)
+}
+
+/// Name of placeholder definition in prefab schema.
+const PLACEHOLDER_SCHEMA: &str = "PropsBindingPlaceholder";
+
+/// Lets every field of properties schema be given a placeholder instead, including fields of
+/// definitions it refers to, so bound prefabs pass the schema. Definitions are shared, so
+/// widget unit prefabs using them accept placeholders too.
+pub(crate) fn allow_placeholders(props: &mut PrefabValue, definitions: &mut PrefabValue) {
+    fn allow(schema: &mut PrefabValue) {
+        *schema = json!({
+            "anyOf": [
+                std::mem::take(schema),
+                { "$ref": format!("#/$defs/{PLACEHOLDER_SCHEMA}") }
+            ]
+        });
+    }
+
+    fn walk(schema: &mut PrefabValue, references: &mut Vec<String>) {
+        let Some(object) = schema.as_object_mut() else {
+            return;
+        };
+        if let Some(name) = object
+            .get("$ref")
+            .and_then(|reference| reference.as_str())
+            .and_then(|reference| reference.strip_prefix("#/$defs/"))
+        {
+            references.push(name.to_owned());
+        }
+        for (key, value) in object {
+            match key.as_str() {
+                "properties" | "patternProperties" => {
+                    for field in value
+                        .as_object_mut()
+                        .into_iter()
+                        .flat_map(|v| v.values_mut())
+                    {
+                        walk(field, references);
+                        allow(field);
+                    }
+                }
+                "items" | "additionalProperties" if value.is_object() => {
+                    walk(value, references);
+                    allow(value);
+                }
+                "prefixItems" => {
+                    for item in value.as_array_mut().into_iter().flatten() {
+                        walk(item, references);
+                        allow(item);
+                    }
+                }
+                "anyOf" | "oneOf" | "allOf" => {
+                    for item in value.as_array_mut().into_iter().flatten() {
+                        walk(item, references);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut references = vec![];
+    walk(props, &mut references);
+    let mut visited = std::collections::HashSet::new();
+    while let Some(name) = references.pop() {
+        if visited.insert(name.clone())
+            && let Some(definition) = definitions.get_mut(&name)
+        {
+            walk(definition, &mut references);
+        }
+    }
+    definitions[PLACEHOLDER_SCHEMA] = json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": { "$bind": { "type": "string" } },
+                "required": ["$bind"],
+                "additionalProperties": false
+            },
+            {
+                "type": "object",
+                "properties": { "$format": { "type": "string" } },
+                "required": ["$format"],
+                "additionalProperties": false
+            }
+        ]
+    });
+}
+
+fn placeholder(value: &PrefabValue) -> Option<(&str, &PrefabValue)> {
+    let object = value.as_object().filter(|object| object.len() == 1)?;
+    let (key, value) = object.iter().next()?;
+    matches!(key.as_str(), "$bind" | "$format").then_some((key.as_str(), value))
+}
+
+/// Resolves placeholders, collecting view model properties that were read.
+pub(crate) struct BindingContext<'a> {
+    view_models: &'a ViewModelCollection,
+    cache: HashMap<String, PrefabValue>,
+    /// View model and property pairs.
+    pub bound: Vec<(String, String)>,
+}
+
+impl<'a> BindingContext<'a> {
+    pub fn new(view_models: &'a ViewModelCollection) -> Self {
+        Self {
+            view_models,
+            cache: Default::default(),
+            bound: Default::default(),
+        }
+    }
+
+    pub fn resolve(&mut self, value: &PrefabValue) -> Result<PrefabValue, BindingError> {
+        if let Some(placeholder) = placeholder(value) {
+            return match Placeholder::parse(placeholder)? {
+                Placeholder::Bind(expression) => self.evaluate(&expression),
+                Placeholder::Format(parts) => {
+                    let mut result = String::new();
+                    for part in parts {
+                        match part {
+                            FormatPart::Text(text) => result.push_str(&text),
+                            FormatPart::Expression(expression, precision) => {
+                                let value = self.evaluate(&expression)?;
+                                result.push_str(&display(&value, precision));
+                            }
+                        }
+                    }
+                    Ok(PrefabValue::String(result))
+                }
+            };
+        }
+        Ok(match value {
+            PrefabValue::Array(items) => PrefabValue::Array(
+                items
+                    .iter()
+                    .map(|item| self.resolve(item))
+                    .collect::<Result<_, _>>()?,
+            ),
+            PrefabValue::Object(object) => PrefabValue::Object(
+                object
+                    .iter()
+                    .map(|(key, item)| Ok((key.to_owned(), self.resolve(item)?)))
+                    .collect::<Result<_, BindingError>>()?,
+            ),
+            value => value.to_owned(),
+        })
+    }
+
+    /// Resolves placeholders like [`Self::resolve`], but leaves out values of the ones that fail,
+    /// so their fields can fall back to defaults, and reports them with JSON pointer to each.
+    /// Arrays with failed items are left out as a whole.
+    pub fn resolve_partial(
+        &mut self,
+        value: &PrefabValue,
+        path: &str,
+        errors: &mut Vec<(String, BindingError)>,
+    ) -> Option<PrefabValue> {
+        if placeholder(value).is_some() {
+            return match self.resolve(value) {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    errors.push((path.to_owned(), error));
+                    None
+                }
+            };
+        }
+        match value {
+            PrefabValue::Array(items) => {
+                let count = errors.len();
+                let items = items
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        let path = crate::validation::pointer_join(path, &index.to_string());
+                        self.resolve_partial(item, &path, errors)
+                    })
+                    .collect();
+                (errors.len() == count).then_some(PrefabValue::Array(items))
+            }
+            PrefabValue::Object(object) => Some(PrefabValue::Object(
+                object
+                    .iter()
+                    .filter_map(|(key, item)| {
+                        let path = crate::validation::pointer_join(path, key);
+                        Some((key.to_owned(), self.resolve_partial(item, &path, errors)?))
+                    })
+                    .collect(),
+            )),
+            value => Some(value.to_owned()),
+        }
+    }
+
+    fn read(&mut self, view_model: &str, fields: &[String]) -> Result<PrefabValue, BindingError> {
+        let property = fields.first().cloned().unwrap_or_default();
+        for property in [property, Default::default()] {
+            let pair = (view_model.to_owned(), property);
+            if !self.bound.contains(&pair) {
+                self.bound.push(pair);
+            }
+        }
+        if !self.cache.contains_key(view_model) {
+            let value = self
+                .view_models
+                .get(view_model)
+                .ok_or_else(|| BindingError::ViewModelNotFound(view_model.to_owned()))?
+                .to_prefab()
+                .ok_or_else(|| BindingError::ViewModelNotSerializable(view_model.to_owned()))?;
+            self.cache.insert(view_model.to_owned(), value);
+        }
+        let mut value = &self.cache[view_model];
+        for field in fields {
+            let next = match value {
+                PrefabValue::Object(object) => object.get(field),
+                PrefabValue::Array(items) => field
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| items.get(index)),
+                _ => None,
+            };
+            match next {
+                Some(next) => value = next,
+                None => return Ok(PrefabValue::Null),
+            }
+        }
+        Ok(value.to_owned())
+    }
+
+    fn evaluate(&mut self, expression: &Expression) -> Result<PrefabValue, BindingError> {
+        Ok(match expression {
+            Expression::Literal(value) => value.to_owned(),
+            Expression::Path { view_model, fields } => self.read(view_model, fields)?,
+            Expression::Not(value) => PrefabValue::Bool(!truthy(&self.evaluate(value)?)),
+            Expression::Negate(value) => match self.evaluate(value)? {
+                PrefabValue::Number(number) => match number.as_i64() {
+                    Some(number) if number != i64::MIN => (-number).into(),
+                    _ => float(-number.as_f64().unwrap_or_default())?,
+                },
+                value => {
+                    return Err(BindingError::InvalidOperands(format!(
+                        "Cannot negate: {value}"
+                    )));
+                }
+            },
+            Expression::Binary(operator, a, b) => match operator {
+                Operator::Or => {
+                    let a = self.evaluate(a)?;
+                    if truthy(&a) { a } else { self.evaluate(b)? }
+                }
+                Operator::And => {
+                    let a = self.evaluate(a)?;
+                    if truthy(&a) { self.evaluate(b)? } else { a }
+                }
+                operator => {
+                    let a = self.evaluate(a)?;
+                    let b = self.evaluate(b)?;
+                    operator.apply(a, b)?
+                }
+            },
+            Expression::Conditional(condition, a, b) => {
+                if truthy(&self.evaluate(condition)?) {
+                    self.evaluate(a)?
+                } else {
+                    self.evaluate(b)?
+                }
+            }
+        })
+    }
+}
+
+fn truthy(value: &PrefabValue) -> bool {
+    match value {
+        PrefabValue::Null => false,
+        PrefabValue::Bool(value) => *value,
+        PrefabValue::Number(number) => number.as_f64().unwrap_or_default() != 0.0,
+        PrefabValue::String(text) => !text.is_empty(),
+        PrefabValue::Array(items) => !items.is_empty(),
+        PrefabValue::Object(_) => true,
+    }
+}
+
+fn display(value: &PrefabValue, precision: Option<usize>) -> String {
+    match (value, precision) {
+        (PrefabValue::Null, _) => Default::default(),
+        (PrefabValue::String(text), _) => text.to_owned(),
+        (PrefabValue::Number(number), Some(precision)) => {
+            format!("{:.precision$}", number.as_f64().unwrap_or_default())
+        }
+        (value, _) => value.to_string(),
+    }
+}
+
+fn float(value: f64) -> Result<PrefabValue, BindingError> {
+    Number::from_f64(value)
+        .map(PrefabValue::Number)
+        .ok_or_else(|| BindingError::InvalidOperands(format!("Result is not finite: {value}")))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Operator {
+    /// Operators by descending length of their symbols, to match longest first.
+    const SYMBOLS: [(&'static str, Self); 13] = [
+        ("||", Self::Or),
+        ("&&", Self::And),
+        ("==", Self::Equal),
+        ("!=", Self::NotEqual),
+        ("<=", Self::LessOrEqual),
+        (">=", Self::GreaterOrEqual),
+        ("<", Self::Less),
+        (">", Self::Greater),
+        ("+", Self::Add),
+        ("-", Self::Subtract),
+        ("*", Self::Multiply),
+        ("/", Self::Divide),
+        ("%", Self::Remainder),
+    ];
+
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Or => 1,
+            Self::And => 2,
+            Self::Equal
+            | Self::NotEqual
+            | Self::Less
+            | Self::LessOrEqual
+            | Self::Greater
+            | Self::GreaterOrEqual => 3,
+            Self::Add | Self::Subtract => 4,
+            Self::Multiply | Self::Divide | Self::Remainder => 5,
+        }
+    }
+
+    fn apply(self, a: PrefabValue, b: PrefabValue) -> Result<PrefabValue, BindingError> {
+        let invalid = |a: &PrefabValue, b: &PrefabValue| {
+            BindingError::InvalidOperands(format!("Cannot apply {self:?} to: {a} and {b}"))
+        };
+        Ok(match (self, &a, &b) {
+            (Self::Equal, _, _) => PrefabValue::Bool(equals(&a, &b)),
+            (Self::NotEqual, _, _) => PrefabValue::Bool(!equals(&a, &b)),
+            (Self::Add, PrefabValue::String(_), _) | (Self::Add, _, PrefabValue::String(_)) => {
+                PrefabValue::String(display(&a, None) + &display(&b, None))
+            }
+            (_, PrefabValue::String(x), PrefabValue::String(y)) => {
+                let ordering = x.cmp(y);
+                PrefabValue::Bool(match self {
+                    Self::Less => ordering.is_lt(),
+                    Self::LessOrEqual => ordering.is_le(),
+                    Self::Greater => ordering.is_gt(),
+                    Self::GreaterOrEqual => ordering.is_ge(),
+                    _ => return Err(invalid(&a, &b)),
+                })
+            }
+            (_, PrefabValue::Number(x), PrefabValue::Number(y)) => {
+                if let (Some(x), Some(y)) = (x.as_i64(), y.as_i64()) {
+                    let result = match self {
+                        Self::Add => x.checked_add(y),
+                        Self::Subtract => x.checked_sub(y),
+                        Self::Multiply => x.checked_mul(y),
+                        Self::Divide if x.checked_rem(y) == Some(0) => x.checked_div(y),
+                        Self::Remainder => x.checked_rem(y),
+                        _ => None,
+                    };
+                    if let Some(result) = result {
+                        return Ok(result.into());
+                    }
+                }
+                let x = x.as_f64().unwrap_or_default();
+                let y = y.as_f64().unwrap_or_default();
+                match self {
+                    Self::Less => PrefabValue::Bool(x < y),
+                    Self::LessOrEqual => PrefabValue::Bool(x <= y),
+                    Self::Greater => PrefabValue::Bool(x > y),
+                    Self::GreaterOrEqual => PrefabValue::Bool(x >= y),
+                    Self::Add => float(x + y)?,
+                    Self::Subtract => float(x - y)?,
+                    Self::Multiply => float(x * y)?,
+                    Self::Divide => float(x / y)?,
+                    Self::Remainder => float(x % y)?,
+                    _ => return Err(invalid(&a, &b)),
+                }
+            }
+            _ => return Err(invalid(&a, &b)),
+        })
+    }
+}
+
+fn equals(a: &PrefabValue, b: &PrefabValue) -> bool {
+    match (a, b) {
+        (PrefabValue::Number(a), PrefabValue::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+    Literal(PrefabValue),
+    Path {
+        view_model: String,
+        fields: Vec<String>,
+    },
+    Not(Box<Expression>),
+    Negate(Box<Expression>),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FormatPart {
+    Text(String),
+    Expression(Expression, Option<usize>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Placeholder {
+    Bind(Expression),
+    Format(Vec<FormatPart>),
+}
+
+impl Placeholder {
+    fn parse((kind, source): (&str, &PrefabValue)) -> Result<Self, BindingError> {
+        let Some(source) = source.as_str() else {
+            return Err(BindingError::InvalidPlaceholder(format!(
+                "Expected text of {kind:?} placeholder"
+            )));
+        };
+        match kind {
+            "$bind" => Ok(Self::Bind(Parser::new(source, 0).parse()?)),
+            _ => Ok(Self::Format(Self::parse_format(source)?)),
+        }
+    }
+
+    fn parse_format(source: &str) -> Result<Vec<FormatPart>, BindingError> {
+        let mut result = vec![];
+        let mut text = String::new();
+        let mut position = 0;
+        while position < source.len() {
+            let rest = &source[position..];
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                text.push_str(&rest[..1]);
+                position += 2;
+            } else if rest.starts_with('{') {
+                let Some(end) = Self::find_closing_brace(rest) else {
+                    return Err(BindingError::Syntax {
+                        offset: position,
+                        message: "Expression is not closed".to_owned(),
+                    });
+                };
+                let mut expression = &rest[1..end];
+                let mut precision = None;
+                if let Some((head, digits)) = expression.rsplit_once(":.")
+                    && !digits.is_empty()
+                    && digits.bytes().all(|byte| byte.is_ascii_digit())
+                {
+                    expression = head;
+                    precision = digits.parse().ok();
+                }
+                if !text.is_empty() {
+                    result.push(FormatPart::Text(std::mem::take(&mut text)));
+                }
+                result.push(FormatPart::Expression(
+                    Parser::new(expression, position + 1).parse()?,
+                    precision,
+                ));
+                position += end + 1;
+            } else if rest.starts_with('}') {
+                return Err(BindingError::Syntax {
+                    offset: position,
+                    message: "Unexpected closing brace".to_owned(),
+                });
+            } else {
+                let character = rest.chars().next().unwrap();
+                text.push(character);
+                position += character.len_utf8();
+            }
+        }
+        if !text.is_empty() {
+            result.push(FormatPart::Text(text));
+        }
+        Ok(result)
+    }
+
+    /// Finds closing brace of expression, skipping braces in string literals.
+    fn find_closing_brace(source: &str) -> Option<usize> {
+        let mut quote = None;
+        for (index, character) in source.char_indices().skip(1) {
+            match (quote, character) {
+                (None, '\'' | '"') => quote = Some(character),
+                (Some(q), c) if q == c => quote = None,
+                (None, '}') => return Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    /// Offset of source in text reported in errors.
+    base: usize,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str, base: usize) -> Self {
+        Self {
+            source,
+            base,
+            position: 0,
+        }
+    }
+
+    fn error<T>(&self, message: impl ToString) -> Result<T, BindingError> {
+        Err(BindingError::Syntax {
+            offset: self.base + self.position,
+            message: message.to_string(),
+        })
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(symbol) {
+            self.position += symbol.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse(mut self) -> Result<Expression, BindingError> {
+        let result = self.conditional()?;
+        self.skip_whitespace();
+        if !self.rest().is_empty() {
+            return self.error("Unexpected trailing text");
+        }
+        Ok(result)
+    }
+
+    fn conditional(&mut self) -> Result<Expression, BindingError> {
+        let condition = self.binary(1)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let a = self.conditional()?;
+        if !self.eat(":") {
+            return self.error("Expected `:` of conditional");
+        }
+        let b = self.conditional()?;
+        Ok(Expression::Conditional(
+            Box::new(condition),
+            Box::new(a),
+            Box::new(b),
+        ))
+    }
+
+    fn operator(&mut self, precedence: u8) -> Option<Operator> {
+        self.skip_whitespace();
+        let (symbol, operator) = Operator::SYMBOLS
+            .iter()
+            .find(|(symbol, _)| self.rest().starts_with(symbol))?;
+        if operator.precedence() != precedence {
+            return None;
+        }
+        self.position += symbol.len();
+        Some(*operator)
+    }
+
+    fn binary(&mut self, precedence: u8) -> Result<Expression, BindingError> {
+        if precedence > 5 {
+            return self.unary();
+        }
+        let mut result = self.binary(precedence + 1)?;
+        while let Some(operator) = self.operator(precedence) {
+            let other = self.binary(precedence + 1)?;
+            result = Expression::Binary(operator, Box::new(result), Box::new(other));
+        }
+        Ok(result)
+    }
+
+    fn unary(&mut self) -> Result<Expression, BindingError> {
+        if self.eat("!") {
+            Ok(Expression::Not(Box::new(self.unary()?)))
+        } else if self.eat("-") {
+            Ok(Expression::Negate(Box::new(self.unary()?)))
+        } else {
+            self.primary()
+        }
+    }
+
+    fn identifier(&mut self) -> Option<&'a str> {
+        let rest = self.rest();
+        let length = rest
+            .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return None;
+        }
+        self.position += length;
+        Some(&rest[..length])
+    }
+
+    fn primary(&mut self) -> Result<Expression, BindingError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let Some(character) = rest.chars().next() else {
+            return self.error("Expected expression");
+        };
+        if self.eat("(") {
+            let result = self.conditional()?;
+            if !self.eat(")") {
+                return self.error("Expected `)`");
+            }
+            return Ok(result);
+        }
+        if character == '\'' || character == '"' {
+            let Some(end) = rest[1..].find(character) else {
+                return self.error("String is not closed");
+            };
+            self.position += end + 2;
+            return Ok(Expression::Literal(PrefabValue::String(
+                rest[1..end + 1].to_owned(),
+            )));
+        }
+        if character.is_ascii_digit() {
+            let length = rest
+                .find(|character: char| !(character.is_ascii_alphanumeric() || character == '.'))
+                .unwrap_or(rest.len());
+            let text = &rest[..length];
+            let value = match text.parse::<i64>() {
+                Ok(value) => value.into(),
+                Err(_) => match text.parse::<f64>() {
+                    Ok(value) => float(value)?,
+                    Err(_) => return self.error(format!("Invalid number: {text}")),
+                },
+            };
+            self.position += length;
+            return Ok(Expression::Literal(value));
+        }
+        let Some(view_model) = self.identifier() else {
+            return self.error(format!("Unexpected character: {character:?}"));
+        };
+        match view_model {
+            "null" => return Ok(Expression::Literal(PrefabValue::Null)),
+            "true" => return Ok(Expression::Literal(PrefabValue::Bool(true))),
+            "false" => return Ok(Expression::Literal(PrefabValue::Bool(false))),
+            _ => {}
+        }
+        let mut fields = vec![];
+        while self.rest().starts_with('.') {
+            self.position += 1;
+            let Some(field) = self.identifier() else {
+                return self.error("Expected field name");
+            };
+            fields.push(field.to_owned());
+        }
+        Ok(Expression::Path {
+            view_model: view_model.to_owned(),
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view_model::ViewModel;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Player {
+        name: String,
+        health: f32,
+        level: usize,
+        items: Vec<String>,
+    }
+
+    fn view_models() -> ViewModelCollection {
+        let mut view_models = ViewModelCollection::default();
+        view_models.insert(
+            "player".to_owned(),
+            ViewModel::new_object(Player {
+                name: "Hero".to_owned(),
+                health: 0.25,
+                level: 3,
+                items: vec!["sword".to_owned()],
+            })
+            .serializable::<Player>(),
+        );
+        view_models.insert("opaque".to_owned(), ViewModel::new_object(42));
+        view_models
+    }
+
+    fn bind(expression: &str) -> Result<PrefabValue, BindingError> {
+        resolve(&json!({ "$bind": expression }), &view_models())
+    }
+
+    #[test]
+    fn test_expressions() {
+        assert_eq!(bind("player.level * 2 + 1").unwrap(), json!(7));
+        assert_eq!(bind("player.level / 2").unwrap(), json!(1.5));
+        assert_eq!(bind("-(player.level - 4)").unwrap(), json!(1));
+        assert_eq!(bind("player.health < 0.5 && !false").unwrap(), json!(true));
+        assert_eq!(bind("player.level == 3.0").unwrap(), json!(true));
+        assert_eq!(bind("player.items.0").unwrap(), json!("sword"));
+        assert_eq!(bind("player.missing.field").unwrap(), json!(null));
+        assert_eq!(bind("player.missing || 'none'").unwrap(), json!("none"));
+        assert_eq!(
+            bind("player.level > 2 ? 'veteran' : player.level > 1 ? 'adept' : 'novice'").unwrap(),
+            json!("veteran")
+        );
+        assert_eq!(bind("'Lv' + player.level").unwrap(), json!("Lv3"));
+        assert_eq!(
+            bind("enemy.name"),
+            Err(BindingError::ViewModelNotFound("enemy".to_owned()))
+        );
+        assert_eq!(
+            bind("opaque"),
+            Err(BindingError::ViewModelNotSerializable("opaque".to_owned()))
+        );
+        assert!(matches!(
+            bind("player.name - 1"),
+            Err(BindingError::InvalidOperands(_))
+        ));
+        assert!(matches!(
+            bind("1 +"),
+            Err(BindingError::Syntax { offset: 3, .. })
+        ));
+        assert!(matches!(
+            bind("(1"),
+            Err(BindingError::Syntax { offset: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        let value = json!({
+            "text": { "$format": "{{{player.name}}} {player.health * 100:.1}% {player.level > 1 ? '}' : ''}" },
+            "color": [{ "$bind": "player.health" }, 0, 0],
+        });
+        assert!(has_bindings(&value));
+        assert!(!has_bindings(&json!({ "text": "{player.name}" })));
+        let view_models = view_models();
+        let mut context = BindingContext::new(&view_models);
+        assert_eq!(
+            context.resolve(&value).unwrap(),
+            json!({ "text": "{Hero} 25.0% }", "color": [0.25, 0, 0] })
+        );
+        assert!(
+            context
+                .bound
+                .contains(&("player".to_owned(), "health".to_owned()))
+        );
+        assert!(
+            context
+                .bound
+                .contains(&("player".to_owned(), "".to_owned()))
+        );
+        assert_eq!(
+            check(&json!({ "a": [{ "$format": "{player.name" }] })),
+            Err((
+                "/a/0".to_owned(),
+                BindingError::Syntax {
+                    offset: 0,
+                    message: "Expression is not closed".to_owned()
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_application() {
+        use crate::{
+            application::Application,
+            view_model::{ViewModelNotifier, ViewModelValue},
+            widget::unit::WidgetUnit,
+        };
+
+        #[derive(Serialize)]
+        struct Stats {
+            score: ViewModelValue<usize>,
+            #[serde(skip)]
+            _notifier: ViewModelNotifier,
+        }
+
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let view_model = ViewModel::produce(|properties| Stats {
+            score: ViewModelValue::new(0, properties.notifier("score")),
+            _notifier: properties.notifier("other"),
+        })
+        .serializable::<Stats>();
+        application
+            .view_models
+            .insert("stats".to_owned(), view_model);
+        let handle = application
+            .view_models
+            .get("stats")
+            .unwrap()
+            .lazy::<Stats>()
+            .unwrap();
+        let data = json!({
+            "Component": {
+                "type_name": "text_box",
+                "props": { "data": {
+                    "TextBoxProps": { "text": { "$format": "Score: {stats.score}" } }
+                } }
+            }
+        });
+        assert!(application.validate_node(&data).is_empty());
+        let node = application.deserialize_node(data.clone()).unwrap();
+        assert_eq!(application.serialize_node(&node).unwrap(), data);
+        application.apply(node);
+        let text = |application: &Application| match application.rendered_tree() {
+            WidgetUnit::TextBox(text_box) => text_box.text.to_owned(),
+            _ => Default::default(),
+        };
+        application.process();
+        assert_eq!(text(&application), "Score: 0");
+        while application.process() {}
+
+        *handle.write().unwrap().score += 5;
+        assert!(application.process());
+        assert_eq!(text(&application), "Score: 5");
+        assert!(application.binding_errors().is_empty());
+
+        application.view_models.remove("stats");
+        application.mark_dirty();
+        application.process();
+        assert_eq!(
+            application.binding_errors()[0].2,
+            BindingError::ViewModelNotFound("stats".to_owned())
+        );
+
+        let issues = application.validate_node(&json!({
+            "Component": {
+                "type_name": "text_box",
+                "props": { "data": { "TextBoxProps": { "text": { "$bind": "1 +" } } } }
+            }
+        }));
+        assert_eq!(issues[0].path, "/Component/props/data/TextBoxProps/text");
+    }
+
+    #[test]
+    fn test_partial_failure() {
+        use crate::{application::Application, widget::unit::WidgetUnit};
+
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let text_box = |text: PrefabValue| {
+            json!({
+                "Component": {
+                    "type_name": "text_box",
+                    "props": { "data": {
+                        "TextBoxProps": { "text": text, "font": { "name": "font", "size": 20.0 } }
+                    } }
+                }
+            })
+        };
+        let font_size = |application: &Application| match application.rendered_tree() {
+            WidgetUnit::TextBox(text_box) => text_box.font.size,
+            _ => Default::default(),
+        };
+
+        let node = application
+            .deserialize_node(text_box(json!({ "$bind": "stats.score" })))
+            .unwrap();
+        application.apply(node);
+        application.process();
+        assert_eq!(font_size(&application), 20.0);
+        let (_, name, error) = &application.binding_errors()[0];
+        assert_eq!(name, "TextBoxProps/text");
+        assert_eq!(error, &BindingError::ViewModelNotFound("stats".to_owned()));
+
+        let node = application
+            .deserialize_node(text_box(json!({ "$bind": "1 + 2" })))
+            .unwrap();
+        application.apply(node);
+        application.process();
+        let (_, name, error) = &application.binding_errors()[0];
+        assert_eq!(name, "TextBoxProps");
+        assert!(
+            error.to_string().starts_with("Could not deserialize: "),
+            "{error}"
+        );
+    }
+}
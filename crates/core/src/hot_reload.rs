@@ -0,0 +1,218 @@
+//! Reloading of widget tree from prefab or markup file while application is running.
+//!
+//! Reloaded tree gets applied only when it differs from [`Application::tree`], and since
+//! widgets keep their states, animators and view-model bindings as long as their [`WidgetId`]
+//! stays the same, only widgets that were added, removed, re-keyed or changed type get
+//! (re)mounted.
+//!
+//! ```rust,no_run
+//! # use raui_core::{application::Application, hot_reload::{HotReload, HotReloadFormat}};
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! let mut hot_reload = HotReload::new("ui/menu.xml", HotReloadFormat::Markup);
+//! loop {
+//!     if let Err(error) = hot_reload.maintain(&mut application) {
+//!         eprintln!("Could not reload UI: {error:?}");
+//!     }
+//!     application.process();
+//!     // layout, interact, render...
+//! }
+//! ```
+//!
+//! [`WidgetId`]: crate::widget::WidgetId
+
+use crate::{
+    PrefabError, PrefabValue,
+    application::{Application, ApplicationError},
+    markup::MarkupError,
+};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+#[derive(Debug, Clone)]
+pub enum HotReloadError {
+    Io(String),
+    Prefab(PrefabError),
+    Markup(MarkupError),
+    Application(ApplicationError),
+}
+
+impl From<PrefabError> for HotReloadError {
+    fn from(error: PrefabError) -> Self {
+        Self::Prefab(error)
+    }
+}
+
+impl From<MarkupError> for HotReloadError {
+    fn from(error: MarkupError) -> Self {
+        Self::Markup(error)
+    }
+}
+
+impl From<ApplicationError> for HotReloadError {
+    fn from(error: ApplicationError) -> Self {
+        Self::Application(error)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum HotReloadFormat {
+    /// JSON of widget node prefab, as produced by [`Application::serialize_node`].
+    #[default]
+    Prefab,
+    /// [Markup][crate::markup] source.
+    Markup,
+}
+
+impl HotReloadFormat {
+    /// Markup for `xml` and `raui` file extensions, prefab otherwise.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("xml" | "raui") => Self::Markup,
+            _ => Self::Prefab,
+        }
+    }
+}
+
+/// Polls file for modifications and applies its widget tree to application.
+#[derive(Debug, Clone)]
+pub struct HotReload {
+    path: PathBuf,
+    format: HotReloadFormat,
+    /// Minimal time between file modification checks.
+    pub interval: Duration,
+    last_check: Option<Instant>,
+    modified: Option<SystemTime>,
+}
+
+impl HotReload {
+    pub fn new(path: impl Into<PathBuf>, format: HotReloadFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            interval: Duration::from_millis(500),
+            last_check: None,
+            modified: None,
+        }
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn format(&self) -> HotReloadFormat {
+        self.format
+    }
+
+    /// Reloads file if it was modified since last load, checking it at most once per
+    /// [`interval`][Self::interval]. First call always loads it.
+    ///
+    /// Returns `true` if widget tree was applied to application. File that failed to load is
+    /// not retried until modified again.
+    pub fn maintain(&mut self, app: &mut Application) -> Result<bool, HotReloadError> {
+        let now = Instant::now();
+        if let Some(last_check) = self.last_check
+            && now.duration_since(last_check) < self.interval
+        {
+            return Ok(false);
+        }
+        self.last_check = Some(now);
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|error| HotReloadError::Io(error.to_string()))?;
+        if self.modified == Some(modified) {
+            return Ok(false);
+        }
+        self.modified = Some(modified);
+        self.reload(app)
+    }
+
+    /// Loads file regardless of its modification time.
+    ///
+    /// Returns `true` if loaded widget tree differed from [`Application::tree`] and was applied.
+    pub fn reload(&mut self, app: &mut Application) -> Result<bool, HotReloadError> {
+        let source = std::fs::read_to_string(&self.path)
+            .map_err(|error| HotReloadError::Io(error.to_string()))?;
+        let node = match self.format {
+            HotReloadFormat::Prefab => app.deserialize_node(
+                serde_json::from_str::<PrefabValue>(&source)
+                    .map_err(|error| PrefabError::CouldNotDeserialize(error.to_string()))?,
+            )?,
+            HotReloadFormat::Markup => app.deserialize_markup(&source)?,
+        };
+        // Trees built in code might not be serializable, these get always replaced.
+        let current = app.serialize_node(app.tree()).ok();
+        let loaded = app.serialize_node(&node)?;
+        if current.as_ref() == Some(&loaded) {
+            return Ok(false);
+        }
+        app.apply(node);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{
+        FnWidget, context::WidgetContext, node::WidgetNode, unit::area::AreaBoxNode,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static MOUNTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn counter(context: WidgetContext) -> WidgetNode {
+        context.life_cycle.mount(|context| {
+            MOUNTS.fetch_add(1, Ordering::SeqCst);
+            let _ = context.state.write(MOUNTS.load(Ordering::SeqCst));
+        });
+        AreaBoxNode::default().into()
+    }
+
+    #[test]
+    fn test_hot_reload() {
+        let path = std::env::temp_dir().join(format!("raui-hot-reload-{}.xml", std::process::id()));
+        std::fs::write(&path, r#"<content_box><counter key="a"/></content_box>"#).unwrap();
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application.register_component("counter", FnWidget::pointer(counter));
+        let mut hot_reload =
+            HotReload::new(&path, HotReloadFormat::from_path(&path)).interval(Duration::ZERO);
+        assert_eq!(hot_reload.format(), HotReloadFormat::Markup);
+        assert!(hot_reload.maintain(&mut application).unwrap());
+        application.process();
+        application.process();
+        assert_eq!(MOUNTS.load(Ordering::SeqCst), 1);
+        assert!(!hot_reload.maintain(&mut application).unwrap());
+        assert!(!hot_reload.reload(&mut application).unwrap());
+
+        std::fs::write(
+            &path,
+            r#"<content_box f32=1>
+                <counter key="a"/>
+                <counter key="b"/>
+            </content_box>"#,
+        )
+        .unwrap();
+        assert!(hot_reload.reload(&mut application).unwrap());
+        application.process();
+        application.process();
+        assert_eq!(MOUNTS.load(Ordering::SeqCst), 2);
+
+        std::fs::write(&path, r#"<content_box><counter"#).unwrap();
+        let error = hot_reload.reload(&mut application).unwrap_err();
+        assert!(matches!(error, HotReloadError::Markup(_)));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
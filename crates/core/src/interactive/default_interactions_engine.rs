@@ -29,6 +29,8 @@ pub enum Interaction {
     PointerDown(PointerButton, Vec2),
     PointerUp(PointerButton, Vec2),
     PointerMove(Vec2),
+    /// (pointer position, scroll delta in units)
+    PointerWheel(Vec2, Vec2),
 }
 
 impl Interaction {
@@ -78,8 +80,11 @@ pub struct DefaultInteractionsEngine {
     tracking: HashMap<WidgetId, WidgetId>,
     selected_chain: Vec<WidgetId>,
     locked_widget: Option<WidgetId>,
+    captured_widget: Option<WidgetId>,
     focused_text_input: Option<WidgetId>,
     sorted_items_ids: Vec<WidgetId>,
+    /// (scope, item selected before scope got registered)
+    focus_scopes: Vec<(WidgetId, Option<WidgetId>)>,
 }
 
 impl DefaultInteractionsEngine {
@@ -110,8 +115,10 @@ impl DefaultInteractionsEngine {
             tracking: HashMap::with_capacity(tracking),
             selected_chain: Vec::with_capacity(selected_chain),
             locked_widget: None,
+            captured_widget: None,
             focused_text_input: None,
             sorted_items_ids: vec![],
+            focus_scopes: vec![],
         }
     }
 
@@ -119,6 +126,15 @@ impl DefaultInteractionsEngine {
         self.locked_widget.as_ref()
     }
 
+    /// Widget receiving all pointer button actions until pointer gets released.
+    pub fn captured_widget(&self) -> Option<&WidgetId> {
+        self.captured_widget.as_ref()
+    }
+
+    pub fn release_pointer_capture(&mut self) {
+        self.captured_widget = None;
+    }
+
     pub fn selected_chain(&self) -> &[WidgetId] {
         &self.selected_chain
     }
@@ -141,6 +157,11 @@ impl DefaultInteractionsEngine {
             .find(|id| self.buttons.contains(id))
     }
 
+    /// Buttons registered for navigation, in no particular order.
+    pub fn buttons(&self) -> impl Iterator<Item = &WidgetId> {
+        self.buttons.iter()
+    }
+
     pub fn selected_scroll_view(&self) -> Option<&WidgetId> {
         self.selected_chain
             .iter()
@@ -159,6 +180,21 @@ impl DefaultInteractionsEngine {
         self.focused_text_input.as_ref()
     }
 
+    pub fn focus_scope(&self) -> Option<&WidgetId> {
+        self.focus_scopes.last().map(|(id, _)| id)
+    }
+
+    pub fn is_in_focus_scope(&self, id: &WidgetId) -> bool {
+        match self.focus_scope() {
+            Some(scope) => {
+                let path = id.path();
+                let scope = scope.path();
+                path == scope || (path.starts_with(scope) && path[scope.len()..].starts_with('/'))
+            }
+            None => true,
+        }
+    }
+
     pub fn interact(&mut self, interaction: Interaction) {
         if interaction.is_some() {
             self.interactions_queue.push_back(interaction);
@@ -218,6 +254,11 @@ impl DefaultInteractionsEngine {
         if self.locked_widget.is_some() || self.selected_chain.last() == id.as_ref() {
             return false;
         }
+        if let Some(id) = &id
+            && !self.is_in_focus_scope(id)
+        {
+            return false;
+        }
         if let Some(id) = &id
             && self.containers.contains_key(id)
         {
@@ -783,6 +824,7 @@ impl DefaultInteractionsEngine {
         let mut result = None;
         if let Some(data) = unit.as_data()
             && self.buttons.contains(data.id())
+            && self.is_in_focus_scope(data.id())
             && let Some(layout) = app.layout_data().items.get(data.id())
         {
             let rect = layout.ui_space;
@@ -894,6 +936,155 @@ impl DefaultInteractionsEngine {
         }
         false
     }
+
+    /// Scroll views under given point, from innermost to outermost.
+    pub fn find_scroll_views(&self, app: &Application, x: Scalar, y: Scalar) -> Vec<WidgetId> {
+        let mut result = vec![];
+        self.find_scroll_views_inner(
+            app,
+            x,
+            y,
+            app.rendered_tree(),
+            app.layout_data().ui_space,
+            &mut result,
+        );
+        // Later hits are rendered on top, so only ancestors of topmost one form a chain.
+        let Some(innermost) = result.pop() else {
+            return result;
+        };
+        let mut chain = result
+            .into_iter()
+            .filter(|id| innermost.is_superset_of(id))
+            .collect::<Vec<_>>();
+        chain.push(innermost);
+        chain.reverse();
+        chain
+    }
+
+    fn find_scroll_views_inner(
+        &self,
+        app: &Application,
+        x: Scalar,
+        y: Scalar,
+        unit: &WidgetUnit,
+        mut clip: Rect,
+        result: &mut Vec<WidgetId>,
+    ) {
+        if x < clip.left || x > clip.right || y < clip.top || y > clip.bottom {
+            return;
+        }
+        if let Some(data) = unit.as_data()
+            && self.scroll_views.contains(data.id())
+            && self.is_in_focus_scope(data.id())
+            && let Some(layout) = app.layout_data().items.get(data.id())
+        {
+            let rect = layout.ui_space;
+            if x >= rect.left && x <= rect.right && y >= rect.top && y <= rect.bottom {
+                result.push(data.id().to_owned());
+            }
+        }
+        match unit {
+            WidgetUnit::AreaBox(unit) => {
+                self.find_scroll_views_inner(app, x, y, &unit.slot, clip, result);
+            }
+            WidgetUnit::ContentBox(unit) => {
+                if unit.clipping
+                    && let Some(item) = app.layout_data().items.get(&unit.id)
+                {
+                    clip = item.ui_space;
+                }
+                for item in &unit.items {
+                    self.find_scroll_views_inner(app, x, y, &item.slot, clip, result);
+                }
+            }
+            WidgetUnit::FlexBox(unit) => {
+                for item in &unit.items {
+                    self.find_scroll_views_inner(app, x, y, &item.slot, clip, result);
+                }
+            }
+            WidgetUnit::GridBox(unit) => {
+                for item in &unit.items {
+                    self.find_scroll_views_inner(app, x, y, &item.slot, clip, result);
+                }
+            }
+            WidgetUnit::SizeBox(unit) => {
+                self.find_scroll_views_inner(app, x, y, &unit.slot, clip, result);
+            }
+            _ => {}
+        }
+    }
+
+    /// (scroll value, content overflow size) of scroll view, read from its layout.
+    fn scroll_view_offset(&self, app: &Application, id: &WidgetId) -> Option<(Vec2, Vec2)> {
+        let oid = self.find_scroll_view_content(id)?;
+        let view = app.layout_data().items.get(id)?.ui_space;
+        let content = app.layout_data().items.get(&oid)?.ui_space;
+        let view_size = view.size();
+        let content_size = content.size();
+        let overflow = Vec2 {
+            x: (content_size.x - view_size.x).max(0.0),
+            y: (content_size.y - view_size.y).max(0.0),
+        };
+        let value = Vec2 {
+            x: if overflow.x > 0.0 {
+                (view.left - content.left) / overflow.x
+            } else {
+                0.0
+            },
+            y: if overflow.y > 0.0 {
+                (view.top - content.top) / overflow.y
+            } else {
+                0.0
+            },
+        };
+        Some((value, overflow))
+    }
+
+    /// Scrolls innermost scroll view under pointer, passing the part of delta it cannot consume
+    /// to its parent scroll views. `pending` keeps values already applied this frame, since
+    /// layout is not recalculated between wheel interactions.
+    fn scroll_wheel(
+        &mut self,
+        app: &mut Application,
+        x: Scalar,
+        y: Scalar,
+        mut delta: Vec2,
+        pending: &mut HashMap<WidgetId, Vec2>,
+    ) -> bool {
+        let views = self.find_scroll_views(app, x, y);
+        let hovered = !views.is_empty();
+        for id in views {
+            if delta.x.abs() < 1.0e-6 && delta.y.abs() < 1.0e-6 {
+                break;
+            }
+            let Some((value, overflow)) = self.scroll_view_offset(app, &id) else {
+                continue;
+            };
+            let value = pending.get(&id).copied().unwrap_or(value);
+            let consume = |value: Scalar, overflow: Scalar, delta: Scalar| {
+                if overflow > 0.0 {
+                    let target = (value + delta / overflow).clamp(0.0, 1.0);
+                    (target, (target - value) * overflow)
+                } else {
+                    (value, 0.0)
+                }
+            };
+            let (vx, cx) = consume(value.x, overflow.x, delta.x);
+            let (vy, cy) = consume(value.y, overflow.y, delta.y);
+            if cx.abs() < 1.0e-6 && cy.abs() < 1.0e-6 {
+                continue;
+            }
+            pending.insert(id.to_owned(), Vec2 { x: vx, y: vy });
+            delta.x -= cx;
+            delta.y -= cy;
+            self.jump(
+                app,
+                &id,
+                NavJump::Scroll(NavScroll::Units(Vec2 { x: cx, y: cy }, true)),
+            );
+        }
+        hovered
+    }
 }
 
 impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteractionsEngine {
@@ -904,6 +1095,8 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
         let mut to_resize = HashSet::new();
         let mut to_relative_layout = HashSet::new();
         let mut to_select = None;
+        let mut to_restore = None;
+        let mut focus_scope_changed = false;
         let mut to_jump = HashMap::new();
         let mut to_focus = None;
         let mut to_send_axis = vec![];
@@ -979,6 +1172,12 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                                 self.tracking.insert(id.to_owned(), who);
                             }
                         }
+                        NavType::FocusScope => {
+                            self.focus_scopes.retain(|(scope, _)| scope != id);
+                            self.focus_scopes
+                                .push((id.to_owned(), self.selected_item().cloned()));
+                            focus_scope_changed = true;
+                        }
                     },
                     NavSignal::Unregister(t) => match t {
                         NavType::Container => {
@@ -999,9 +1198,19 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                             {
                                 self.locked_widget = None;
                             }
+                            if let Some(cid) = &self.captured_widget
+                                && cid == id
+                            {
+                                self.captured_widget = None;
+                            }
                         }
                         NavType::Button => {
                             self.buttons.remove(id);
+                            if let Some(cid) = &self.captured_widget
+                                && cid == id
+                            {
+                                self.captured_widget = None;
+                            }
                         }
                         NavType::TextInput => {
                             self.text_inputs.remove(id);
@@ -1020,6 +1229,19 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                         NavType::Tracking(_) => {
                             self.tracking.remove(id);
                         }
+                        NavType::FocusScope => {
+                            if let Some(index) =
+                                self.focus_scopes.iter().position(|(scope, _)| scope == id)
+                            {
+                                let (_, previous) = self.focus_scopes.remove(index);
+                                if let Some((_, next)) = self.focus_scopes.get_mut(index) {
+                                    *next = previous;
+                                } else {
+                                    to_restore = previous;
+                                    focus_scope_changed = true;
+                                }
+                            }
+                        }
                     },
                     NavSignal::Select(idref) => to_select = Some(idref.to_owned()),
                     NavSignal::Unselect => to_select = Some(().into()),
@@ -1035,6 +1257,15 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                             self.locked_widget = None;
                         }
                     }
+                    NavSignal::CapturePointer(idref) => {
+                        self.captured_widget = Some(idref.read().unwrap_or_else(|| id.to_owned()));
+                    }
+                    NavSignal::ReleasePointer(idref) => {
+                        let id = idref.read().unwrap_or_else(|| id.to_owned());
+                        if self.captured_widget.as_ref() == Some(&id) {
+                            self.captured_widget = None;
+                        }
+                    }
                     NavSignal::Jump(data) => {
                         to_jump.insert(id.to_owned(), data.to_owned());
                     }
@@ -1083,6 +1314,18 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
         if !to_jump.is_empty() {
             self.cache_sorted_items_ids(app);
         }
+        if focus_scope_changed {
+            let restore = to_restore.filter(|id| {
+                self.items_owners.contains_key(id) || self.containers.contains_key(id)
+            });
+            if restore.is_some() {
+                self.select_item(app, restore);
+            } else if let Some(id) = self.selected_item()
+                && !self.is_in_focus_scope(id)
+            {
+                self.select_item(app, None);
+            }
+        }
         if let Some(idref) = to_select {
             self.select_item(app, idref.read());
         }
@@ -1103,6 +1346,7 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
             }
         }
         let mut result = DefaultInteractionsEngineResult::default();
+        let mut scrolled = HashMap::new();
         while let Some(interaction) = self.interactions_queue.pop_front() {
             match interaction {
                 Interaction::None => {}
@@ -1152,7 +1396,9 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                     _ => {}
                 },
                 Interaction::PointerMove(Vec2 { x, y }) => {
-                    if self.locked_widget.is_some() {
+                    if self.captured_widget.is_some() {
+                        result.captured_pointer_location = true;
+                    } else if self.locked_widget.is_some() {
                         if self.selected_button().is_some() {
                             result.captured_pointer_location = true;
                         }
@@ -1209,7 +1455,15 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                     }
                 }
                 Interaction::PointerDown(button, Vec2 { x, y }) => {
-                    if let Some((found, _)) = self.find_button(app, x, y) {
+                    if let Some(id) = &self.captured_widget {
+                        result.captured_pointer_location = true;
+                        result.captured_pointer_action = true;
+                        let action = match button {
+                            PointerButton::Trigger => NavSignal::Accept(true),
+                            PointerButton::Context => NavSignal::Context(true),
+                        };
+                        app.send_message(id, action);
+                    } else if let Some((found, _)) = self.find_button(app, x, y) {
                         self.select_item(app, Some(found));
                         result.captured_pointer_location = true;
                         let action = match button {
@@ -1273,7 +1527,10 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                         PointerButton::Trigger => NavSignal::Accept(false),
                         PointerButton::Context => NavSignal::Context(false),
                     };
-                    if self.send_to_selected_button(app, action) {
+                    if let Some(id) = self.captured_widget.take() {
+                        app.send_message(&id, action);
+                        result.captured_pointer_action = true;
+                    } else if self.send_to_selected_button(app, action) {
                         result.captured_pointer_action = true;
                     }
                     for (id, who) in &self.tracking {
@@ -1317,8 +1574,357 @@ impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for DefaultInteract
                         }
                     }
                 }
+                Interaction::PointerWheel(Vec2 { x, y }, delta) => {
+                    if self.scroll_wheel(app, x, y, delta, &mut scrolled) {
+                        result.captured_pointer_location = true;
+                        result.captured_pointer_action = true;
+                    } else if self.does_hover_widget(app, x, y) {
+                        result.captured_pointer_location = true;
+                    }
+                }
             }
         }
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        layout::CoordsMapping,
+        pre_hooks,
+        tester::AppCycleTester,
+        widget::{
+            WidgetRef,
+            component::{
+                containers::{
+                    content_box::nav_content_box,
+                    horizontal_box::nav_horizontal_box,
+                    scroll_box::nav_scroll_box,
+                    size_box::{SizeBoxProps, size_box},
+                    vertical_box::{VerticalBoxProps, vertical_box},
+                },
+                interactive::{
+                    button::{ButtonNotifyMessage, ButtonNotifyProps, button},
+                    navigation::{NavItemActive, focus_scope},
+                    slider_view::slider_view,
+                },
+            },
+            context::WidgetContext,
+            node::WidgetNode,
+            unit::{flex::FlexBoxItemLayout, size::SizeBoxSizeValue},
+        },
+    };
+
+    fn use_grabber(context: &mut WidgetContext) {
+        context.life_cycle.change(|context| {
+            for msg in context.messenger.messages {
+                if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>()
+                    && msg.trigger_start()
+                {
+                    context
+                        .signals
+                        .write(NavSignal::CapturePointer(msg.sender.to_owned().into()));
+                }
+            }
+        });
+    }
+
+    #[pre_hooks(use_grabber)]
+    fn grabber(mut context: WidgetContext) -> WidgetNode {
+        make_widget!(button)
+            .key("button")
+            .with_props(NavItemActive)
+            .with_props(ButtonNotifyProps(context.id.to_owned().into()))
+            .named_slot("content", block(100.0))
+            .into()
+    }
+
+    fn list(items: Vec<WidgetNode>) -> WidgetNode {
+        make_widget!(vertical_box)
+            .with_props(VerticalBoxProps {
+                override_slots_layout: Some(FlexBoxItemLayout {
+                    grow: 0.0,
+                    shrink: 0.0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .listed_slots(items)
+            .into()
+    }
+
+    fn block(height: Scalar) -> WidgetNode {
+        make_widget!(size_box)
+            .with_props(SizeBoxProps {
+                width: SizeBoxSizeValue::Fill,
+                height: SizeBoxSizeValue::Exact(height),
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn tree(outside: &WidgetRef, inside: &WidgetRef, modal: bool) -> WidgetNode {
+        let mut root = make_widget!(nav_content_box).key("root").listed_slot(
+            make_widget!(button)
+                .idref(outside.clone())
+                .with_props(NavItemActive),
+        );
+        if modal {
+            root = root.listed_slot(
+                make_widget!(focus_scope).key("scope").named_slot(
+                    "content",
+                    make_widget!(button)
+                        .idref(inside.clone())
+                        .with_props(NavItemActive),
+                ),
+            );
+        }
+        root.into()
+    }
+
+    #[test]
+    fn test_focus_scope() {
+        let outside = WidgetRef::default();
+        let inside = WidgetRef::default();
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 100.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+
+        tester.application.apply(tree(&outside, &inside, false));
+        tester.run_frame(());
+        tester.run_frame(());
+        tester
+            .interactions_engine
+            .interact(Interaction::Navigate(NavSignal::Select(
+                outside.clone().into(),
+            )));
+        tester.run_frame(());
+        assert_eq!(
+            tester.interactions_engine.selected_item(),
+            outside.read().as_ref()
+        );
+
+        tester.application.apply(tree(&outside, &inside, true));
+        tester.run_frame(());
+        tester.run_frame(());
+        assert!(tester.interactions_engine.focus_scope().is_some());
+        assert_eq!(tester.interactions_engine.selected_item(), None);
+        tester
+            .interactions_engine
+            .interact(Interaction::Navigate(NavSignal::Select(
+                outside.clone().into(),
+            )));
+        tester.run_frame(());
+        assert_eq!(tester.interactions_engine.selected_item(), None);
+        tester
+            .interactions_engine
+            .interact(Interaction::Navigate(NavSignal::Select(
+                inside.clone().into(),
+            )));
+        tester.run_frame(());
+        assert_eq!(
+            tester.interactions_engine.selected_item(),
+            inside.read().as_ref()
+        );
+
+        tester.application.apply(tree(&outside, &inside, false));
+        tester.run_frame(());
+        tester.run_frame(());
+        assert!(tester.interactions_engine.focus_scope().is_none());
+        assert_eq!(
+            tester.interactions_engine.selected_item(),
+            outside.read().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_pointer_wheel_chaining() {
+        let outer = WidgetRef::default();
+        let inner = WidgetRef::default();
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 100.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+        tester.application.apply(
+            make_widget!(nav_content_box).key("root").listed_slot(
+                make_widget!(nav_scroll_box)
+                    .idref(outer.clone())
+                    .with_props(NavItemActive)
+                    .named_slot(
+                        "content",
+                        list(vec![
+                            make_widget!(size_box)
+                                .with_props(SizeBoxProps {
+                                    width: SizeBoxSizeValue::Fill,
+                                    height: SizeBoxSizeValue::Exact(100.0),
+                                    ..Default::default()
+                                })
+                                .named_slot(
+                                    "content",
+                                    make_widget!(nav_scroll_box)
+                                        .idref(inner.clone())
+                                        .with_props(NavItemActive)
+                                        .named_slot("content", list(vec![block(200.0)])),
+                                )
+                                .into(),
+                            block(200.0),
+                        ]),
+                    ),
+            ),
+        );
+        tester.run_frame(());
+        tester.run_frame(());
+        let outer = outer.read().unwrap();
+        let inner = inner.read().unwrap();
+        assert_eq!(
+            tester
+                .interactions_engine
+                .find_scroll_views(&tester.application, 50.0, 50.0),
+            vec![inner.clone(), outer.clone()]
+        );
+
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerWheel(
+                Vec2 { x: 50.0, y: 50.0 },
+                Vec2 { x: 0.0, y: 150.0 },
+            ));
+        tester.run_frame(());
+        tester.run_frame(());
+        tester.run_frame(());
+        let offset = |id: &WidgetId| {
+            tester
+                .interactions_engine
+                .scroll_view_offset(&tester.application, id)
+                .unwrap()
+        };
+        assert_eq!(
+            offset(&inner),
+            (Vec2 { x: 0.0, y: 1.0 }, Vec2 { x: 0.0, y: 100.0 })
+        );
+        assert_eq!(
+            offset(&outer),
+            (Vec2 { x: 0.0, y: 0.25 }, Vec2 { x: 0.0, y: 200.0 })
+        );
+    }
+
+    #[test]
+    fn test_pointer_capture() {
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 200.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+        tester.application.apply(
+            make_widget!(nav_horizontal_box)
+                .key("root")
+                .listed_slot(make_widget!(grabber).key("grabber"))
+                .listed_slot(
+                    make_widget!(button)
+                        .key("other")
+                        .with_props(NavItemActive)
+                        .named_slot("content", block(100.0)),
+                ),
+        );
+        tester.run_frame(());
+        tester.run_frame(());
+
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerDown(
+                PointerButton::Trigger,
+                Vec2 { x: 50.0, y: 50.0 },
+            ));
+        tester.run_frame(());
+        tester.run_frame(());
+        tester.run_frame(());
+        let grabbed = tester.interactions_engine.selected_item().cloned().unwrap();
+        assert_eq!(grabbed.key(), "button");
+        assert_eq!(tester.interactions_engine.captured_widget(), Some(&grabbed));
+
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerMove(Vec2 { x: 150.0, y: 50.0 }));
+        tester.run_frame(());
+        assert_eq!(tester.interactions_engine.selected_item(), Some(&grabbed));
+
+        tester.interactions_engine.interact(Interaction::PointerUp(
+            PointerButton::Trigger,
+            Vec2 { x: 150.0, y: 50.0 },
+        ));
+        tester.run_frame(());
+        assert_eq!(tester.interactions_engine.captured_widget(), None);
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerMove(Vec2 { x: 150.0, y: 50.0 }));
+        tester.run_frame(());
+        assert_eq!(
+            tester.interactions_engine.selected_item().unwrap().key(),
+            "other"
+        );
+    }
+
+    #[test]
+    fn test_drag_lock() {
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 100.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+        tester.application.apply(
+            make_widget!(nav_content_box).key("root").listed_slot(
+                make_widget!(slider_view)
+                    .key("slider")
+                    .with_props(NavItemActive)
+                    .named_slot("content", block(100.0)),
+            ),
+        );
+        tester.run_frame(());
+        tester.run_frame(());
+
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerDown(
+                PointerButton::Trigger,
+                Vec2 { x: 50.0, y: 50.0 },
+            ));
+        tester.run_frame(());
+        tester.run_frame(());
+        tester.run_frame(());
+        let slider = tester.interactions_engine.selected_item().cloned().unwrap();
+        assert_eq!(slider.key(), "slider");
+        assert_eq!(tester.interactions_engine.captured_widget(), Some(&slider));
+        assert_eq!(tester.interactions_engine.locked_widget(), Some(&slider));
+
+        tester.interactions_engine.interact(Interaction::PointerUp(
+            PointerButton::Trigger,
+            Vec2 { x: 50.0, y: 50.0 },
+        ));
+        tester.run_frame(());
+        tester.run_frame(());
+        tester.run_frame(());
+        assert_eq!(tester.interactions_engine.captured_widget(), None);
+        assert_eq!(tester.interactions_engine.locked_widget(), None);
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
         utils::{Rect, Vec2},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -35,7 +36,7 @@ impl std::fmt::Debug for LayoutSortedItems<'_> {
     }
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Layout {
     pub ui_space: Rect,
     pub items: HashMap<WidgetId, LayoutItem>,
@@ -115,7 +116,7 @@ impl Layout {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LayoutNode {
     pub id: WidgetId,
     pub local_space: Rect,
@@ -128,7 +129,7 @@ impl LayoutNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LayoutItem {
     pub local_space: Rect,
     pub ui_space: Rect,
@@ -162,7 +163,7 @@ impl LayoutEngine<()> for () {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum CoordsMappingScaling {
     #[default]
     None,
@@ -175,7 +176,7 @@ pub enum CoordsMappingScaling {
     FitToView(Vec2, bool),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct CoordsMapping {
     #[serde(default)]
     scale: Vec2,
@@ -3,7 +3,12 @@
 //! The things that most users will be interested in here are the [components][widget::component].
 //! Those have more documentation on how to use widgets, components, etc. in your app.
 
+extern crate self as raui_core;
+
+pub mod accessibility;
 pub mod application;
+pub mod binary;
+pub mod binding;
 #[macro_use]
 pub mod messenger;
 #[macro_use]
@@ -13,10 +18,16 @@ pub mod state;
 #[macro_use]
 pub mod widget;
 pub mod animator;
+pub mod hot_reload;
 pub mod interactive;
 pub mod layout;
+pub mod markup;
+pub mod registration;
 pub mod signals;
+pub mod style;
+pub mod template;
 pub mod tester;
+pub mod validation;
 pub mod view_model;
 
 pub type Scalar = f32;
@@ -25,6 +36,8 @@ pub type UnsignedInteger = u32;
 
 pub use intuicio_data::{lifetime::*, managed::*, managed_box::*, type_hash::*};
 pub use raui_derive::*;
+pub use schemars;
+use schemars::{Schema, SchemaGenerator};
 use serde::{Serialize, de::DeserializeOwned};
 #[doc(inline)]
 pub use serde_json::{Number as PrefabNumber, Value as PrefabValue};
@@ -55,6 +68,23 @@ pub trait Prefab: Serialize + DeserializeOwned {
             Err(error) => Err(PrefabError::CouldNotSerialize(error.to_string())),
         }
     }
+
+    /// JSON Schema of prefab data, accepting any value unless provided.
+    ///
+    /// Types deriving [`PropsData`][macro@crate::PropsData] provide it with `#[prefab_schema]`
+    /// attribute, using their `schemars::JsonSchema` implementation.
+    fn prefab_schema(_generator: &mut SchemaGenerator) -> Schema {
+        Schema::from(true)
+    }
+}
+
+impl std::fmt::Display for PrefabError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CouldNotSerialize(message) => write!(f, "Could not serialize: {message}"),
+            Self::CouldNotDeserialize(message) => write!(f, "Could not deserialize: {message}"),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -87,3 +117,15 @@ impl Logger for PrintLogger {
         println!("{kind:?} | {message}");
     }
 }
+
+/// Collects log messages, for tests to check what got reported.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct RecordingLogger(pub Vec<(LogKind, String)>);
+
+#[cfg(test)]
+impl Logger for RecordingLogger {
+    fn log(&mut self, kind: LogKind, message: &str) {
+        self.0.push((kind, message.to_owned()));
+    }
+}
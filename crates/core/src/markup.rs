@@ -0,0 +1,617 @@
+//! Human friendly markup format for widget trees.
+//!
+//! Markup is XML-like: elements are components named the same as in
+//! [`Application::register_component`], and attributes are properties named the same as in
+//! [`Application::register_props`], with values written as JSON:
+//!
+//! ```xml
+//! <!-- Comments are allowed anywhere between elements. -->
+//! <nav_vertical_box key="menu" VerticalBoxProps={"separation": 4}>
+//!     <button key="start" NavItemActive shared:ButtonNotifyProps={"id": "menu"}>
+//!         <text_box slot="content" TextBoxProps={"text": "Start"} />
+//!     </button>
+//! </nav_vertical_box>
+//! ```
+//!
+//! - `key` and `slot` attributes are reserved for widget key and named slot of parent, and
+//!   children without `slot` attribute go into listed slots.
+//! - Properties prefixed with `shared:` become shared properties.
+//! - Properties without value are set to `null`, which suits unit structs.
+//! - Multiple root elements make a tuple node, no root elements make an empty node.
+//!
+//! [`Application::register_component`]: crate::application::Application::register_component
+//! [`Application::register_props`]: crate::application::Application::register_props
+
+use crate::{
+    Prefab, PrefabError, PrefabValue,
+    props::PropsGroupPrefab,
+    widget::{component::WidgetComponentPrefab, node::WidgetNodePrefab},
+};
+use std::collections::HashMap;
+
+const KEY_ATTRIBUTE: &str = "key";
+const SLOT_ATTRIBUTE: &str = "slot";
+const SHARED_PREFIX: &str = "shared:";
+
+#[derive(Debug, Clone)]
+pub enum MarkupErrorKind {
+    UnexpectedEnd,
+    UnexpectedCharacter(char),
+    UnexpectedText,
+    UnclosedComment,
+    MismatchedClosingTag { expected: String, found: String },
+    DuplicateAttribute(String),
+    DuplicateSlot(String),
+    ExpectedString(String),
+    InvalidValue(String),
+    ComponentMappingNotFound(String),
+    Prefab(PrefabError),
+}
+
+impl std::fmt::Display for MarkupErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "Unexpected end of markup"),
+            Self::UnexpectedCharacter(character) => {
+                write!(f, "Unexpected character: {character:?}")
+            }
+            Self::UnexpectedText => write!(f, "Text is allowed only in attribute values"),
+            Self::UnclosedComment => write!(f, "Comment is not closed"),
+            Self::MismatchedClosingTag { expected, found } => {
+                write!(f, "Expected closing tag of {expected:?}, found: {found:?}")
+            }
+            Self::DuplicateAttribute(name) => write!(f, "Duplicate attribute: {name:?}"),
+            Self::DuplicateSlot(name) => write!(f, "Duplicate named slot: {name:?}"),
+            Self::ExpectedString(name) => write!(f, "Attribute {name:?} expects string value"),
+            Self::InvalidValue(message) => write!(f, "Invalid value: {message}"),
+            Self::ComponentMappingNotFound(name) => {
+                write!(f, "Component mapping not found: {name:?}")
+            }
+            Self::Prefab(PrefabError::CouldNotSerialize(message))
+            | Self::Prefab(PrefabError::CouldNotDeserialize(message)) => {
+                write!(f, "Invalid properties: {message}")
+            }
+        }
+    }
+}
+
+/// Markup error with 1-based line and column (in characters) of where it happened.
+#[derive(Debug, Clone)]
+pub struct MarkupError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: MarkupErrorKind,
+}
+
+impl MarkupError {
+    pub(crate) fn new(source: &str, offset: usize, kind: MarkupErrorKind) -> Self {
+        let before = &source[..offset.min(source.len())];
+        let line_start = before
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+impl std::fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MarkupAttribute {
+    pub offset: usize,
+    pub name: String,
+    pub value: PrefabValue,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MarkupElement {
+    pub offset: usize,
+    pub type_name: String,
+    pub key: Option<String>,
+    pub props: Vec<MarkupAttribute>,
+    pub shared_props: Vec<MarkupAttribute>,
+    pub listed_slots: Vec<MarkupElement>,
+    pub named_slots: Vec<(String, MarkupElement)>,
+}
+
+impl MarkupElement {
+    fn into_prefab(self) -> Result<WidgetComponentPrefab, PrefabError> {
+        let props_prefab = |props: Vec<MarkupAttribute>| {
+            PropsGroupPrefab {
+                data: props
+                    .into_iter()
+                    .map(|attribute| (attribute.name, attribute.value))
+                    .collect(),
+            }
+            .to_prefab()
+        };
+        Ok(WidgetComponentPrefab {
+            type_name: self.type_name,
+            key: self.key,
+            props: props_prefab(self.props)?,
+            shared_props: if self.shared_props.is_empty() {
+                None
+            } else {
+                Some(props_prefab(self.shared_props)?)
+            },
+            listed_slots: self
+                .listed_slots
+                .into_iter()
+                .map(|element| Ok(WidgetNodePrefab::Component(element.into_prefab()?)))
+                .collect::<Result<_, PrefabError>>()?,
+            named_slots: self
+                .named_slots
+                .into_iter()
+                .map(|(name, element)| {
+                    Ok((name, WidgetNodePrefab::Component(element.into_prefab()?)))
+                })
+                .collect::<Result<HashMap<_, _>, PrefabError>>()?,
+        })
+    }
+}
+
+/// Converts markup into [`PrefabValue`] of widget node, as accepted by
+/// [`Application::deserialize_node`].
+///
+/// Component and property names are not validated here - use
+/// [`Application::deserialize_markup`] to get errors pointing at markup for these too.
+///
+/// [`Application::deserialize_node`]: crate::application::Application::deserialize_node
+/// [`Application::deserialize_markup`]: crate::application::Application::deserialize_markup
+pub fn markup_to_prefab(source: &str) -> Result<PrefabValue, MarkupError> {
+    let mut elements = parse(source)?;
+    let prefab = match elements.len() {
+        0 => WidgetNodePrefab::None,
+        1 => WidgetNodePrefab::Component(
+            elements
+                .remove(0)
+                .into_prefab()
+                .map_err(|error| MarkupError::new(source, 0, MarkupErrorKind::Prefab(error)))?,
+        ),
+        _ => WidgetNodePrefab::Tuple(
+            elements
+                .into_iter()
+                .map(|element| {
+                    let offset = element.offset;
+                    element
+                        .into_prefab()
+                        .map(WidgetNodePrefab::Component)
+                        .map_err(|error| {
+                            MarkupError::new(source, offset, MarkupErrorKind::Prefab(error))
+                        })
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+    };
+    prefab
+        .to_prefab()
+        .map_err(|error| MarkupError::new(source, 0, MarkupErrorKind::Prefab(error)))
+}
+
+/// Parses root elements of markup.
+pub(crate) fn parse(source: &str) -> Result<Vec<MarkupElement>, MarkupError> {
+    let mut parser = Parser { source, offset: 0 };
+    let mut result = vec![];
+    loop {
+        parser.skip_misc()?;
+        match parser.peek() {
+            None => return Ok(result),
+            Some('<') => result.push(parser.element()?.1),
+            Some(_) => return Err(parser.error(MarkupErrorKind::UnexpectedText)),
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: MarkupErrorKind) -> MarkupError {
+        MarkupError::new(self.source, self.offset, kind)
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next(&mut self) -> Result<char, MarkupError> {
+        let character = self
+            .peek()
+            .ok_or_else(|| self.error(MarkupErrorKind::UnexpectedEnd))?;
+        self.offset += character.len_utf8();
+        Ok(character)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MarkupError> {
+        match self.peek() {
+            Some(character) if character == expected => {
+                self.offset += character.len_utf8();
+                Ok(())
+            }
+            Some(character) => Err(self.error(MarkupErrorKind::UnexpectedCharacter(character))),
+            None => Err(self.error(MarkupErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_misc(&mut self) -> Result<(), MarkupError> {
+        loop {
+            self.skip_whitespace();
+            if !self.rest().starts_with("<!--") {
+                return Ok(());
+            }
+            match self.rest().find("-->") {
+                Some(index) => self.offset += index + "-->".len(),
+                None => return Err(self.error(MarkupErrorKind::UnclosedComment)),
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, MarkupError> {
+        let length = self
+            .rest()
+            .find(|character: char| character.is_whitespace() || "<>/=\"'{}[]".contains(character))
+            .unwrap_or(self.rest().len());
+        if length == 0 {
+            return Err(match self.peek() {
+                Some(character) => self.error(MarkupErrorKind::UnexpectedCharacter(character)),
+                None => self.error(MarkupErrorKind::UnexpectedEnd),
+            });
+        }
+        let result = self.rest()[..length].to_owned();
+        self.offset += length;
+        Ok(result)
+    }
+
+    fn value(&mut self) -> Result<PrefabValue, MarkupError> {
+        let start = self.offset;
+        match self.peek() {
+            Some('"' | '{' | '[') => {
+                let mut stream =
+                    serde_json::Deserializer::from_str(self.rest()).into_iter::<PrefabValue>();
+                let result = stream.next();
+                let length = stream.byte_offset();
+                match result {
+                    Some(Ok(value)) => {
+                        self.offset += length;
+                        Ok(value)
+                    }
+                    Some(Err(error)) => Err(self.json_error(start, error)),
+                    None => Err(self.error(MarkupErrorKind::UnexpectedEnd)),
+                }
+            }
+            Some(_) => {
+                let length = self
+                    .rest()
+                    .find(|character: char| character.is_whitespace() || "<>/".contains(character))
+                    .unwrap_or(self.rest().len());
+                let token = &self.rest()[..length];
+                if token.is_empty() {
+                    return Err(self.error(MarkupErrorKind::UnexpectedCharacter(
+                        self.peek().unwrap_or_default(),
+                    )));
+                }
+                let result = serde_json::from_str(token);
+                self.offset += length;
+                result.map_err(|error| self.json_error(start, error))
+            }
+            None => Err(self.error(MarkupErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn json_error(&self, start: usize, error: serde_json::Error) -> MarkupError {
+        // JSON errors point at lines and byte columns of value source.
+        let value = &self.source[start..];
+        let line_start = value
+            .split_inclusive('\n')
+            .take(error.line().saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>();
+        let mut offset = start + line_start + error.column().saturating_sub(1);
+        while !self.source.is_char_boundary(offset.min(self.source.len())) {
+            offset -= 1;
+        }
+        let message = error.to_string();
+        let message = match message.rsplit_once(" at line ") {
+            Some((message, _)) => message.to_owned(),
+            None => message,
+        };
+        MarkupError::new(self.source, offset, MarkupErrorKind::InvalidValue(message))
+    }
+
+    fn string_attribute(
+        &self,
+        attribute: MarkupAttribute,
+        target: &mut Option<String>,
+    ) -> Result<(), MarkupError> {
+        if target.is_some() {
+            return Err(MarkupError::new(
+                self.source,
+                attribute.offset,
+                MarkupErrorKind::DuplicateAttribute(attribute.name),
+            ));
+        }
+        match attribute.value {
+            PrefabValue::String(value) => {
+                *target = Some(value);
+                Ok(())
+            }
+            _ => Err(MarkupError::new(
+                self.source,
+                attribute.offset,
+                MarkupErrorKind::ExpectedString(attribute.name),
+            )),
+        }
+    }
+
+    /// Parses element along with the named slot it goes into.
+    fn element(&mut self) -> Result<(Option<String>, MarkupElement), MarkupError> {
+        let offset = self.offset;
+        self.expect('<')?;
+        let type_name = self.name()?;
+        let mut result = MarkupElement {
+            offset,
+            type_name,
+            key: None,
+            props: vec![],
+            shared_props: vec![],
+            listed_slots: vec![],
+            named_slots: vec![],
+        };
+        let mut slot = None;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('/') => {
+                    self.next()?;
+                    self.expect('>')?;
+                    return Ok((slot, result));
+                }
+                Some('>') => {
+                    self.next()?;
+                    break;
+                }
+                _ => {}
+            }
+            let offset = self.offset;
+            let name = self.name()?;
+            self.skip_whitespace();
+            let value = if self.peek() == Some('=') {
+                self.next()?;
+                self.skip_whitespace();
+                self.value()?
+            } else {
+                PrefabValue::Null
+            };
+            let attribute = MarkupAttribute {
+                offset,
+                name,
+                value,
+            };
+            if attribute.name == KEY_ATTRIBUTE {
+                self.string_attribute(attribute, &mut result.key)?;
+                continue;
+            }
+            if attribute.name == SLOT_ATTRIBUTE {
+                self.string_attribute(attribute, &mut slot)?;
+                continue;
+            }
+            let (name, props) = match attribute.name.strip_prefix(SHARED_PREFIX) {
+                Some(name) => (name.to_owned(), &mut result.shared_props),
+                None => (attribute.name.to_owned(), &mut result.props),
+            };
+            if props.iter().any(|item| item.name == name) {
+                return Err(MarkupError::new(
+                    self.source,
+                    offset,
+                    MarkupErrorKind::DuplicateAttribute(attribute.name),
+                ));
+            }
+            props.push(MarkupAttribute { name, ..attribute });
+        }
+        loop {
+            self.skip_misc()?;
+            if self.rest().starts_with("</") {
+                let closing = self.offset;
+                self.offset += "</".len();
+                let name = self.name()?;
+                if name != result.type_name {
+                    return Err(MarkupError::new(
+                        self.source,
+                        closing,
+                        MarkupErrorKind::MismatchedClosingTag {
+                            expected: result.type_name,
+                            found: name,
+                        },
+                    ));
+                }
+                self.skip_whitespace();
+                self.expect('>')?;
+                return Ok((slot, result));
+            }
+            match self.peek() {
+                Some('<') => {
+                    let offset = self.offset;
+                    match self.element()? {
+                        (Some(name), element) => {
+                            if result.named_slots.iter().any(|(item, _)| item == &name) {
+                                return Err(MarkupError::new(
+                                    self.source,
+                                    offset,
+                                    MarkupErrorKind::DuplicateSlot(name),
+                                ));
+                            }
+                            result.named_slots.push((name, element));
+                        }
+                        (None, element) => result.listed_slots.push(element),
+                    }
+                }
+                Some(_) => return Err(self.error(MarkupErrorKind::UnexpectedText)),
+                None => return Err(self.error(MarkupErrorKind::UnexpectedEnd)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markup_to_prefab() {
+        let prefab = markup_to_prefab(
+            r#"
+            <!-- Menu -->
+            <vertical_box key="menu" VerticalBoxProps={"separation": 4} shared:f32=1.5>
+                <button NavItemActive>
+                    <text_box slot="content" TextBoxProps={"text": "Start </>"}/>
+                </button>
+                <image_box />
+            </vertical_box>
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            prefab,
+            serde_json::json!({
+                "Component": {
+                    "type_name": "vertical_box",
+                    "key": "menu",
+                    "props": {"data": {"VerticalBoxProps": {"separation": 4}}},
+                    "shared_props": {"data": {"f32": 1.5}},
+                    "listed_slots": [
+                        {
+                            "Component": {
+                                "type_name": "button",
+                                "props": {"data": {"NavItemActive": null}},
+                                "named_slots": {
+                                    "content": {
+                                        "Component": {
+                                            "type_name": "text_box",
+                                            "props": {"data": {"TextBoxProps": {"text": "Start </>"}}}
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        {
+                            "Component": {
+                                "type_name": "image_box",
+                                "props": {}
+                            }
+                        }
+                    ]
+                }
+            })
+        );
+        assert_eq!(markup_to_prefab("").unwrap(), serde_json::json!("None"));
+        assert_eq!(
+            markup_to_prefab("<a/><b/>").unwrap(),
+            serde_json::json!({"Tuple": [
+                {"Component": {"type_name": "a", "props": {}}},
+                {"Component": {"type_name": "b", "props": {}}}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_markup_errors() {
+        let error = |source| {
+            let error = markup_to_prefab(source).unwrap_err();
+            (error.line, error.column, error.to_string())
+        };
+        assert_eq!(
+            error("<a>\n  <b></c>\n</a>"),
+            (
+                2,
+                6,
+                "2:6: Expected closing tag of \"b\", found: \"c\"".to_owned()
+            )
+        );
+        assert_eq!(
+            error("<a>\n  hello\n</a>"),
+            (
+                2,
+                3,
+                "2:3: Text is allowed only in attribute values".to_owned()
+            )
+        );
+        assert_eq!(
+            error("<a\n  f32={\"x\": ]}/>"),
+            (2, 13, "2:13: Invalid value: expected value".to_owned())
+        );
+        assert_eq!(
+            error("<a key=1/>"),
+            (
+                1,
+                4,
+                "1:4: Attribute \"key\" expects string value".to_owned()
+            )
+        );
+        assert_eq!(
+            error("<a>\n  <b slot=\"x\"/>\n  <c slot=\"x\"/>\n</a>"),
+            (3, 3, "3:3: Duplicate named slot: \"x\"".to_owned())
+        );
+        assert_eq!(
+            error("<a>"),
+            (1, 4, "1:4: Unexpected end of markup".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_markup() {
+        use crate::{
+            application::Application,
+            widget::component::{interactive::navigation::NavItemActive, text_box::TextBoxProps},
+        };
+
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let node = application
+            .deserialize_markup(
+                r#"<button key="ok" NavItemActive>
+                    <text_box slot="content" TextBoxProps={"text": "Ok"}/>
+                </button>"#,
+            )
+            .unwrap();
+        let component = node.as_component().unwrap();
+        assert_eq!(component.type_name, "button");
+        assert_eq!(component.key.as_deref(), Some("ok"));
+        assert!(component.props.has::<NavItemActive>());
+        let content = component.named_slots["content"].as_component().unwrap();
+        assert_eq!(
+            content.props.read::<TextBoxProps>().unwrap().text.as_str(),
+            "Ok"
+        );
+        let error = application
+            .deserialize_markup("<button>\n  <foo/>\n</button>")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:3: Component mapping not found: \"foo\""
+        );
+        let error = application
+            .deserialize_markup("<button\n  NavItemActive\n  Foo={}/>")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert!(matches!(error.kind, MarkupErrorKind::Prefab(_)));
+    }
+}
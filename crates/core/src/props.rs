@@ -1,22 +1,41 @@
 //! Widget property types
 
-use crate::{Prefab, PrefabError, PrefabValue};
+use crate::{
+    Prefab, PrefabError, PrefabValue,
+    binding::PropsBindings,
+    style::{diff_prefab, merge_prefab},
+    validation::{PrefabIssue, PrefabIssueKind, validate_props},
+};
 use intuicio_data::type_hash::TypeHash;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
 use std::{
     any::{Any, type_name},
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
 };
 
 type PropsSerializeFactory =
     Box<dyn Fn(&dyn PropsData) -> Result<PrefabValue, PrefabError> + Send + Sync>;
 type PropsDeserializeFactory =
     Box<dyn Fn(PrefabValue, &mut Props) -> Result<(), PrefabError> + Send + Sync>;
+type PropsSchemaFactory = fn(&mut SchemaGenerator) -> Schema;
+type PropsValidateFactory = fn(&str, PrefabValue) -> Result<(), PrefabIssue>;
+type PropsDefaultFactory = fn() -> Option<PrefabValue>;
+
+/// Serialized properties made of serde defaults only, if they have one.
+fn default_props<T: Prefab>() -> Option<PrefabValue> {
+    T::from_prefab(PrefabValue::Object(Default::default()))
+        .and_then(|props| props.to_prefab())
+        .ok()
+}
 
 #[derive(Default)]
 pub struct PropsRegistry {
     type_mapping: HashMap<TypeHash, String>,
     factories: HashMap<String, (PropsSerializeFactory, PropsDeserializeFactory)>,
+    schemas: HashMap<String, PropsSchemaFactory>,
+    validators: HashMap<String, PropsValidateFactory>,
+    defaults: HashMap<String, PropsDefaultFactory>,
 }
 
 impl PropsRegistry {
@@ -38,18 +57,52 @@ impl PropsRegistry {
             Ok(())
         });
         self.factories.insert(name.to_owned(), (s, d));
+        self.schemas.insert(name.to_owned(), T::prefab_schema);
+        self.validators.insert(name.to_owned(), validate_props::<T>);
+        self.defaults.insert(name.to_owned(), default_props::<T>);
         self.type_mapping
             .insert(TypeHash::of::<T>(), name.to_owned());
     }
 
     pub fn unregister_factory(&mut self, name: &str) {
         self.factories.remove(name);
+        self.schemas.remove(name);
+        self.validators.remove(name);
+        self.defaults.remove(name);
+    }
+
+    pub fn has_factory(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Checks if data deserializes into properties registered under given name, reporting the
+    /// first problem found with JSON pointer relative to data.
+    pub fn validate(&self, name: &str, data: PrefabValue) -> Result<(), PrefabIssue> {
+        match self.validators.get(name) {
+            Some(validator) => validator(name, data),
+            None => Err(PrefabIssue {
+                path: Default::default(),
+                kind: PrefabIssueKind::UnknownProps(name.to_owned()),
+            }),
+        }
+    }
+
+    /// JSON Schemas of registered properties, by their registered names.
+    ///
+    /// Schemas usually refer to definitions collected in `generator`.
+    pub fn schemas(&self, generator: &mut SchemaGenerator) -> BTreeMap<String, Schema> {
+        self.schemas
+            .iter()
+            .map(|(name, schema)| (name.to_owned(), schema(generator)))
+            .collect()
     }
 
     pub fn serialize(&self, props: &Props) -> Result<PrefabValue, PrefabError> {
         let mut group = PropsGroupPrefab::default();
         for (t, p) in &props.0 {
-            if let Some(name) = self.type_mapping.get(t) {
+            if let Some(bindings) = p.as_any().downcast_ref::<PropsBindings>() {
+                group.data.extend(bindings.data.clone());
+            } else if let Some(name) = self.type_mapping.get(t) {
                 if let Some(factory) = self.factories.get(name) {
                     group.data.insert(name.to_owned(), (factory.0)(p.as_ref())?);
                 }
@@ -62,6 +115,88 @@ impl PropsRegistry {
         group.to_prefab()
     }
 
+    /// Serializes only properties of registered types, returning how many were skipped.
+    pub fn serialize_registered(&self, props: &Props) -> Result<(PrefabValue, usize), PrefabError> {
+        let mut group = PropsGroupPrefab::default();
+        let mut skipped = 0;
+        for (t, p) in &props.0 {
+            if let Some(bindings) = p.as_any().downcast_ref::<PropsBindings>() {
+                group.data.extend(bindings.data.clone());
+                continue;
+            }
+            match self
+                .type_mapping
+                .get(t)
+                .and_then(|name| Some((name, self.factories.get(name)?)))
+            {
+                Some((name, factory)) => {
+                    group.data.insert(name.to_owned(), (factory.0)(p.as_ref())?);
+                }
+                None => skipped += 1,
+            }
+        }
+        Ok((group.to_prefab()?, skipped))
+    }
+
+    /// Patches fields of properties registered under given name, patching default properties if
+    /// `props` do not have them.
+    ///
+    /// `defaults` only patch fields that keep their serde default values, so fields set to
+    /// other values win over them, while `overrides` patch any fields. Properties without serde
+    /// defaults keep all their fields over `defaults`. If patched properties do not deserialize,
+    /// `overrides` alone replace them.
+    pub fn patch(
+        &self,
+        name: &str,
+        defaults: &[&PrefabValue],
+        overrides: &[&PrefabValue],
+        props: &mut Props,
+    ) -> Result<(), PrefabError> {
+        let Some(factory) = self.factories.get(name) else {
+            return Err(PrefabError::CouldNotDeserialize(format!(
+                "Could not find properties factory: {name:?}"
+            )));
+        };
+        let current = self
+            .type_mapping
+            .iter()
+            .find(|(_, mapped)| mapped.as_str() == name)
+            .and_then(|(t, _)| props.0.get(t));
+        let mut data = match current {
+            Some(current) => (factory.0)(current.as_ref())?,
+            None => PrefabValue::Null,
+        };
+        let explicit = if current.is_none() || defaults.is_empty() {
+            None
+        } else {
+            match self.defaults.get(name).and_then(|default| default()) {
+                Some(default) => diff_prefab(&data, &default),
+                None => Some(data.to_owned()),
+            }
+        };
+        for patch in defaults {
+            merge_prefab(&mut data, patch);
+        }
+        if let Some(explicit) = explicit {
+            merge_prefab(&mut data, &explicit);
+        }
+        for patch in overrides {
+            merge_prefab(&mut data, patch);
+        }
+        let result = (factory.1)(data, props);
+        if result.is_err() && !overrides.is_empty() {
+            // Fields of other internally tagged enum variant merge like struct ones would.
+            let mut data = PrefabValue::Null;
+            for patch in overrides {
+                merge_prefab(&mut data, patch);
+            }
+            if (factory.1)(data, props).is_ok() {
+                return Ok(());
+            }
+        }
+        result
+    }
+
     pub fn deserialize(&self, data: PrefabValue) -> Result<Props, PrefabError> {
         let data = if data.is_null() {
             PropsGroupPrefab::default()
@@ -103,7 +238,7 @@ where
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PropsGroupPrefab {
     #[serde(default)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
@@ -364,9 +499,28 @@ impl From<&Self> for Props {
 /// You may prefer to use the [derive macro][`macro@crate::PropsData`] instead, but in case of
 /// auto-implementing PropsData and Prefab traits for remote or std types, you might find this macro
 /// useful.
+///
+/// With `schema` after the type, [`Prefab::prefab_schema`] uses `schemars::JsonSchema`
+/// implementation of the type.
 #[macro_export]
 macro_rules! implement_props_data {
+    ($type_name:ty, schema) => {
+        $crate::implement_props_data!(@props_data $type_name);
+
+        impl $crate::Prefab for $type_name {
+            fn prefab_schema(
+                generator: &mut $crate::schemars::SchemaGenerator,
+            ) -> $crate::schemars::Schema {
+                generator.subschema_for::<Self>()
+            }
+        }
+    };
     ($type_name:ty) => {
+        $crate::implement_props_data!(@props_data $type_name);
+
+        impl $crate::Prefab for $type_name {}
+    };
+    (@props_data $type_name:ty) => {
         impl $crate::props::PropsData for $type_name
         where
             Self: Clone,
@@ -379,28 +533,26 @@ macro_rules! implement_props_data {
                 self
             }
         }
-
-        impl $crate::Prefab for $type_name {}
     };
 }
 
-implement_props_data!(());
-implement_props_data!(i8);
-implement_props_data!(i16);
-implement_props_data!(i32);
-implement_props_data!(i64);
-implement_props_data!(i128);
-implement_props_data!(u8);
-implement_props_data!(u16);
-implement_props_data!(u32);
-implement_props_data!(u64);
-implement_props_data!(u128);
-implement_props_data!(f32);
-implement_props_data!(f64);
-implement_props_data!(isize);
-implement_props_data!(usize);
-implement_props_data!(bool);
-implement_props_data!(String);
+implement_props_data!((), schema);
+implement_props_data!(i8, schema);
+implement_props_data!(i16, schema);
+implement_props_data!(i32, schema);
+implement_props_data!(i64, schema);
+implement_props_data!(i128, schema);
+implement_props_data!(u8, schema);
+implement_props_data!(u16, schema);
+implement_props_data!(u32, schema);
+implement_props_data!(u64, schema);
+implement_props_data!(u128, schema);
+implement_props_data!(f32, schema);
+implement_props_data!(f64, schema);
+implement_props_data!(isize, schema);
+implement_props_data!(usize, schema);
+implement_props_data!(bool, schema);
+implement_props_data!(String, schema);
 
 macro_rules! impl_tuple_props_conversion {
     ($($id:ident),+) => {
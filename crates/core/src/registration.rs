@@ -0,0 +1,178 @@
+//! Components and properties collected from [`register_component`] and [`register_props`]
+//! attribute macros, from all crates linked into the program.
+//!
+//! Instead of listing every type in hand-written `setup` function, annotate them where they
+//! are declared and call [`setup`] once:
+//!
+//! ```
+//! # use raui_core::{
+//! #     Prefab, PropsData, application::Application, make_widget, props::PropsData,
+//! #     register_component, register_props,
+//! #     widget::{
+//! #         component::text_box::{TextBoxProps, text_box},
+//! #         context::WidgetContext,
+//! #         node::WidgetNode,
+//! #     },
+//! # };
+//! # use serde::{Deserialize, Serialize};
+//! #[register_props]
+//! #[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+//! struct GreetingProps {
+//!     #[serde(default)]
+//!     name: String,
+//! }
+//!
+//! #[register_component]
+//! fn greeting(context: WidgetContext) -> WidgetNode {
+//!     let GreetingProps { name } = context.props.read_cloned_or_default();
+//!     make_widget!(text_box)
+//!         .with_props(TextBoxProps {
+//!             text: format!("Hello, {name}!"),
+//!             ..Default::default()
+//!         })
+//!         .into()
+//! }
+//!
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! application.setup(raui_core::registration::setup);
+//! let node = application
+//!     .deserialize_markup(r#"<greeting GreetingProps={"name": "designer"}/>"#)
+//!     .unwrap();
+//! ```
+//!
+//! Registered items are created by name, so they can not be generic:
+//!
+//! ```compile_fail
+//! # use raui_core::{
+//! #     Prefab, PropsData, props::PropsData, register_component,
+//! #     widget::{context::WidgetContext, node::WidgetNode},
+//! # };
+//! #[register_component]
+//! fn wrapper<T: PropsData + Default>(context: WidgetContext) -> WidgetNode {
+//!     Default::default()
+//! }
+//! ```
+//!
+//! [`register_component`]: crate::register_component
+//! [`register_props`]: crate::register_props
+
+use crate::{
+    Prefab,
+    application::Application,
+    props::PropsData,
+    widget::{FnWidget, context::WidgetContext, node::WidgetNode},
+};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// Component function registered under type name.
+pub struct ComponentRegistration {
+    pub type_name: &'static str,
+    pub processor: fn(WidgetContext) -> WidgetNode,
+}
+
+impl ComponentRegistration {
+    pub const fn new(type_name: &'static str, processor: fn(WidgetContext) -> WidgetNode) -> Self {
+        Self {
+            type_name,
+            processor,
+        }
+    }
+}
+
+/// Properties type registered under name.
+pub struct PropsRegistration {
+    pub name: &'static str,
+    register: fn(&mut Application, &str),
+}
+
+impl PropsRegistration {
+    pub const fn new<T>(name: &'static str) -> Self
+    where
+        T: 'static + Prefab + PropsData,
+    {
+        Self {
+            name,
+            register: |app, name| app.register_props::<T>(name),
+        }
+    }
+}
+
+inventory::collect!(ComponentRegistration);
+inventory::collect!(PropsRegistration);
+
+pub fn components() -> impl Iterator<Item = &'static ComponentRegistration> {
+    inventory::iter::<ComponentRegistration>.into_iter()
+}
+
+pub fn props() -> impl Iterator<Item = &'static PropsRegistration> {
+    inventory::iter::<PropsRegistration>.into_iter()
+}
+
+/// Registers all collected components and properties in application.
+pub fn setup(app: &mut Application) {
+    for registration in props() {
+        (registration.register)(app, registration.name);
+    }
+    for registration in components() {
+        app.register_component(
+            registration.type_name,
+            FnWidget::pointer(registration.processor),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PropsData, register_component, register_props,
+        widget::{
+            component::text_box::{TextBoxProps, text_box},
+            unit::WidgetUnit,
+        },
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[register_props]
+    #[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+    #[props_data(crate::props::PropsData)]
+    #[prefab(crate::Prefab)]
+    struct RegisteredProps {
+        #[serde(default)]
+        name: String,
+    }
+
+    #[register_component("registered_label")]
+    fn registered(context: WidgetContext) -> WidgetNode {
+        let RegisteredProps { name } = context.props.read_cloned_or_default();
+        make_widget!(text_box)
+            .with_props(TextBoxProps {
+                text: name,
+                ..Default::default()
+            })
+            .into()
+    }
+
+    #[test]
+    fn test_setup() {
+        assert!(props().any(|registration| registration.name == "RegisteredProps"));
+        assert!(components().any(|registration| registration.type_name == "registered_label"));
+
+        let source = r#"<registered_label RegisteredProps={"name": "registered"}/>"#;
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        assert!(application.deserialize_markup(source).is_err());
+
+        application.setup(setup);
+        let node = application.deserialize_markup(source).unwrap();
+        application.apply(node);
+        application.forced_process();
+        match application.rendered_tree() {
+            WidgetUnit::TextBox(text) => assert_eq!(text.text, "registered"),
+            unit => panic!("Unexpected widget unit: {unit:?}"),
+        }
+    }
+}
@@ -0,0 +1,753 @@
+//! Style sheets injecting properties into components matched by selectors.
+//!
+//! Style sheet is a list of rules, set with [`Application::set_style_sheet`]. Each rule has a
+//! selector and properties groups that patch fields of component `props` and `shared_props`
+//! whenever matching component gets processed. Like in CSS, rules only set fields component
+//! leaves at their serde defaults, and fields component was given other values win, unless rule
+//! is `important`. Fields not mentioned by rules keep the values component was given, and
+//! properties component does not have start from their defaults. Since shared properties pass
+//! down to descendants, rules can restyle whole subtrees, for example by overriding theme
+//! properties. Enum values given other variant are replaced as a whole, and properties that
+//! could not be patched are reported by [`Application::style_errors`]. Fields component sets
+//! to their default values can not be told apart from the ones it leaves out, see
+//! [`StyleRule`].
+//!
+//! Selectors resemble CSS ones:
+//!
+//! - `text_box` matches component type name, `*` matches any component.
+//! - `#title` matches widget id key.
+//! - `.highlight` matches widget id meta flag: `title?highlight`.
+//! - `[size]` matches meta parameter, with any value or none, and `[size=big]` (or
+//!   `[size='big']`) matches meta parameter value.
+//! - `vertical_box text_box` matches component inside of another one, at any depth.
+//! - `vertical_box > text_box` matches component directly inside of another one.
+//!
+//! Rules apply in order of their selector [specificity][StyleSpecificity], and rules of equal
+//! specificity apply in order they are listed, so later ones win. Important rules apply after
+//! all others.
+//!
+//! ```
+//! # use raui_core::{
+//! #     Prefab,
+//! #     application::Application,
+//! #     make_widget,
+//! #     style::StyleSheet,
+//! #     widget::{
+//! #         component::{
+//! #             containers::vertical_box::vertical_box,
+//! #             text_box::{TextBoxProps, text_box},
+//! #         },
+//! #         unit::WidgetUnit,
+//! #     },
+//! # };
+//! # use serde_json::json;
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! let style_sheet = StyleSheet::from_prefab(json!({
+//!     "rules": [
+//!         {
+//!             "selector": "vertical_box text_box",
+//!             "props": { "TextBoxProps": { "font": { "size": 24.0 } } }
+//!         },
+//!         {
+//!             "selector": "#title.highlight",
+//!             "props": { "TextBoxProps": { "color": { "r": 1.0, "g": 0.0, "b": 0.0, "a": 1.0 } } }
+//!         }
+//!     ]
+//! }))
+//! .unwrap();
+//! application.set_style_sheet(style_sheet).unwrap();
+//! application.apply(
+//!     make_widget!(vertical_box).listed_slot(
+//!         make_widget!(text_box)
+//!             .key("title?highlight")
+//!             .with_props(TextBoxProps {
+//!                 text: "Hello".to_owned(),
+//!                 ..Default::default()
+//!             }),
+//!     ),
+//! );
+//! application.process();
+//! let WidgetUnit::FlexBox(flex_box) = application.rendered_tree() else {
+//!     panic!("Expected flex box");
+//! };
+//! let WidgetUnit::TextBox(title) = &flex_box.items[0].slot else {
+//!     panic!("Expected text box");
+//! };
+//! assert_eq!(title.text, "Hello");
+//! assert_eq!(title.font.size, 24.0);
+//! assert_eq!(title.color.r, 1.0);
+//! ```
+//!
+//! [`Application::set_style_sheet`]: crate::application::Application::set_style_sheet
+//! [`Application::style_errors`]: crate::application::Application::style_errors
+
+use crate::{Prefab, PrefabValue, validation::PrefabIssue, widget::WidgetIdMetaParams};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleError {
+    /// Selector could not be parsed, with byte offset into its source.
+    Syntax { offset: usize, message: String },
+    /// Rule properties do not deserialize, with JSON pointer relative to the rule.
+    Props(PrefabIssue),
+}
+
+impl std::fmt::Display for StyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax { offset, message } => write!(f, "{message} at offset {offset}"),
+            Self::Props(issue) => write!(f, "{issue}"),
+        }
+    }
+}
+
+/// Serializable list of style rules.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StyleSheet {
+    #[serde(default)]
+    pub rules: Vec<StyleRule>,
+}
+
+impl Prefab for StyleSheet {}
+
+fn is_false(v: &bool) -> bool {
+    !*v
+}
+
+/// Properties injected into components matching selector.
+///
+/// Component properties are typed values, so fields component was given are found by comparing
+/// them with default properties. Field component explicitly sets to its default value looks
+/// unset and still gets overridden by rule; non-default values are the ones that win.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StyleRule {
+    pub selector: String,
+    /// Properties groups by their registered names, with fields to override.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub props: HashMap<String, PrefabValue>,
+    /// Shared properties groups by their registered names, with fields to override.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub shared_props: HashMap<String, PrefabValue>,
+    /// Overrides fields component was given too, after other rules. When patched properties do
+    /// not deserialize (mixing fields of internally tagged enum variants, which can not be told
+    /// apart from structs), important patches replace whole properties instead.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub important: bool,
+}
+
+/// Selector weight deciding which rules override others, compared field by field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyleSpecificity {
+    pub keys: usize,
+    /// Meta flags and parameters.
+    pub meta: usize,
+    pub type_names: usize,
+}
+
+/// Component as seen by selectors.
+#[derive(Debug, Clone, Copy)]
+pub struct StyleTarget<'a> {
+    pub type_name: &'a str,
+    pub key: &'a str,
+    pub meta: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StyleCombinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct StyleCompound {
+    type_name: Option<String>,
+    key: Option<String>,
+    flags: Vec<String>,
+    params: Vec<(String, Option<String>)>,
+}
+
+impl StyleCompound {
+    fn matches(&self, target: &StyleTarget) -> bool {
+        if self
+            .type_name
+            .as_ref()
+            .is_some_and(|type_name| type_name != target.type_name)
+            || self.key.as_ref().is_some_and(|key| key != target.key)
+        {
+            return false;
+        }
+        let meta = WidgetIdMetaParams::new(target.meta);
+        self.flags.iter().all(|flag| meta.has_flag(flag))
+            && self.params.iter().all(|(name, value)| match value {
+                Some(value) => meta.find_value(name) == Some(value.as_str()),
+                None => meta.find(name).is_some(),
+            })
+    }
+}
+
+/// Parsed rule selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleSelector {
+    compounds: Vec<StyleCompound>,
+    /// Combinators between consecutive compounds.
+    combinators: Vec<StyleCombinator>,
+}
+
+impl StyleSelector {
+    pub fn specificity(&self) -> StyleSpecificity {
+        self.compounds
+            .iter()
+            .fold(StyleSpecificity::default(), |result, compound| {
+                StyleSpecificity {
+                    keys: result.keys + compound.key.is_some() as usize,
+                    meta: result.meta + compound.flags.len() + compound.params.len(),
+                    type_names: result.type_names + compound.type_name.is_some() as usize,
+                }
+            })
+    }
+
+    /// Checks if selector matches last of targets, with the ones before being its ancestors
+    /// starting from the root.
+    pub fn matches(&self, targets: &[StyleTarget]) -> bool {
+        let Some((target, ancestors)) = targets.split_last() else {
+            return false;
+        };
+        let Some((compound, compounds)) = self.compounds.split_last() else {
+            return false;
+        };
+        compound.matches(target) && Self::matches_ancestors(compounds, &self.combinators, ancestors)
+    }
+
+    fn matches_ancestors(
+        compounds: &[StyleCompound],
+        combinators: &[StyleCombinator],
+        ancestors: &[StyleTarget],
+    ) -> bool {
+        let (Some((compound, compounds)), Some((combinator, combinators))) =
+            (compounds.split_last(), combinators.split_last())
+        else {
+            return true;
+        };
+        match combinator {
+            StyleCombinator::Child => ancestors.split_last().is_some_and(|(target, ancestors)| {
+                compound.matches(target)
+                    && Self::matches_ancestors(compounds, combinators, ancestors)
+            }),
+            StyleCombinator::Descendant => (0..ancestors.len()).rev().any(|index| {
+                compound.matches(&ancestors[index])
+                    && Self::matches_ancestors(compounds, combinators, &ancestors[..index])
+            }),
+        }
+    }
+}
+
+impl FromStr for StyleSelector {
+    type Err = StyleError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        SelectorParser {
+            source,
+            position: 0,
+        }
+        .selector()
+    }
+}
+
+struct SelectorParser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl SelectorParser<'_> {
+    fn error<T>(&self, message: impl ToString) -> Result<T, StyleError> {
+        Err(StyleError::Syntax {
+            offset: self.position,
+            message: message.to_string(),
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn advance(&mut self, character: char) {
+        self.position += character.len_utf8();
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+        while let Some(character) = self.peek().filter(|character| character.is_whitespace()) {
+            self.advance(character);
+        }
+        self.position > start
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), StyleError> {
+        match self.peek() {
+            Some(character) if character == expected => {
+                self.advance(character);
+                Ok(())
+            }
+            _ => self.error(format!("Expected {expected:?}")),
+        }
+    }
+
+    fn name(&mut self) -> Result<String, StyleError> {
+        let start = self.position;
+        while let Some(character) = self
+            .peek()
+            .filter(|character| character.is_alphanumeric() || "_-:".contains(*character))
+        {
+            self.advance(character);
+        }
+        if self.position == start {
+            return self.error("Expected name");
+        }
+        Ok(self.source[start..self.position].to_owned())
+    }
+
+    fn value(&mut self) -> Result<String, StyleError> {
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) => {
+                self.advance(quote);
+                let start = self.position;
+                while let Some(character) = self.peek().filter(|character| *character != quote) {
+                    self.advance(character);
+                }
+                let result = self.source[start..self.position].to_owned();
+                self.expect(quote)?;
+                Ok(result)
+            }
+            _ => self.name(),
+        }
+    }
+
+    fn compound(&mut self) -> Result<StyleCompound, StyleError> {
+        let start = self.position;
+        let mut result = StyleCompound::default();
+        match self.peek() {
+            Some('*') => self.advance('*'),
+            Some(character) if character.is_alphanumeric() || character == '_' => {
+                result.type_name = Some(self.name()?);
+            }
+            _ => {}
+        }
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    if result.key.is_some() {
+                        return self.error("Key is already selected");
+                    }
+                    self.advance('#');
+                    result.key = Some(self.name()?);
+                }
+                Some('.') => {
+                    self.advance('.');
+                    result.flags.push(self.name()?);
+                }
+                Some('[') => {
+                    self.advance('[');
+                    self.skip_whitespace();
+                    let name = self.name()?;
+                    self.skip_whitespace();
+                    let value = if self.peek() == Some('=') {
+                        self.advance('=');
+                        self.skip_whitespace();
+                        Some(self.value()?)
+                    } else {
+                        None
+                    };
+                    self.skip_whitespace();
+                    self.expect(']')?;
+                    result.params.push((name, value));
+                }
+                _ => break,
+            }
+        }
+        if self.position == start {
+            return self.error("Expected selector");
+        }
+        Ok(result)
+    }
+
+    fn selector(mut self) -> Result<StyleSelector, StyleError> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.compound()?];
+        let mut combinators = vec![];
+        loop {
+            let separated = self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('>') => {
+                    self.advance('>');
+                    self.skip_whitespace();
+                    combinators.push(StyleCombinator::Child);
+                }
+                Some(_) if separated => combinators.push(StyleCombinator::Descendant),
+                Some(character) => return self.error(format!("Unexpected {character:?}")),
+            }
+            compounds.push(self.compound()?);
+        }
+        Ok(StyleSelector {
+            compounds,
+            combinators,
+        })
+    }
+}
+
+/// Tells if objects are different variants of externally tagged enum.
+fn is_other_variant(
+    a: &serde_json::Map<String, PrefabValue>,
+    b: &serde_json::Map<String, PrefabValue>,
+) -> bool {
+    a.len() == 1 && b.len() == 1 && !a.keys().eq(b.keys())
+}
+
+/// Merges `patch` into `target`, recursively for objects and replacing other values.
+///
+/// Objects with single, different keys are treated as different variants of enum and get
+/// replaced too, since merging them would make invalid enum value.
+pub(crate) fn merge_prefab(target: &mut PrefabValue, patch: &PrefabValue) {
+    match (target, patch) {
+        (PrefabValue::Object(target), PrefabValue::Object(patch))
+            if !is_other_variant(target, patch) =>
+        {
+            for (key, value) in patch {
+                merge_prefab(
+                    target.entry(key.to_owned()).or_insert(PrefabValue::Null),
+                    value,
+                );
+            }
+        }
+        (target, patch) => *target = patch.to_owned(),
+    }
+}
+
+/// Gives fields of `value` that differ from `base`, recursively for objects, so that merging
+/// them into `base` makes `value` again.
+pub(crate) fn diff_prefab(value: &PrefabValue, base: &PrefabValue) -> Option<PrefabValue> {
+    match (value, base) {
+        (PrefabValue::Object(value), PrefabValue::Object(base))
+            if !is_other_variant(value, base) =>
+        {
+            let result = value
+                .iter()
+                .filter_map(|(key, item)| match base.get(key) {
+                    Some(base) => diff_prefab(item, base).map(|item| (key.to_owned(), item)),
+                    None => Some((key.to_owned(), item.to_owned())),
+                })
+                .collect::<serde_json::Map<_, _>>();
+            (!result.is_empty()).then_some(PrefabValue::Object(result))
+        }
+        (value, base) => (value != base).then(|| value.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PropsData,
+        application::Application,
+        widget::{
+            component::{
+                containers::{content_box::content_box, vertical_box::vertical_box},
+                image_box::{ImageBoxProps, image_box},
+                text_box::{TextBoxProps, text_box},
+            },
+            context::WidgetContext,
+            node::WidgetNode,
+            unit::{WidgetUnit, image::ImageBoxMaterial, text::TextBoxFont},
+            utils::Color,
+        },
+    };
+    use serde_json::json;
+
+    fn target<'a>(type_name: &'a str, key: &'a str, meta: &'a str) -> StyleTarget<'a> {
+        StyleTarget {
+            type_name,
+            key,
+            meta,
+        }
+    }
+
+    #[test]
+    fn test_selector() {
+        let targets = [
+            target("vertical_box", "root", ""),
+            target("content_box", "panel", "dark&size=big"),
+            target("text_box", "title", "highlight"),
+        ];
+        let matches = |selector: &str| selector.parse::<StyleSelector>().unwrap().matches(&targets);
+        assert!(matches("*"));
+        assert!(matches("text_box#title.highlight"));
+        assert!(matches("vertical_box text_box"));
+        assert!(matches("content_box > text_box"));
+        assert!(matches("vertical_box > content_box > text_box"));
+        assert!(matches("#root [size=big] *"));
+        assert!(matches(".dark[size='big'] > .highlight"));
+        assert!(!matches("vertical_box > text_box"));
+        assert!(!matches("content_box"));
+        assert!(!matches("text_box.dark"));
+        assert!(!matches("[size=small] text_box"));
+        assert!(!matches("[highlight=yes]"));
+
+        assert_eq!(
+            "a#b.c > d[e] f"
+                .parse::<StyleSelector>()
+                .unwrap()
+                .specificity(),
+            StyleSpecificity {
+                keys: 1,
+                meta: 2,
+                type_names: 3,
+            }
+        );
+        assert_eq!(
+            "text_box >".parse::<StyleSelector>(),
+            Err(StyleError::Syntax {
+                offset: 10,
+                message: "Expected selector".to_owned()
+            })
+        );
+        assert!("a#b#c".parse::<StyleSelector>().is_err());
+        assert!("[size=big".parse::<StyleSelector>().is_err());
+        assert!("a,b".parse::<StyleSelector>().is_err());
+    }
+
+    fn shared_text(context: WidgetContext) -> WidgetNode {
+        make_widget!(text_box)
+            .with_props(
+                context
+                    .shared_props
+                    .read_cloned_or_default::<TextBoxProps>(),
+            )
+            .into()
+    }
+
+    #[test]
+    fn test_application() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let style_sheet = StyleSheet::from_prefab(json!({
+            "rules": [
+                {
+                    "selector": "#title",
+                    "props": { "TextBoxProps": { "font": { "size": 32.0 } } }
+                },
+                {
+                    "selector": "text_box",
+                    "props": { "TextBoxProps": { "font": { "name": "sans", "size": 16.0 } } }
+                },
+                {
+                    "selector": "content_box",
+                    "shared_props": { "TextBoxProps": { "text": "Untitled" } }
+                },
+                {
+                    "selector": "#note",
+                    "props": { "TextBoxProps": { "color": { "r": 0.0, "g": 1.0, "b": 0.0, "a": 1.0 } } },
+                    "important": true
+                }
+            ]
+        }))
+        .unwrap();
+        application.set_style_sheet(style_sheet.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(application.style_sheet()).unwrap(),
+            serde_json::to_value(&style_sheet).unwrap()
+        );
+        application.apply(
+            make_widget!(vertical_box)
+                .listed_slot(
+                    make_widget!(text_box)
+                        .key("title")
+                        .with_props(TextBoxProps {
+                            text: "Hello".to_owned(),
+                            ..Default::default()
+                        }),
+                )
+                .listed_slot(make_widget!(content_box).listed_slot(make_widget!(shared_text)))
+                .listed_slot(make_widget!(text_box).key("note").with_props(TextBoxProps {
+                    font: TextBoxFont {
+                        size: 10.0,
+                        ..Default::default()
+                    },
+                    color: Color {
+                        r: 0.0,
+                        g: 0.0,
+                        b: 1.0,
+                        a: 1.0,
+                    },
+                    ..Default::default()
+                })),
+        );
+        application.process();
+        let WidgetUnit::FlexBox(flex_box) = application.rendered_tree() else {
+            panic!("Expected flex box");
+        };
+        let WidgetUnit::TextBox(title) = &flex_box.items[0].slot else {
+            panic!("Expected text box");
+        };
+        assert_eq!(title.text, "Hello");
+        assert_eq!(title.font.name, "sans");
+        assert_eq!(title.font.size, 32.0);
+        let WidgetUnit::ContentBox(content_box) = &flex_box.items[1].slot else {
+            panic!("Expected content box");
+        };
+        let WidgetUnit::TextBox(text) = &content_box.items[0].slot else {
+            panic!("Expected text box");
+        };
+        assert_eq!(text.text, "Untitled");
+        assert_eq!(text.font.size, 16.0);
+        let WidgetUnit::TextBox(note) = &flex_box.items[2].slot else {
+            panic!("Expected text box");
+        };
+        // Fields component was given win over rules, unless these are important.
+        assert_eq!(note.font.name, "sans");
+        assert_eq!(note.font.size, 10.0);
+        assert_eq!(note.color.g, 1.0);
+        assert_eq!(note.color.b, 0.0);
+
+        assert_eq!(
+            application
+                .set_style_sheet(
+                    StyleSheet::from_prefab(json!({
+                        "rules": [
+                            { "selector": "text_box" },
+                            {
+                                "selector": "text_box",
+                                "props": { "TextBoxProps": { "font": { "size": "big" } } }
+                            }
+                        ]
+                    }))
+                    .unwrap()
+                )
+                .unwrap_err()
+                .0,
+            1
+        );
+    }
+
+    #[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+    #[props_data(crate::props::PropsData)]
+    #[prefab(crate::Prefab)]
+    #[serde(tag = "type", deny_unknown_fields)]
+    enum Shape {
+        Circle { radius: f32 },
+        Square { size: f32 },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Self::Circle { radius: 1.0 }
+        }
+    }
+
+    fn shape_text(context: WidgetContext) -> WidgetNode {
+        let shape = context.props.read_cloned_or_default::<Shape>();
+        make_widget!(text_box)
+            .with_props(TextBoxProps {
+                text: format!("{shape:?}"),
+                ..Default::default()
+            })
+            .into()
+    }
+
+    #[test]
+    fn test_enum_variants() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application.register_props::<Shape>("Shape");
+        application
+            .set_style_sheet(
+                StyleSheet::from_prefab(json!({
+                    "rules": [
+                        {
+                            "selector": "image_box",
+                            "props": {
+                                "ImageBoxProps": { "material": { "Image": { "id": "icon" } } }
+                            },
+                            "important": true
+                        },
+                        {
+                            "selector": "shape_text",
+                            "props": { "Shape": { "type": "Circle", "radius": 2.0 } },
+                            "important": true
+                        }
+                    ]
+                }))
+                .unwrap(),
+            )
+            .unwrap();
+        application.apply(
+            make_widget!(content_box)
+                .listed_slot(
+                    make_widget!(image_box).with_props(ImageBoxProps::colored(Color {
+                        r: 1.0,
+                        g: 0.0,
+                        b: 0.0,
+                        a: 1.0,
+                    })),
+                )
+                .listed_slot(make_widget!(shape_text).with_props(Shape::Square { size: 2.0 })),
+        );
+        application.process();
+        let WidgetUnit::ContentBox(content_box) = application.rendered_tree() else {
+            panic!("Expected content box");
+        };
+        let WidgetUnit::ImageBox(image_box) = &content_box.items[0].slot else {
+            panic!("Expected image box");
+        };
+        let ImageBoxMaterial::Image(image) = &image_box.material else {
+            panic!("Expected image material");
+        };
+        assert_eq!(image.id, "icon");
+        // Internally tagged enums cannot be told apart from structs, so merged variants do not
+        // deserialize and important patch replaces the whole value.
+        let WidgetUnit::TextBox(text_box) = &content_box.items[1].slot else {
+            panic!("Expected text box");
+        };
+        assert_eq!(text_box.text, "Circle { radius: 2.0 }");
+        assert!(application.style_errors().is_empty());
+    }
+
+    #[test]
+    fn test_explicit_defaults() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application
+            .set_style_sheet(
+                StyleSheet::from_prefab(json!({
+                    "rules": [
+                        {
+                            "selector": "text_box",
+                            "props": { "TextBoxProps": { "text": "Styled", "font": { "size": 24.0 } } }
+                        }
+                    ]
+                }))
+                .unwrap(),
+            )
+            .unwrap();
+        // Empty text is the default one, so it looks unset and rule still overrides it.
+        application.apply(make_widget!(text_box).with_props(TextBoxProps {
+            text: Default::default(),
+            font: TextBoxFont {
+                size: 12.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+        application.process();
+        let WidgetUnit::TextBox(text_box) = application.rendered_tree() else {
+            panic!("Expected text box");
+        };
+        assert_eq!(text_box.text, "Styled");
+        assert_eq!(text_box.font.size, 12.0);
+    }
+}
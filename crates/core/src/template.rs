@@ -0,0 +1,312 @@
+//! Reusable widget tree fragments defined in prefab data.
+//!
+//! Template is a serialized widget node with placeholders, registered under a name with
+//! [`Application::register_template`]. Prefabs instantiate it with `Template` node, which gets
+//! expanded when prefab is deserialized:
+//!
+//! - `{"$param": "name"}` value is replaced with instance parameter, or its declared default.
+//! - `{"$slot": "name"}` node is replaced with instance named slot, or empty node.
+//! - `{"$listed_slots": null}` item of a list is replaced with all instance listed slots.
+//! - Instance `key` replaces key of template root component.
+//!
+//! ```
+//! # use raui_core::application::Application;
+//! # use serde_json::json;
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! application
+//!     .register_templates(json!({
+//!         "labeled": {
+//!             "params": { "label": "" },
+//!             "content": {
+//!                 "Component": {
+//!                     "type_name": "vertical_box",
+//!                     "listed_slots": [
+//!                         {
+//!                             "Component": {
+//!                                 "type_name": "text_box",
+//!                                 "props": { "data": { "TextBoxProps": {
+//!                                     "text": { "$param": "label" }
+//!                                 } } }
+//!                             }
+//!                         },
+//!                         { "$slot": "content" }
+//!                     ]
+//!                 }
+//!             }
+//!         }
+//!     }))
+//!     .unwrap();
+//! let node = application
+//!     .deserialize_node(json!({
+//!         "Template": {
+//!             "name": "labeled",
+//!             "key": "volume",
+//!             "params": { "label": "Volume" },
+//!             "named_slots": { "content": { "Component": { "type_name": "image_box" } } }
+//!         }
+//!     }))
+//!     .unwrap();
+//! let component = node.as_component().unwrap();
+//! assert_eq!(component.type_name, "vertical_box");
+//! assert_eq!(component.key.as_deref(), Some("volume"));
+//! assert_eq!(component.listed_slots.len(), 2);
+//! ```
+//!
+//! [`Application::register_template`]: crate::application::Application::register_template
+
+use crate::{Prefab, PrefabError, PrefabValue, widget::node::WidgetNodePrefab};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum TemplateError {
+    NotFound(String),
+    /// Template instantiates itself, directly or through other templates.
+    Recursion(String),
+    /// Instance sets parameter that template does not declare.
+    UnknownParam {
+        template: String,
+        param: String,
+    },
+    /// Template uses parameter that it does not declare.
+    UndeclaredParam {
+        template: String,
+        param: String,
+    },
+    Prefab(PrefabError),
+}
+
+impl From<PrefabError> for TemplateError {
+    fn from(error: PrefabError) -> Self {
+        Self::Prefab(error)
+    }
+}
+
+/// Widget node prefab with parameter and slot placeholders.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PrefabTemplate {
+    /// Declared parameters with their default values.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, PrefabValue>,
+    /// Serialized widget node with placeholders.
+    #[serde(default)]
+    pub content: PrefabValue,
+}
+
+impl Prefab for PrefabTemplate {}
+
+impl PrefabTemplate {
+    pub(crate) fn instantiate(
+        &self,
+        name: &str,
+        mut params: HashMap<String, PrefabValue>,
+        listed_slots: &[PrefabValue],
+        named_slots: &HashMap<String, PrefabValue>,
+    ) -> Result<PrefabValue, TemplateError> {
+        if let Some(param) = params
+            .keys()
+            .find(|param| !self.params.contains_key(*param))
+        {
+            return Err(TemplateError::UnknownParam {
+                template: name.to_owned(),
+                param: param.to_owned(),
+            });
+        }
+        for (param, value) in &self.params {
+            params
+                .entry(param.to_owned())
+                .or_insert_with(|| value.to_owned());
+        }
+        let instance = TemplateInstance {
+            name,
+            params: &params,
+            listed_slots,
+            named_slots,
+        };
+        instance.substitute(&self.content)
+    }
+}
+
+struct TemplateInstance<'a> {
+    name: &'a str,
+    params: &'a HashMap<String, PrefabValue>,
+    listed_slots: &'a [PrefabValue],
+    named_slots: &'a HashMap<String, PrefabValue>,
+}
+
+impl TemplateInstance<'_> {
+    fn placeholder<'a>(value: &'a PrefabValue, tag: &str) -> Option<&'a PrefabValue> {
+        value
+            .as_object()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.get(tag))
+    }
+
+    fn substitute(&self, value: &PrefabValue) -> Result<PrefabValue, TemplateError> {
+        if let Some(param) = Self::placeholder(value, "$param") {
+            let param = param.as_str().unwrap_or_default();
+            return self
+                .params
+                .get(param)
+                .cloned()
+                .ok_or_else(|| TemplateError::UndeclaredParam {
+                    template: self.name.to_owned(),
+                    param: param.to_owned(),
+                });
+        }
+        if let Some(slot) = Self::placeholder(value, "$slot") {
+            return Ok(slot
+                .as_str()
+                .and_then(|slot| self.named_slots.get(slot))
+                .cloned()
+                .unwrap_or_else(|| PrefabValue::String("None".to_owned())));
+        }
+        Ok(match value {
+            PrefabValue::Array(items) => {
+                let mut result = Vec::with_capacity(items.len());
+                for item in items {
+                    if Self::placeholder(item, "$listed_slots").is_some() {
+                        result.extend(self.listed_slots.iter().cloned());
+                    } else {
+                        result.push(self.substitute(item)?);
+                    }
+                }
+                PrefabValue::Array(result)
+            }
+            PrefabValue::Object(object) => PrefabValue::Object(
+                object
+                    .iter()
+                    .map(|(key, item)| Ok((key.to_owned(), self.substitute(item)?)))
+                    .collect::<Result<_, TemplateError>>()?,
+            ),
+            value => value.to_owned(),
+        })
+    }
+}
+
+/// Instance of registered template.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct WidgetTemplatePrefab {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, PrefabValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub listed_slots: Vec<WidgetNodePrefab>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub named_slots: HashMap<String, WidgetNodePrefab>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::{Application, ApplicationError},
+        widget::component::text_box::TextBoxProps,
+    };
+    use serde_json::json;
+
+    fn application() -> Application {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application
+            .register_templates(json!({
+                "panel": {
+                    "params": { "title": "Untitled" },
+                    "content": {
+                        "Component": {
+                            "type_name": "vertical_box",
+                            "listed_slots": [
+                                {
+                                    "Component": {
+                                        "type_name": "text_box",
+                                        "props": { "data": { "TextBoxProps": {
+                                            "text": { "$param": "title" }
+                                        } } }
+                                    }
+                                },
+                                { "$listed_slots": null },
+                                { "$slot": "footer" }
+                            ]
+                        }
+                    }
+                },
+                "loop": {
+                    "content": {
+                        "Component": {
+                            "type_name": "content_box",
+                            "listed_slots": [{ "Template": { "name": "loop" } }]
+                        }
+                    }
+                },
+                "typo": {
+                    "content": { "$param": "missing" }
+                }
+            }))
+            .unwrap();
+        application
+    }
+
+    fn text(node: &crate::widget::node::WidgetNode) -> String {
+        node.as_component()
+            .unwrap()
+            .props
+            .read_cloned::<TextBoxProps>()
+            .unwrap()
+            .text
+    }
+
+    #[test]
+    fn test_template() {
+        let application = application();
+        let node = application
+            .deserialize_node(json!({
+                "Template": {
+                    "name": "panel",
+                    "listed_slots": [
+                        { "Template": { "name": "panel", "params": { "title": "Inner" } } },
+                        { "Component": { "type_name": "image_box" } }
+                    ]
+                }
+            }))
+            .unwrap();
+        let slots = &node.as_component().unwrap().listed_slots;
+        assert_eq!(slots.len(), 4);
+        assert_eq!(text(&slots[0]), "Untitled");
+        assert_eq!(
+            text(&slots[1].as_component().unwrap().listed_slots[0]),
+            "Inner"
+        );
+        assert_eq!(slots[2].as_component().unwrap().type_name, "image_box");
+        assert!(slots[3].is_none());
+
+        assert!(matches!(
+            application.deserialize_node(json!({ "Template": { "name": "loop" } })),
+            Err(ApplicationError::Template(TemplateError::Recursion(name))) if name == "loop"
+        ));
+        assert!(matches!(
+            application.deserialize_node(json!({ "Template": { "name": "typo" } })),
+            Err(ApplicationError::Template(TemplateError::UndeclaredParam { param, .. }))
+                if param == "missing"
+        ));
+        assert!(matches!(
+            application.deserialize_node(
+                json!({ "Template": { "name": "panel", "params": { "titel": "" } } })
+            ),
+            Err(ApplicationError::Template(TemplateError::UnknownParam { param, .. }))
+                if param == "titel"
+        ));
+        let issues = application.validate_node(&json!({ "Template": { "name": "panl" } }));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/Template/name");
+    }
+}
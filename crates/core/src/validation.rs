@@ -0,0 +1,508 @@
+//! Validation of serialized widget trees.
+//!
+//! [`Application::deserialize_node`] stops at the first problem and reports it as plain
+//! message. Validation instead walks whole prefab and collects every problem found, each one
+//! located with [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) into validated data:
+//!
+//! ```
+//! # use raui_core::{application::Application, validation::PrefabIssueKind};
+//! # use serde_json::json;
+//! let mut application = Application::default();
+//! application.setup(raui_core::widget::setup);
+//! let issues = application.validate_node(&json!({
+//!     "Component": {
+//!         "type_name": "content_box",
+//!         "listed_slots": [
+//!             { "Component": { "type_name": "texy_box" } },
+//!             {
+//!                 "Component": {
+//!                     "type_name": "text_box",
+//!                     "props": { "data": { "TextBoxProps": { "text": 42 } } }
+//!                 }
+//!             }
+//!         ]
+//!     }
+//! }));
+//! assert_eq!(issues.len(), 2);
+//! assert_eq!(issues[0].path, "/Component/listed_slots/0/Component/type_name");
+//! assert_eq!(
+//!     issues[1].path,
+//!     "/Component/listed_slots/1/Component/props/data/TextBoxProps/text"
+//! );
+//! ```
+//!
+//! [`Application::deserialize_node`]: crate::application::Application::deserialize_node
+
+use crate::{Prefab, PrefabValue, props::PropsRegistry, widget::node::WidgetNodePrefab};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefabIssueKind {
+    /// Data does not match widget tree structure.
+    InvalidNode(String),
+    UnknownComponent(String),
+    UnknownTemplate(String),
+    UnknownProps(String),
+    InvalidProps {
+        name: String,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for PrefabIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNode(message) => write!(f, "{message}"),
+            Self::UnknownComponent(type_name) => {
+                write!(f, "Could not find component mapping: {type_name:?}")
+            }
+            Self::UnknownTemplate(name) => write!(f, "Could not find template: {name:?}"),
+            Self::UnknownProps(name) => write!(f, "Could not find properties factory: {name:?}"),
+            Self::InvalidProps { name, message } => {
+                write!(f, "Invalid {name:?} properties: {message}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefabIssue {
+    /// JSON pointer to offending value, empty when it is whole data.
+    pub path: String,
+    pub kind: PrefabIssueKind,
+}
+
+impl std::fmt::Display for PrefabIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+/// Appends escaped segment to JSON pointer.
+pub(crate) fn pointer_join(path: &str, segment: &str) -> String {
+    format!("{}/{}", path, segment.replace('~', "~0").replace('/', "~1"))
+}
+
+fn pointer_from_path(path: &serde_path_to_error::Path) -> String {
+    use serde_path_to_error::Segment;
+
+    path.iter()
+        .fold(String::new(), |result, segment| match segment {
+            Segment::Seq { index } => pointer_join(&result, &index.to_string()),
+            Segment::Map { key } => pointer_join(&result, key),
+            Segment::Enum { variant } => pointer_join(&result, variant),
+            Segment::Unknown => result,
+        })
+}
+
+pub(crate) fn validate_props<T>(name: &str, data: PrefabValue) -> Result<(), PrefabIssue>
+where
+    T: Prefab,
+{
+    match serde_path_to_error::deserialize::<_, T>(data) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(PrefabIssue {
+            path: pointer_from_path(error.path()),
+            kind: PrefabIssueKind::InvalidProps {
+                name: name.to_owned(),
+                message: error.inner().to_string(),
+            },
+        }),
+    }
+}
+
+/// Walks serialized widget node and collects its issues.
+///
+/// In lenient mode unknown properties are removed from data and reported as warnings instead.
+pub(crate) struct PrefabValidator<'a> {
+    has_component: &'a dyn Fn(&str) -> bool,
+    has_template: &'a dyn Fn(&str) -> bool,
+    props_registry: &'a PropsRegistry,
+    lenient: bool,
+    pub issues: Vec<PrefabIssue>,
+    pub warnings: Vec<PrefabIssue>,
+}
+
+impl<'a> PrefabValidator<'a> {
+    pub fn new(
+        has_component: &'a dyn Fn(&str) -> bool,
+        has_template: &'a dyn Fn(&str) -> bool,
+        props_registry: &'a PropsRegistry,
+        lenient: bool,
+    ) -> Self {
+        Self {
+            has_component,
+            has_template,
+            props_registry,
+            lenient,
+            issues: vec![],
+            warnings: vec![],
+        }
+    }
+
+    pub fn validate(&mut self, data: &mut PrefabValue) {
+        self.node(data, "");
+        // Structure problems not covered by walking the tree get reported by deserialization,
+        // unless already reported by the walk.
+        if self
+            .issues
+            .iter()
+            .any(|issue| matches!(issue.kind, PrefabIssueKind::InvalidNode(_)))
+        {
+            return;
+        }
+        if let Err(error) = serde_path_to_error::deserialize::<_, WidgetNodePrefab>(data.clone()) {
+            self.issues.push(PrefabIssue {
+                path: pointer_from_path(error.path()),
+                kind: PrefabIssueKind::InvalidNode(error.inner().to_string()),
+            });
+        }
+    }
+
+    fn invalid(&mut self, path: &str, message: impl ToString) {
+        self.issues.push(PrefabIssue {
+            path: path.to_owned(),
+            kind: PrefabIssueKind::InvalidNode(message.to_string()),
+        });
+    }
+
+    fn node(&mut self, data: &mut PrefabValue, path: &str) {
+        let variant = match data {
+            PrefabValue::String(variant) if variant == "None" => return,
+            PrefabValue::Object(object) if object.len() == 1 => object.iter_mut().next(),
+            _ => None,
+        };
+        let Some((variant, data)) = variant else {
+            self.invalid(path, "Expected widget node");
+            return;
+        };
+        let path = pointer_join(path, variant);
+        match variant.as_str() {
+            "None" => {}
+            "Component" => self.component(data, &path),
+            "Unit" => self.unit(data, &path),
+            "Tuple" => self.nodes(data, &path),
+            "Template" => self.template(data, &path),
+            _ => self.invalid(&path, format!("Unknown widget node variant: {variant:?}")),
+        }
+    }
+
+    fn nodes(&mut self, data: &mut PrefabValue, path: &str) {
+        let Some(items) = data.as_array_mut() else {
+            self.invalid(path, "Expected list of widget nodes");
+            return;
+        };
+        for (index, item) in items.iter_mut().enumerate() {
+            self.node(item, &pointer_join(path, &index.to_string()));
+        }
+    }
+
+    fn component(&mut self, data: &mut PrefabValue, path: &str) {
+        let Some(object) = data.as_object_mut() else {
+            self.invalid(path, "Expected widget component");
+            return;
+        };
+        let type_name_path = pointer_join(path, "type_name");
+        match object.get("type_name") {
+            Some(PrefabValue::String(type_name)) => {
+                if !(self.has_component)(type_name) {
+                    self.issues.push(PrefabIssue {
+                        path: type_name_path,
+                        kind: PrefabIssueKind::UnknownComponent(type_name.to_owned()),
+                    });
+                }
+            }
+            Some(_) => self.invalid(&type_name_path, "Expected component type name"),
+            None => self.issues.push(PrefabIssue {
+                path: type_name_path,
+                kind: PrefabIssueKind::UnknownComponent(Default::default()),
+            }),
+        }
+        for field in ["props", "shared_props"] {
+            if let Some(props) = object.get_mut(field) {
+                self.props_group(props, &pointer_join(path, field), true);
+            }
+        }
+        self.slots(object, path);
+    }
+
+    /// Parameters are not checked, since their use depends on template content.
+    fn template(&mut self, data: &mut PrefabValue, path: &str) {
+        let Some(object) = data.as_object_mut() else {
+            self.invalid(path, "Expected template instance");
+            return;
+        };
+        let name_path = pointer_join(path, "name");
+        match object.get("name") {
+            Some(PrefabValue::String(name)) => {
+                if !(self.has_template)(name) {
+                    self.issues.push(PrefabIssue {
+                        path: name_path,
+                        kind: PrefabIssueKind::UnknownTemplate(name.to_owned()),
+                    });
+                }
+            }
+            Some(_) => self.invalid(&name_path, "Expected template name"),
+            None => self.issues.push(PrefabIssue {
+                path: name_path,
+                kind: PrefabIssueKind::UnknownTemplate(Default::default()),
+            }),
+        }
+        self.slots(object, path);
+    }
+
+    fn slots(&mut self, object: &mut serde_json::Map<String, PrefabValue>, path: &str) {
+        if let Some(slots) = object.get_mut("listed_slots") {
+            self.nodes(slots, &pointer_join(path, "listed_slots"));
+        }
+        if let Some(slots) = object.get_mut("named_slots") {
+            let path = pointer_join(path, "named_slots");
+            let Some(slots) = slots.as_object_mut() else {
+                self.invalid(&path, "Expected map of widget nodes");
+                return;
+            };
+            for (name, slot) in slots {
+                self.node(slot, &pointer_join(&path, name));
+            }
+        }
+    }
+
+    fn unit(&mut self, data: &mut PrefabValue, path: &str) {
+        let variant = match data {
+            PrefabValue::String(variant) if variant == "None" => return,
+            PrefabValue::Object(object) if object.len() == 1 => object.iter_mut().next(),
+            _ => None,
+        };
+        let Some((variant, data)) = variant else {
+            self.invalid(path, "Expected widget unit");
+            return;
+        };
+        let path = pointer_join(path, variant);
+        if !matches!(
+            variant.as_str(),
+            "None"
+                | "AreaBox"
+                | "PortalBox"
+                | "ContentBox"
+                | "FlexBox"
+                | "GridBox"
+                | "SizeBox"
+                | "ImageBox"
+                | "TextBox"
+        ) {
+            self.invalid(&path, format!("Unknown widget unit variant: {variant:?}"));
+            return;
+        }
+        let Some(object) = data.as_object_mut() else {
+            // Remaining shape problems are left for deserialization to report.
+            return;
+        };
+        if let Some(props) = object.get_mut("props") {
+            self.props_group(props, &pointer_join(&path, "props"), false);
+        }
+        if let Some(slot) = object.get_mut("slot") {
+            let path = pointer_join(&path, "slot");
+            if variant == "PortalBox" {
+                self.portal_slot(slot, &path);
+            } else {
+                self.node(slot, &path);
+            }
+        }
+        if let Some(PrefabValue::Array(items)) = object.get_mut("items") {
+            let path = pointer_join(&path, "items");
+            for (index, item) in items.iter_mut().enumerate() {
+                if let Some(slot) = item.get_mut("slot") {
+                    let path = pointer_join(&pointer_join(&path, &index.to_string()), "slot");
+                    self.node(slot, &path);
+                }
+            }
+        }
+    }
+
+    fn portal_slot(&mut self, data: &mut PrefabValue, path: &str) {
+        let Some((variant, data)) = data
+            .as_object_mut()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.iter_mut().next())
+        else {
+            self.invalid(path, "Expected portal box slot");
+            return;
+        };
+        let path = pointer_join(path, variant);
+        match variant.as_str() {
+            "Slot" => self.node(data, &path),
+            "ContentItem" | "FlexItem" | "GridItem" => {
+                if let Some(slot) = data.get_mut("slot") {
+                    self.node(slot, &pointer_join(&path, "slot"));
+                }
+            }
+            _ => self.invalid(
+                &path,
+                format!("Unknown portal box slot variant: {variant:?}"),
+            ),
+        }
+    }
+
+    /// Only components resolve [bindings][crate::binding] in their properties.
+    fn props_group(&mut self, data: &mut PrefabValue, path: &str, bindings: bool) {
+        let group = match data {
+            PrefabValue::Null => return,
+            PrefabValue::Object(object) => object.get_mut("data"),
+            _ => {
+                self.invalid(path, "Expected properties group");
+                return;
+            }
+        };
+        let path = pointer_join(path, "data");
+        let entries = match group {
+            None => return,
+            Some(PrefabValue::Object(entries)) => entries,
+            Some(_) => {
+                self.invalid(&path, "Expected map of properties");
+                return;
+            }
+        };
+        let mut unknown = vec![];
+        for (name, value) in entries.iter() {
+            let path = pointer_join(&path, name);
+            if !self.props_registry.has_factory(name) {
+                let issue = PrefabIssue {
+                    path,
+                    kind: PrefabIssueKind::UnknownProps(name.to_owned()),
+                };
+                if self.lenient {
+                    unknown.push(name.to_owned());
+                    self.warnings.push(issue);
+                } else {
+                    self.issues.push(issue);
+                }
+            } else if bindings && crate::binding::has_bindings(value) {
+                if let Err((pointer, error)) = crate::binding::check(value) {
+                    self.issues.push(PrefabIssue {
+                        path: format!("{path}{pointer}"),
+                        kind: PrefabIssueKind::InvalidProps {
+                            name: name.to_owned(),
+                            message: error.to_string(),
+                        },
+                    });
+                }
+            } else if let Err(mut issue) = self.props_registry.validate(name, value.clone()) {
+                issue.path = format!("{}{}", path, issue.path);
+                self.issues.push(issue);
+            }
+        }
+        for name in unknown {
+            entries.remove(&name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LogKind, RecordingLogger, application::Application};
+    use serde_json::json;
+
+    fn tree() -> PrefabValue {
+        json!({
+            "Component": {
+                "type_name": "content_box",
+                "props": { "data": { "Foo/Bar": null } },
+                "listed_slots": [
+                    {
+                        "Component": {
+                            "type_name": "text_box",
+                            "props": { "data": { "TextBoxProps": { "text": 42 } } }
+                        }
+                    },
+                    {
+                        "Unit": {
+                            "SizeBox": {
+                                "slot": { "Component": { "type_name": "image_bux" } }
+                            }
+                        }
+                    }
+                ],
+                "named_slots": { "content": "Nothing" }
+            }
+        })
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let issues = application.validate_node(&tree());
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "/Component/props/data/Foo~1Bar",
+                "/Component/listed_slots/0/Component/props/data/TextBoxProps/text",
+                "/Component/listed_slots/1/Unit/SizeBox/slot/Component/type_name",
+                "/Component/named_slots/content",
+            ]
+        );
+        assert_eq!(
+            issues[0].kind,
+            PrefabIssueKind::UnknownProps("Foo/Bar".to_owned())
+        );
+        assert!(matches!(
+            &issues[1].kind,
+            PrefabIssueKind::InvalidProps { name, .. } if name == "TextBoxProps"
+        ));
+        assert_eq!(
+            issues[2].kind,
+            PrefabIssueKind::UnknownComponent("image_bux".to_owned())
+        );
+        assert!(matches!(issues[3].kind, PrefabIssueKind::InvalidNode(_)));
+
+        let issues = application.validate_node(&json!({"Component": {"key": 1}}));
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[1].path, "/Component/key");
+    }
+
+    #[test]
+    fn test_lenient() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        let mut logger = RecordingLogger::default();
+        let data = json!({
+            "Component": {
+                "type_name": "text_box",
+                "props": { "data": { "Unknown": 1, "TextBoxProps": { "text": "hi" } } }
+            }
+        });
+        assert!(
+            application
+                .deserialize_node_validated(data.clone())
+                .is_err()
+        );
+        let node = application
+            .deserialize_node_lenient(data, &mut logger)
+            .unwrap();
+        assert_eq!(logger.0.len(), 1);
+        assert_eq!(logger.0[0].0, LogKind::Warning);
+        assert_eq!(
+            logger.0[0].1,
+            "/Component/props/data/Unknown: Could not find properties factory: \"Unknown\""
+        );
+        assert!(
+            node.as_component()
+                .unwrap()
+                .props
+                .has::<crate::widget::component::text_box::TextBoxProps>()
+        );
+
+        assert!(
+            application
+                .deserialize_node_lenient(tree(), &mut logger)
+                .is_err()
+        );
+    }
+}
@@ -1,9 +1,13 @@
-use crate::widget::{WidgetId, WidgetIdCommon};
+use crate::{
+    PrefabValue,
+    widget::{WidgetId, WidgetIdCommon},
+};
 use intuicio_data::{
     lifetime::{ValueReadAccess, ValueWriteAccess},
     managed::DynamicManaged,
     managed::{Managed, ManagedLazy, ManagedRef, ManagedRefMut},
 };
+use serde::{Serialize, Serializer};
 use std::{
     collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
@@ -177,6 +181,7 @@ impl ViewModelNotifier {
 pub struct ViewModel {
     object: DynamicManaged,
     pub properties: ViewModelProperties,
+    to_prefab: Option<fn(&DynamicManaged) -> Option<PrefabValue>>,
 }
 
 impl ViewModel {
@@ -184,6 +189,7 @@ impl ViewModel {
         Self {
             object: DynamicManaged::new(object).ok().unwrap(),
             properties,
+            to_prefab: None,
         }
     }
 
@@ -194,7 +200,30 @@ impl ViewModel {
     pub fn produce<T: 'static>(producer: impl FnOnce(&mut ViewModelProperties) -> T) -> Self {
         let mut properties = Default::default();
         let object = DynamicManaged::new(producer(&mut properties)).ok().unwrap();
-        Self { object, properties }
+        Self {
+            object,
+            properties,
+            to_prefab: None,
+        }
+    }
+
+    /// Allows reading object as [`PrefabValue`], which is how prefab
+    /// [bindings][crate::binding] read view models.
+    ///
+    /// Reading moved object invalidates lazy handles taken before the move, so take them
+    /// from view model already inserted into its collection.
+    pub fn serializable<T: 'static + Serialize>(mut self) -> Self {
+        self.to_prefab = Some(|object| {
+            object
+                .read::<T>()
+                .and_then(|object| serde_json::to_value(&*object).ok())
+        });
+        self
+    }
+
+    /// Object serialized to [`PrefabValue`], if view model is [serializable][Self::serializable].
+    pub fn to_prefab(&self) -> Option<PrefabValue> {
+        self.to_prefab.and_then(|to_prefab| to_prefab(&self.object))
     }
 
     pub fn borrow<T: 'static>(&self) -> Option<ManagedRef<T>> {
@@ -493,6 +522,15 @@ impl<T> DerefMut for ViewModelValue<T> {
     }
 }
 
+impl<T> Serialize for ViewModelValue<T>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
 impl<T> std::fmt::Display for ViewModelValue<T>
 where
     T: std::fmt::Display,
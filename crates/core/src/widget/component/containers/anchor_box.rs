@@ -11,11 +11,13 @@ use crate::{
         utils::{Rect, Vec2, lerp},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct AnchorProps {
     #[serde(default)]
     pub outer_box_size: Vec2,
@@ -23,9 +25,10 @@ pub struct AnchorProps {
     pub inner_box_rect: Rect,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct AnchorNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -40,9 +43,10 @@ pub struct AnchorNotifyMessage {
     pub prev: AnchorProps,
 }
 
-#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct PivotBoxProps {
     #[serde(default = "PivotBoxProps::default_pivot")]
     pub pivot: Vec2,
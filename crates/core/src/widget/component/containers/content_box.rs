@@ -9,24 +9,38 @@ use crate::{
         },
         context::WidgetContext,
         node::WidgetNode,
-        unit::content::{
-            ContentBoxContentReposition, ContentBoxItemLayout, ContentBoxItemNode, ContentBoxNode,
+        unit::{
+            UnitLayer,
+            content::{
+                ContentBoxClipShape, ContentBoxContentReposition, ContentBoxItemLayout,
+                ContentBoxItemNode, ContentBoxNode,
+            },
         },
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// The properties of a [`content_box`] component
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ContentBoxProps {
     /// Whether or not to clip the parts of items that overflow outside of the box bounds
     #[serde(default)]
     pub clipping: bool,
+    /// The shape of clipping area, used when clipping is enabled
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ContentBoxClipShape::is_rectangle")]
+    pub clip_shape: ContentBoxClipShape,
     /// The content repositioning strategy to use.
     pub content_reposition: ContentBoxContentReposition,
+    /// Opacity and blend mode used to composite the box contents as a whole
+    #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
     /// The transform to apply to the box and it's contents
     #[serde(default)]
     pub transform: Transform,
@@ -79,8 +93,10 @@ pub fn content_box(context: WidgetContext) -> WidgetNode {
 
     let ContentBoxProps {
         clipping,
+        clip_shape,
         transform,
         content_reposition,
+        layer,
     } = props.read_cloned_or_default();
 
     let items = listed_slots
@@ -100,7 +116,9 @@ pub fn content_box(context: WidgetContext) -> WidgetNode {
         props: props.clone(),
         items,
         clipping,
+        clip_shape,
         content_reposition,
+        layer,
         transform,
     }
     .into()
@@ -12,11 +12,13 @@ use crate::{
         utils::{Rect, Vec2},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ContextBoxProps {
     #[serde(default)]
     pub show: bool,
@@ -7,15 +7,20 @@ use crate::{
         },
         context::WidgetContext,
         node::WidgetNode,
-        unit::flex::{FlexBoxDirection, FlexBoxItemLayout, FlexBoxItemNode, FlexBoxNode},
+        unit::{
+            UnitLayer,
+            flex::{FlexBoxDirection, FlexBoxItemLayout, FlexBoxItemNode, FlexBoxNode},
+        },
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct FlexBoxProps {
     #[serde(default)]
     pub direction: FlexBoxDirection,
@@ -24,6 +29,9 @@ pub struct FlexBoxProps {
     #[serde(default)]
     pub wrap: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -62,6 +70,7 @@ pub fn flex_box(context: WidgetContext) -> WidgetNode {
         separation,
         wrap,
         transform,
+        layer,
     } = props.read_cloned_or_default();
 
     let items = listed_slots
@@ -83,6 +92,7 @@ pub fn flex_box(context: WidgetContext) -> WidgetNode {
         direction,
         separation,
         wrap,
+        layer,
         transform,
     }
     .into()
@@ -15,11 +15,13 @@ use crate::{
         utils::Vec2,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct FloatBoxProps {
     #[serde(default)]
     pub bounds_left: Option<Scalar>,
@@ -31,18 +33,20 @@ pub struct FloatBoxProps {
     pub bounds_bottom: Option<Scalar>,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct FloatBoxNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
     pub WidgetIdOrRef,
 );
 
-#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct FloatBoxState {
     #[serde(default)]
     pub position: Vec2,
@@ -7,21 +7,29 @@ use crate::{
         },
         context::WidgetContext,
         node::WidgetNode,
-        unit::grid::{GridBoxItemLayout, GridBoxItemNode, GridBoxNode},
+        unit::{
+            UnitLayer,
+            grid::{GridBoxItemLayout, GridBoxItemNode, GridBoxNode},
+        },
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct GridBoxProps {
     #[serde(default)]
     pub cols: usize,
     #[serde(default)]
     pub rows: usize,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -59,6 +67,7 @@ pub fn grid_box(context: WidgetContext) -> WidgetNode {
         cols,
         rows,
         transform,
+        layer,
     } = props.read_cloned_or_default();
 
     let items = listed_slots
@@ -79,6 +88,7 @@ pub fn grid_box(context: WidgetContext) -> WidgetNode {
         items,
         cols,
         rows,
+        layer,
         transform,
     }
     .into()
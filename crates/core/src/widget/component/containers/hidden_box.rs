@@ -2,11 +2,13 @@ use crate::{
     PropsData, unpack_named_slots,
     widget::{context::WidgetContext, node::WidgetNode, unit::area::AreaBoxNode},
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct HiddenBoxProps(#[serde(default)] pub bool);
 
 pub fn hidden_box(context: WidgetContext) -> WidgetNode {
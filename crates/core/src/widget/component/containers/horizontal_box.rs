@@ -10,15 +10,20 @@ use crate::{
         },
         context::WidgetContext,
         node::WidgetNode,
-        unit::flex::{FlexBoxDirection, FlexBoxItemLayout},
+        unit::{
+            UnitLayer,
+            flex::{FlexBoxDirection, FlexBoxItemLayout},
+        },
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct HorizontalBoxProps {
     #[serde(default)]
     pub separation: Scalar,
@@ -27,6 +32,9 @@ pub struct HorizontalBoxProps {
     #[serde(default)]
     pub override_slots_layout: Option<FlexBoxItemLayout>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -68,6 +76,7 @@ pub fn horizontal_box(context: WidgetContext) -> WidgetNode {
         separation,
         reversed,
         override_slots_layout,
+        layer,
         transform,
     } = props.read_cloned_or_default();
 
@@ -87,6 +96,7 @@ pub fn horizontal_box(context: WidgetContext) -> WidgetNode {
         },
         separation,
         wrap: false,
+        layer,
         transform,
     });
 
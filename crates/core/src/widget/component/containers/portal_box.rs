@@ -13,11 +13,13 @@ use crate::{
         },
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct PortalsContainer(#[serde(default)] pub WidgetRef);
 
 pub fn portal_box(context: WidgetContext) -> WidgetNode {
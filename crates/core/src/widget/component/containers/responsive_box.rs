@@ -9,6 +9,7 @@ use crate::{
         utils::Vec2,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
@@ -18,14 +19,14 @@ pub struct MediaQueryContext<'a> {
     pub view_model: Option<&'a MediaQueryViewModel>,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, JsonSchema)]
 pub enum MediaQueryOrientation {
     #[default]
     Portrait,
     Landscape,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum MediaQueryNumber {
     Exact(Scalar),
     Min(Scalar),
@@ -50,9 +51,10 @@ impl MediaQueryNumber {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub enum MediaQueryExpression {
     #[default]
     Any,
@@ -143,9 +145,10 @@ impl MediaQueryExpression {
     }
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ResponsiveBoxState {
     pub size: Vec2,
 }
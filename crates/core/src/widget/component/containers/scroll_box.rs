@@ -21,7 +21,9 @@ use crate::{
                     NavTrackingNotifyProps, use_nav_container_active, use_nav_item,
                     use_nav_item_active, use_nav_scroll_view_content,
                 },
-                scroll_view::{ScrollViewState, use_scroll_view},
+                scroll_view::{
+                    ScrollViewGrabMessage, ScrollViewKinetics, ScrollViewState, use_scroll_view,
+                },
             },
             use_resize_listener,
         },
@@ -34,20 +36,23 @@ use crate::{
         utils::{Rect, Vec2, lerp},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ScrollBoxOwner(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetId::is_none")]
     pub WidgetId,
 );
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct SideScrollbarsProps {
     #[serde(default)]
     pub size: Scalar,
@@ -68,9 +73,10 @@ impl Default for SideScrollbarsProps {
     }
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct SideScrollbarsState {
     pub horizontal_state: ButtonProps,
     pub vertical_state: ButtonProps,
@@ -111,6 +117,10 @@ pub fn nav_scroll_box_content(mut context: WidgetContext) -> WidgetNode {
 }
 
 pub fn use_nav_scroll_box(context: &mut WidgetContext) {
+    context.life_cycle.unmount(|context| {
+        context.signals.write(NavSignal::Unlock);
+    });
+
     context.life_cycle.change(|context| {
         for msg in context.messenger.messages {
             if let Some(ResizeListenerSignal::Change(_)) = msg.as_any().downcast_ref()
@@ -121,6 +131,39 @@ pub fn use_nav_scroll_box(context: &mut WidgetContext) {
                     .write(NavSignal::Jump(NavJump::Scroll(NavScroll::Factor(
                         data.value, false,
                     ))));
+            } else if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>()
+                && msg.sender.key() == "input-consumer"
+            {
+                if msg.trigger_start() {
+                    context.signals.write(NavSignal::Lock);
+                    context
+                        .signals
+                        .write(NavSignal::CapturePointer(msg.sender.to_owned().into()));
+                    context
+                        .messenger
+                        .write(context.id.to_owned(), ScrollViewGrabMessage::Grab);
+                }
+                if msg.trigger_stop() {
+                    context.signals.write(NavSignal::Unlock);
+                    context
+                        .signals
+                        .write(NavSignal::ReleasePointer(msg.sender.to_owned().into()));
+                    context
+                        .messenger
+                        .write(context.id.to_owned(), ScrollViewGrabMessage::Release);
+                }
+            } else if let Some(msg) = msg.as_any().downcast_ref::<NavTrackingNotifyMessage>()
+                && msg.sender.key() == "input-consumer"
+            {
+                // Input consumer covers whole view, so its tracking factor is in view sizes.
+                let delta = msg.pointer_delta_factor();
+                context.messenger.write(
+                    context.id.to_owned(),
+                    ScrollViewGrabMessage::Move(Vec2 {
+                        x: -delta.x,
+                        y: -delta.y,
+                    }),
+                );
             }
         }
     });
@@ -170,11 +213,20 @@ pub fn nav_scroll_box(mut context: WidgetContext) -> WidgetNode {
         ..Default::default()
     };
 
+    // With kinetics content can be dragged around by grabbing the space behind it.
+    let input_consumer = if props.has::<ScrollViewKinetics>() {
+        make_widget!(self_tracked_button)
+            .with_props(ButtonNotifyProps(id.to_owned().into()))
+            .with_props(NavTrackingNotifyProps(id.to_owned().into()))
+    } else {
+        make_widget!(button)
+    };
+
     let content = make_widget!(content_box)
         .key(key)
         .merge_props(props.clone())
         .listed_slot(
-            make_widget!(button)
+            input_consumer
                 .key("input-consumer")
                 .with_props(NavItemActive)
                 .named_slot(
@@ -218,9 +270,15 @@ pub fn use_nav_scroll_box_side_scrollbars(context: &mut WidgetContext) {
             if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>() {
                 if msg.trigger_start() {
                     context.signals.write(NavSignal::Lock);
+                    context
+                        .signals
+                        .write(NavSignal::CapturePointer(msg.sender.to_owned().into()));
                 }
                 if msg.trigger_stop() {
                     context.signals.write(NavSignal::Unlock);
+                    context
+                        .signals
+                        .write(NavSignal::ReleasePointer(msg.sender.to_owned().into()));
                 }
                 if msg.sender.key() == "hbar" {
                     state.horizontal_state = msg.state;
@@ -7,15 +7,20 @@ use crate::{
         },
         context::WidgetContext,
         node::WidgetNode,
-        unit::content::{ContentBoxItemNode, ContentBoxNode},
+        unit::{
+            UnitLayer,
+            content::{ContentBoxItemNode, ContentBoxNode},
+        },
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct SwitchBoxProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -23,6 +28,9 @@ pub struct SwitchBoxProps {
     #[serde(default)]
     pub clipping: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -60,6 +68,7 @@ pub fn switch_box(context: WidgetContext) -> WidgetNode {
         active_index,
         clipping,
         transform,
+        layer,
     } = props.read_cloned_or_default();
 
     let items = if let Some(index) = active_index {
@@ -80,7 +89,9 @@ pub fn switch_box(context: WidgetContext) -> WidgetNode {
         props: props.clone(),
         items,
         clipping,
+        clip_shape: Default::default(),
         content_reposition: Default::default(),
+        layer,
         transform,
     }
     .into()
@@ -18,9 +18,10 @@ use crate::{
         utils::Transform,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum TabsBoxTabsLocation {
     #[default]
     Top,
@@ -29,9 +30,10 @@ pub enum TabsBoxTabsLocation {
     Right,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TabsBoxProps {
     #[serde(default)]
     pub tabs_location: TabsBoxTabsLocation,
@@ -47,17 +49,19 @@ pub struct TabsBoxProps {
     pub transform: Transform,
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TabsState {
     #[serde(default)]
     pub active_index: usize,
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TabPlateProps {
     #[serde(default)]
     pub active: bool,
@@ -76,6 +80,7 @@ impl TabsBoxProps {
             },
             separation: self.tabs_and_content_separation,
             wrap: false,
+            layer: Default::default(),
             transform: self.transform.to_owned(),
         }
     }
@@ -2,11 +2,13 @@ use crate::{
     PropsData,
     widget::{context::WidgetContext, node::WidgetNode},
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct VariantBoxProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
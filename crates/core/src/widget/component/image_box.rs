@@ -1,21 +1,30 @@
 use crate::{
-    PropsData,
+    PropsData, Scalar,
+    animator::{AnimatedValue, Animation},
+    pre_hooks,
+    props::Props,
     widget::{
         component::WidgetAlpha,
         context::WidgetContext,
         node::WidgetNode,
         unit::image::{
-            ImageBoxAspectRatio, ImageBoxColor, ImageBoxImage, ImageBoxMaterial, ImageBoxNode,
+            ImageBoxAspectRatio, ImageBoxColor, ImageBoxFlipbook, ImageBoxFlipbookFrames,
+            ImageBoxFlipbookLoop, ImageBoxImage, ImageBoxMaterial, ImageBoxNode, ImageBoxShape,
             ImageBoxSizeValue,
         },
-        utils::{Color, Transform},
+        utils::{Color, Gradient, Shadow, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+const FLIPBOOK_ANIMATION: &str = "image-box-flipbook";
+const TIME_VALUE: &str = "time";
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ImageBoxProps {
     #[serde(default)]
     pub width: ImageBoxSizeValue,
@@ -27,6 +36,9 @@ pub struct ImageBoxProps {
     #[serde(default)]
     pub material: ImageBoxMaterial,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -41,6 +53,20 @@ impl ImageBoxProps {
         }
     }
 
+    pub fn gradient(gradient: Gradient) -> Self {
+        Self {
+            material: ImageBoxMaterial::Gradient(gradient),
+            ..Default::default()
+        }
+    }
+
+    pub fn shape(shape: ImageBoxShape) -> Self {
+        Self {
+            material: ImageBoxMaterial::Shape(shape),
+            ..Default::default()
+        }
+    }
+
     pub fn image(id: impl ToString) -> Self {
         Self {
             material: ImageBoxMaterial::Image(ImageBoxImage {
@@ -51,6 +77,13 @@ impl ImageBoxProps {
         }
     }
 
+    pub fn flipbook(flipbook: ImageBoxFlipbook) -> Self {
+        Self {
+            material: ImageBoxMaterial::Flipbook(flipbook),
+            ..Default::default()
+        }
+    }
+
     pub fn image_aspect_ratio(id: impl ToString, outside: bool) -> Self {
         Self {
             material: ImageBoxMaterial::Image(ImageBoxImage {
@@ -67,11 +100,127 @@ impl ImageBoxProps {
     }
 }
 
-pub fn image_box(context: WidgetContext) -> WidgetNode {
+/// Tells if flipbook played once has already reached its last frame.
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct ImageBoxFlipbookState {
+    #[serde(default)]
+    pub finished: bool,
+    /// Frames of flipbook that is played, animation restarts when they or its timing change.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frames: Option<ImageBoxFlipbookFrames>,
+    #[serde(default)]
+    pub fps: Scalar,
+    #[serde(default)]
+    pub loop_mode: ImageBoxFlipbookLoop,
+}
+
+impl ImageBoxFlipbookState {
+    fn new(props: &Props) -> Self {
+        props.map_or_default::<ImageBoxProps, _, _>(|props| match &props.material {
+            ImageBoxMaterial::Flipbook(flipbook) => Self {
+                finished: false,
+                frames: Some(flipbook.frames.clone()),
+                fps: flipbook.fps,
+                loop_mode: flipbook.loop_mode,
+            },
+            _ => Default::default(),
+        })
+    }
+
+    /// Tells if both states describe the same flipbook, regardless of its progress.
+    fn same_flipbook(&self, other: &Self) -> bool {
+        self.frames == other.frames && self.fps == other.fps && self.loop_mode == other.loop_mode
+    }
+}
+
+fn flipbook_animation(props: &Props) -> Option<(Animation, ImageBoxFlipbookLoop)> {
+    props.map_or_default::<ImageBoxProps, _, _>(|props| match &props.material {
+        ImageBoxMaterial::Flipbook(flipbook) => {
+            let duration = flipbook.cycle_duration();
+            if duration <= 0.0 {
+                return None;
+            }
+            let animation = Animation::Value(AnimatedValue {
+                name: TIME_VALUE.to_owned(),
+                duration,
+            });
+            let animation = match flipbook.loop_mode {
+                ImageBoxFlipbookLoop::Once => animation,
+                _ => Animation::Looped(Box::new(animation)),
+            };
+            Some((animation, flipbook.loop_mode))
+        }
+        _ => None,
+    })
+}
+
+pub fn use_image_box(context: &mut WidgetContext) {
+    context.life_cycle.mount(|context| {
+        let state = context
+            .state
+            .read_cloned_or_default::<ImageBoxFlipbookState>();
+        let current = ImageBoxFlipbookState::new(context.props);
+        let finished = if state.same_flipbook(&current) {
+            state.finished
+        } else {
+            let _ = context.state.write_with(current);
+            false
+        };
+        if let Some((animation, _)) = flipbook_animation(context.props).filter(|_| !finished) {
+            let _ = context.animator.change(FLIPBOOK_ANIMATION, Some(animation));
+        }
+    });
+
+    context.life_cycle.change(|context| {
+        let running = context.animator.has(FLIPBOOK_ANIMATION);
+        let mut state = context
+            .state
+            .read_cloned_or_default::<ImageBoxFlipbookState>();
+        let current = ImageBoxFlipbookState::new(context.props);
+        let changed = !state.same_flipbook(&current);
+        if changed {
+            // Flipbook got replaced, so it plays from the start, even if it played once already.
+            state = current;
+            let _ = context.state.write_with(state.clone());
+        }
+        match flipbook_animation(context.props) {
+            Some((animation, loop_mode)) => {
+                if loop_mode == ImageBoxFlipbookLoop::Once && !changed {
+                    let ended = context
+                        .animator
+                        .value_progress(FLIPBOOK_ANIMATION, TIME_VALUE)
+                        .map(|progress| progress.progress_factor >= 1.0)
+                        .unwrap_or_default();
+                    if ended && !state.finished {
+                        state.finished = true;
+                        let _ = context.state.write_with(state.clone());
+                    }
+                }
+                if (changed || !running) && !state.finished {
+                    let _ = context.animator.change(FLIPBOOK_ANIMATION, Some(animation));
+                }
+            }
+            None => {
+                if running {
+                    let _ = context.animator.change(FLIPBOOK_ANIMATION, None);
+                }
+            }
+        }
+    });
+}
+
+#[pre_hooks(use_image_box)]
+pub fn image_box(mut context: WidgetContext) -> WidgetNode {
     let WidgetContext {
         id,
         props,
         shared_props,
+        state,
+        animator,
         ..
     } = context;
 
@@ -80,6 +229,7 @@ pub fn image_box(context: WidgetContext) -> WidgetNode {
         height,
         content_keep_aspect_ratio,
         mut material,
+        mut shadows,
         transform,
     } = props.read_cloned_or_default();
 
@@ -91,8 +241,40 @@ pub fn image_box(context: WidgetContext) -> WidgetNode {
         ImageBoxMaterial::Image(image) => {
             image.tint.a *= alpha;
         }
+        ImageBoxMaterial::Shape(shape) => {
+            shape.fill.a *= alpha;
+            shape.stroke_color.a *= alpha;
+        }
+        ImageBoxMaterial::Gradient(gradient) => {
+            gradient.multiply_alpha(alpha);
+        }
+        ImageBoxMaterial::Flipbook(flipbook) => {
+            flipbook.tint.a *= alpha;
+            // Animation and state of flipbook these props just replaced are still there until
+            // change hook restarts them, so they are ignored in the meantime.
+            let state = state.read_cloned_or_default::<ImageBoxFlipbookState>();
+            let current = state.same_flipbook(&ImageBoxFlipbookState::new(props));
+            if flipbook.fps > 0.0 {
+                flipbook.frame = match animator
+                    .value_progress(FLIPBOOK_ANIMATION, TIME_VALUE)
+                    .filter(|_| current)
+                {
+                    Some(progress) => flipbook.frame_at(progress.time),
+                    None if flipbook.loop_mode == ImageBoxFlipbookLoop::Once
+                        && current
+                        && state.finished =>
+                    {
+                        flipbook.frames_count().saturating_sub(1)
+                    }
+                    None => 0,
+                };
+            }
+        }
         _ => {}
     }
+    for shadow in &mut shadows {
+        shadow.color.a *= alpha;
+    }
 
     ImageBoxNode {
         id: id.to_owned(),
@@ -101,7 +283,74 @@ pub fn image_box(context: WidgetContext) -> WidgetNode {
         height,
         content_keep_aspect_ratio,
         material,
+        shadows,
         transform,
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{application::Application, make_widget, widget::unit::WidgetUnit};
+
+    fn flipbook_box(frames: usize, loop_mode: ImageBoxFlipbookLoop) -> WidgetNode {
+        make_widget!(image_box)
+            .key("flipbook")
+            .with_props(ImageBoxProps::flipbook(
+                ImageBoxFlipbook::new(ImageBoxFlipbookFrames::Grid {
+                    id: "sheet".to_owned(),
+                    columns: frames,
+                    rows: 1,
+                    count: None,
+                })
+                .fps(10.0)
+                .loop_mode(loop_mode),
+            ))
+            .into()
+    }
+
+    fn frame(application: &Application) -> usize {
+        match application.rendered_tree() {
+            WidgetUnit::ImageBox(unit) => match &unit.material {
+                ImageBoxMaterial::Flipbook(flipbook) => flipbook.frame,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Applies tree and tells flipbook frame rendered in each of following frames.
+    fn frames(application: &mut Application, tree: WidgetNode) -> Vec<usize> {
+        application.apply(tree);
+        (0..6)
+            .map(|_| {
+                application.process();
+                frame(application)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_flipbook_change() {
+        let mut application = Application::default();
+        application.setup(crate::widget::setup);
+        application.animations_delta_time = 0.06;
+        let once = || flipbook_box(2, ImageBoxFlipbookLoop::Once);
+        assert_eq!(frames(&mut application, once()), vec![0, 0, 1, 1, 1, 1]);
+        // Same flipbook stays at its last frame.
+        assert_eq!(frames(&mut application, once()), vec![1; 6]);
+        // Different frames play from the start.
+        let three = |loop_mode| flipbook_box(3, loop_mode);
+        assert_eq!(
+            frames(&mut application, three(ImageBoxFlipbookLoop::Once)),
+            vec![0, 0, 1, 1, 2, 2]
+        );
+        // So does different loop mode, as well as switching back to played flipbook.
+        assert_eq!(
+            frames(&mut application, three(ImageBoxFlipbookLoop::Loop)),
+            vec![0, 0, 1, 1, 2, 2]
+        );
+        assert_eq!(frames(&mut application, once()), vec![0, 0, 1, 1, 1, 1]);
+    }
+}
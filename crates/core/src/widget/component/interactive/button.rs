@@ -1,5 +1,8 @@
 use crate::{
-    MessageData, PropsData, pre_hooks, unpack_named_slots,
+    MessageData, PropsData, Scalar,
+    accessibility::{AccessibilityAction, AccessibilityProps},
+    animator::{AnimatedValue, Animation},
+    pre_hooks, unpack_named_slots,
     widget::{
         WidgetId, WidgetIdOrRef,
         component::interactive::navigation::{
@@ -10,15 +13,25 @@ use crate::{
         unit::area::AreaBoxNode,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+const HOLD_ANIMATION: &str = "button-hold";
+const CLICK_ANIMATION: &str = "button-click";
+const TIME_VALUE: &str = "time";
+
 fn is_false(v: &bool) -> bool {
     !*v
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+fn is_zero(v: &Scalar) -> bool {
+    v.abs() < 1.0e-6
+}
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ButtonProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "is_false")]
@@ -29,11 +42,52 @@ pub struct ButtonProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "is_false")]
     pub context: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub long_press: bool,
+}
+
+/// Configures multi-click and long-press detection of buttons.
+///
+/// Time is measured by widget animator, so it advances with `Application::animations_delta_time`.
+/// Zero time disables given detection (default).
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct ButtonDetectionProps {
+    /// Max time between trigger release and next trigger press to count it as consecutive click.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub multi_click_time: Scalar,
+    /// Time trigger has to be held down to report long press.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub long_press_time: Scalar,
+}
+
+impl ButtonDetectionProps {
+    pub fn is_enabled(&self) -> bool {
+        !is_zero(&self.multi_click_time) || !is_zero(&self.long_press_time)
+    }
+}
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct ButtonDetectionState {
+    #[serde(default)]
+    pub click_count: usize,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub hold_duration: Scalar,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ButtonNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -46,6 +100,11 @@ pub struct ButtonNotifyMessage {
     pub sender: WidgetId,
     pub state: ButtonProps,
     pub prev: ButtonProps,
+    /// Number of consecutive clicks, counting current one.
+    pub click_count: usize,
+    /// For how long trigger has been held down (or was held down, when released).
+    /// Measured only when [`ButtonDetectionProps`] detection is enabled.
+    pub hold_duration: Scalar,
 }
 
 impl ButtonNotifyMessage {
@@ -84,6 +143,26 @@ impl ButtonNotifyMessage {
     pub fn context_changed(&self) -> bool {
         self.prev.context != self.state.context
     }
+
+    pub fn long_press_start(&self) -> bool {
+        !self.prev.long_press && self.state.long_press
+    }
+
+    pub fn long_press_stop(&self) -> bool {
+        self.prev.long_press && !self.state.long_press
+    }
+
+    pub fn long_press_changed(&self) -> bool {
+        self.prev.long_press != self.state.long_press
+    }
+
+    pub fn multi_click(&self, count: usize) -> bool {
+        self.trigger_start() && self.click_count == count
+    }
+
+    pub fn double_click(&self) -> bool {
+        self.multi_click(2)
+    }
 }
 
 pub fn use_button_notified_state(context: &mut WidgetContext) {
@@ -113,17 +192,33 @@ pub fn use_button(context: &mut WidgetContext) {
                 sender: context.id.to_owned(),
                 state: Default::default(),
                 prev: Default::default(),
+                click_count: 0,
+                hold_duration: 0.0,
             },
         );
         let _ = context.state.write_with(ButtonProps::default());
+        let _ = context.state.write_with(ButtonDetectionState::default());
     });
 
     context.life_cycle.change(|context| {
+        let detection = context
+            .props
+            .read_cloned_or_default::<ButtonDetectionProps>();
         let mut dirty = false;
         let mut data = context.state.read_cloned_or_default::<ButtonProps>();
-        let prev = data;
+        let mut detection_state = context
+            .state
+            .read_cloned_or_default::<ButtonDetectionState>();
+        let mut prev = data;
+        let mut click = None;
         for msg in context.messenger.messages {
-            if let Some(msg) = msg.as_any().downcast_ref() {
+            if let Some(msg) = msg.as_any().downcast_ref::<AccessibilityAction>() {
+                match msg {
+                    AccessibilityAction::Click => click = Some(false),
+                    AccessibilityAction::ContextClick => click = Some(true),
+                    _ => {}
+                }
+            } else if let Some(msg) = msg.as_any().downcast_ref() {
                 match msg {
                     NavSignal::Select(_) => {
                         data.selected = true;
@@ -145,6 +240,77 @@ pub fn use_button(context: &mut WidgetContext) {
                 }
             }
         }
+        // Accessibility clicks report press immediately followed by release.
+        if let Some(context_click) = click {
+            let mut pressed = data;
+            if context_click {
+                pressed.context = true;
+                data.context = false;
+            } else {
+                pressed.trigger = true;
+                data.trigger = false;
+                detection_state.click_count = 1;
+            }
+            notify(
+                &context,
+                ButtonNotifyMessage {
+                    sender: context.id.to_owned(),
+                    state: pressed,
+                    prev,
+                    click_count: detection_state.click_count,
+                    hold_duration: 0.0,
+                },
+            );
+            prev = pressed;
+            dirty = true;
+        }
+        if detection.is_enabled() {
+            if let Some(progress) = context.animator.value_progress(HOLD_ANIMATION, TIME_VALUE) {
+                detection_state.hold_duration = progress.time;
+            }
+            if !prev.trigger && data.trigger {
+                let within_window = context
+                    .animator
+                    .value_progress(CLICK_ANIMATION, TIME_VALUE)
+                    .map(|progress| progress.time < progress.duration)
+                    .unwrap_or_default();
+                detection_state.click_count = if within_window {
+                    detection_state.click_count + 1
+                } else {
+                    1
+                };
+                detection_state.hold_duration = 0.0;
+                let _ = context.animator.change(
+                    HOLD_ANIMATION,
+                    Some(Animation::Value(AnimatedValue {
+                        name: TIME_VALUE.to_owned(),
+                        duration: Scalar::MAX,
+                    })),
+                );
+                let _ = context.animator.change(CLICK_ANIMATION, None);
+            } else if prev.trigger && !data.trigger {
+                data.long_press = false;
+                let _ = context.animator.change(HOLD_ANIMATION, None);
+                if !is_zero(&detection.multi_click_time) {
+                    let _ = context.animator.change(
+                        CLICK_ANIMATION,
+                        Some(Animation::Value(AnimatedValue {
+                            name: TIME_VALUE.to_owned(),
+                            duration: detection.multi_click_time,
+                        })),
+                    );
+                }
+            } else if data.trigger
+                && !data.long_press
+                && !is_zero(&detection.long_press_time)
+                && detection_state.hold_duration >= detection.long_press_time
+            {
+                data.long_press = true;
+                dirty = true;
+            }
+        } else if !prev.trigger && data.trigger {
+            detection_state.click_count = 1;
+        }
         if dirty {
             notify(
                 &context,
@@ -152,9 +318,12 @@ pub fn use_button(context: &mut WidgetContext) {
                     sender: context.id.to_owned(),
                     state: data.to_owned(),
                     prev,
+                    click_count: detection_state.click_count,
+                    hold_duration: detection_state.hold_duration,
                 },
             );
             let _ = context.state.write_with(data);
+            let _ = context.state.write_with(detection_state);
         }
     });
 }
@@ -163,14 +332,22 @@ pub fn use_button(context: &mut WidgetContext) {
 pub fn button(mut context: WidgetContext) -> WidgetNode {
     let WidgetContext {
         id,
+        props,
         state,
         named_slots,
         ..
     } = context;
     unpack_named_slots!(named_slots => content);
 
+    let button_props = state.read_cloned_or_default::<ButtonProps>();
+    if let Ok(mut accessibility) = props.read_cloned::<AccessibilityProps>() {
+        accessibility.states.selected = button_props.selected;
+        accessibility.states.pressed = button_props.trigger;
+        props.write(accessibility);
+    }
+
     if let Some(p) = content.props_mut() {
-        p.write(state.read_cloned_or_default::<ButtonProps>());
+        p.write(button_props);
     }
 
     AreaBoxNode {
@@ -189,3 +366,105 @@ pub fn tracked_button(mut context: WidgetContext) -> WidgetNode {
 pub fn self_tracked_button(mut context: WidgetContext) -> WidgetNode {
     button(context)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::Application,
+        make_widget,
+        view_model::ViewModel,
+        widget::{WidgetRef, component::containers::content_box::content_box},
+    };
+
+    const RECORDER: &str = "recorder";
+
+    fn listener(context: WidgetContext) -> WidgetNode {
+        context.life_cycle.change(|mut context| {
+            let mut messages = context
+                .view_models
+                .view_model_mut(RECORDER)
+                .unwrap()
+                .write::<Vec<ButtonNotifyMessage>>()
+                .unwrap();
+            for msg in context.messenger.messages {
+                if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>() {
+                    messages.push(msg.to_owned());
+                }
+            }
+        });
+        WidgetNode::None
+    }
+
+    #[test]
+    fn test_button_detection() {
+        let listener_ref = WidgetRef::default();
+        let button_ref = WidgetRef::default();
+        let mut application = Application::default();
+        application.view_models.insert(
+            RECORDER.to_owned(),
+            ViewModel::new_object(Vec::<ButtonNotifyMessage>::new()),
+        );
+        application.apply(
+            make_widget!(content_box)
+                .listed_slot(make_widget!(listener).idref(listener_ref.clone()))
+                .listed_slot(
+                    make_widget!(button)
+                        .idref(button_ref.clone())
+                        .with_props(ButtonNotifyProps(listener_ref.into()))
+                        .with_props(ButtonDetectionProps {
+                            multi_click_time: 0.5,
+                            long_press_time: 0.25,
+                        }),
+                ),
+        );
+        application.animations_delta_time = 0.1;
+        application.process();
+        let id = button_ref.read().unwrap();
+
+        let frame = |application: &mut Application, accept: Option<bool>| {
+            if let Some(accept) = accept {
+                application.send_message(&id, NavSignal::Accept(accept));
+            }
+            application.process();
+            application.process();
+            application
+                .view_models
+                .get_mut(RECORDER)
+                .unwrap()
+                .write::<Vec<ButtonNotifyMessage>>()
+                .unwrap()
+                .drain(..)
+                .filter(|msg| msg.trigger_changed() || msg.long_press_changed())
+                .collect::<Vec<_>>()
+        };
+
+        let messages = frame(&mut application, Some(true));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].trigger_start());
+        assert_eq!(messages[0].click_count, 1);
+
+        let messages = frame(&mut application, None);
+        assert!(messages.is_empty());
+        let messages = frame(&mut application, None);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].long_press_start());
+        assert!(messages[0].hold_duration >= 0.25);
+
+        let messages = frame(&mut application, Some(false));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].trigger_stop());
+        assert!(messages[0].long_press_stop());
+
+        let messages = frame(&mut application, Some(true));
+        assert!(messages[0].double_click());
+        let _ = frame(&mut application, Some(false));
+
+        for _ in 0..5 {
+            frame(&mut application, None);
+        }
+        let messages = frame(&mut application, Some(true));
+        assert!(messages[0].trigger_start());
+        assert_eq!(messages[0].click_count, 1);
+    }
+}
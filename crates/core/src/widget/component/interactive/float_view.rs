@@ -30,6 +30,7 @@ pub fn use_float_view_control(context: &mut WidgetContext) {
         .write(NavTrackingNotifyProps(context.id.to_owned().into()));
 
     context.life_cycle.unmount(|context| {
+        context.signals.write(NavSignal::ReleasePointer(().into()));
         context.signals.write(NavSignal::Unlock);
     });
 
@@ -50,9 +51,11 @@ pub fn use_float_view_control(context: &mut WidgetContext) {
             if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>() {
                 if msg.trigger_start() {
                     context.signals.write(NavSignal::Lock);
+                    context.signals.write(NavSignal::CapturePointer(().into()));
                 }
                 if msg.trigger_stop() {
                     context.signals.write(NavSignal::Unlock);
+                    context.signals.write(NavSignal::ReleasePointer(().into()));
                 }
             } else if let Some(msg) = msg.as_any().downcast_ref::<NavTrackingNotifyMessage>()
                 && button.selected
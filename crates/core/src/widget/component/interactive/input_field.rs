@@ -1,5 +1,9 @@
 use crate::{
-    Integer, MessageData, PropsData, Scalar, UnsignedInteger, pre_hooks, unpack_named_slots,
+    Integer, MessageData, PropsData, Scalar, UnsignedInteger,
+    accessibility::{AccessibilityAction, AccessibilityProps},
+    pre_hooks,
+    props::Props,
+    unpack_named_slots,
     view_model::ViewModelValue,
     widget::{
         WidgetId, WidgetIdOrRef,
@@ -13,6 +17,7 @@ use crate::{
     },
 };
 use intuicio_data::managed::ManagedLazy;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -98,9 +103,10 @@ impl<T: TextInputProxy + 'static> From<ManagedLazy<T>> for TextInput {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub enum TextInputMode {
     #[default]
     Text,
@@ -159,9 +165,10 @@ impl TextInputMode {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TextInputState {
     #[serde(default)]
     #[serde(skip_serializing_if = "is_false")]
@@ -171,9 +178,10 @@ pub struct TextInputState {
     pub cursor_position: usize,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TextInputProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "is_false")]
@@ -183,18 +191,20 @@ pub struct TextInputProps {
     pub text: Option<TextInput>,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TextInputNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
     pub WidgetIdOrRef,
 );
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TextInputControlNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -261,7 +271,14 @@ pub fn use_text_input(context: &mut WidgetContext) {
         let mut dirty_state = false;
         let mut submitted = false;
         for msg in context.messenger.messages {
-            if let Some(msg) = msg.as_any().downcast_ref() {
+            if let Some(AccessibilityAction::SetText(value)) = msg.as_any().downcast_ref() {
+                if mode.is_valid(value) {
+                    text = value.to_owned();
+                    state.cursor_position = text.chars().count();
+                    dirty_text = true;
+                    dirty_state = true;
+                }
+            } else if let Some(msg) = msg.as_any().downcast_ref() {
                 match msg {
                     NavSignal::FocusTextInput(idref) => {
                         state.focused = idref.is_some();
@@ -430,6 +447,18 @@ pub fn use_input_field(context: &mut WidgetContext) {
     });
 }
 
+fn update_accessibility(props: &mut Props, state: &TextInputState, text_props: &TextInputProps) {
+    if let Ok(mut accessibility) = props.read_cloned::<AccessibilityProps>() {
+        accessibility.value = text_props
+            .text
+            .as_ref()
+            .map(|text| text.get())
+            .unwrap_or_default();
+        accessibility.states.focused = state.focused;
+        props.write(accessibility);
+    }
+}
+
 #[pre_hooks(use_nav_item, use_text_input)]
 pub fn text_input(mut context: WidgetContext) -> WidgetNode {
     let WidgetContext {
@@ -441,9 +470,13 @@ pub fn text_input(mut context: WidgetContext) -> WidgetNode {
     } = context;
     unpack_named_slots!(named_slots => content);
 
+    let text_input_state = state.read_cloned_or_default::<TextInputState>();
+    let text_input_props = props.read_cloned_or_default::<TextInputProps>();
+    update_accessibility(props, &text_input_state, &text_input_props);
+
     if let Some(p) = content.props_mut() {
-        p.write(state.read_cloned_or_default::<TextInputState>());
-        p.write(props.read_cloned_or_default::<TextInputProps>());
+        p.write(text_input_state);
+        p.write(text_input_props);
     }
 
     AreaBoxNode {
@@ -464,10 +497,15 @@ pub fn input_field(mut context: WidgetContext) -> WidgetNode {
     } = context;
     unpack_named_slots!(named_slots => content);
 
+    let button_props = state.read_cloned_or_default::<ButtonProps>();
+    let text_input_state = state.read_cloned_or_default::<TextInputState>();
+    let text_input_props = props.read_cloned_or_default::<TextInputProps>();
+    update_accessibility(props, &text_input_state, &text_input_props);
+
     if let Some(p) = content.props_mut() {
-        p.write(state.read_cloned_or_default::<ButtonProps>());
-        p.write(state.read_cloned_or_default::<TextInputState>());
-        p.write(props.read_cloned_or_default::<TextInputProps>());
+        p.write(button_props);
+        p.write(text_input_state);
+        p.write(text_input_props);
     }
 
     AreaBoxNode {
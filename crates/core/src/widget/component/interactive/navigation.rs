@@ -1,39 +1,51 @@
 use crate::{
-    MessageData, PropsData, Scalar, post_hooks, pre_hooks, unpack_named_slots,
+    MessageData, PropsData, Scalar,
+    accessibility::AccessibilityAction,
+    post_hooks, pre_hooks, unpack_named_slots,
     widget::{
         WidgetId, WidgetIdOrRef, component::containers::portal_box::PortalsContainer,
         context::WidgetContext, node::WidgetNode, unit::area::AreaBoxNode, utils::Vec2,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavAutoSelect;
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavItemActive;
 
-#[derive(PropsData, Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavTrackingActive(#[serde(default)] pub WidgetIdOrRef);
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavTrackingNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
     pub WidgetIdOrRef,
 );
 
-#[derive(PropsData, Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavTrackingProps {
     #[serde(default)]
     pub factor: Vec2,
@@ -79,29 +91,50 @@ impl NavTrackingNotifyMessage {
     }
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavLockingActive;
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct NavFocusScopeActive;
+
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavContainerActive;
 
-#[derive(PropsData, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavContainerDesiredSelection(#[serde(default)] pub WidgetIdOrRef);
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavJumpActive(#[serde(default)] pub NavJumpMode);
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavJumpLooped;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -114,6 +147,9 @@ pub enum NavType {
     ScrollViewContent,
     /// (tracked widget)
     Tracking(WidgetIdOrRef),
+    /// Traps selection inside its widget subtree while registered and restores previously
+    /// selected item when unregistered.
+    FocusScope,
 }
 
 #[derive(MessageData, Debug, Default, Clone)]
@@ -127,6 +163,10 @@ pub enum NavSignal {
     Unselect,
     Lock,
     Unlock,
+    /// Routes pointer button actions to given widget (or sender if none) until pointer gets
+    /// released.
+    CapturePointer(WidgetIdOrRef),
+    ReleasePointer(WidgetIdOrRef),
     Accept(bool),
     Context(bool),
     Cancel(bool),
@@ -143,7 +183,7 @@ pub enum NavSignal {
     Custom(WidgetIdOrRef, String),
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum NavJumpMode {
     #[default]
     Direction,
@@ -152,9 +192,10 @@ pub enum NavJumpMode {
     StepPages,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct NavJumpMapProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -176,7 +217,7 @@ pub struct NavJumpMapProps {
     pub next: WidgetIdOrRef,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum NavDirection {
     #[default]
     None,
@@ -202,7 +243,7 @@ pub enum NavJump {
     Scroll(NavScroll),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum NavTextChange {
     InsertCharacter(char),
     MoveCursorLeft,
@@ -214,7 +255,7 @@ pub enum NavTextChange {
     NewLine,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum NavScroll {
     /// (factor location, relative)
     Factor(Vec2, bool),
@@ -505,6 +546,16 @@ pub fn use_nav_item(context: &mut WidgetContext) {
     context.life_cycle.unmount(|context| {
         context.signals.write(NavSignal::Unregister(NavType::Item));
     });
+
+    context.life_cycle.change(|context| {
+        for msg in context.messenger.messages {
+            if let Some(AccessibilityAction::Focus) = msg.as_any().downcast_ref() {
+                context
+                    .signals
+                    .write(NavSignal::Select(context.id.to_owned().into()));
+            }
+        }
+    });
 }
 
 #[post_hooks(use_nav_item)]
@@ -660,6 +711,46 @@ pub fn use_nav_locking(context: &mut WidgetContext) {
     });
 }
 
+pub fn use_nav_focus_scope(context: &mut WidgetContext) {
+    context.life_cycle.mount(|context| {
+        if context.props.has::<NavFocusScopeActive>() {
+            context
+                .signals
+                .write(NavSignal::Register(NavType::FocusScope));
+            let _ = context.state.write_with(NavFocusScopeActive);
+        }
+    });
+
+    context.life_cycle.unmount(|context| {
+        if context.state.has::<NavFocusScopeActive>() {
+            context
+                .signals
+                .write(NavSignal::Unregister(NavType::FocusScope));
+        }
+    });
+
+    context.life_cycle.change(|context| {
+        if context.props.has::<NavFocusScopeActive>() {
+            if !context.state.has::<NavFocusScopeActive>() {
+                context
+                    .signals
+                    .write(NavSignal::Register(NavType::FocusScope));
+                let _ = context.state.write_with(NavFocusScopeActive);
+            }
+        } else if context.state.has::<NavFocusScopeActive>() {
+            context
+                .signals
+                .write(NavSignal::Unregister(NavType::FocusScope));
+            let _ = context.state.write_without::<NavFocusScopeActive>();
+        }
+    });
+}
+
+#[post_hooks(use_nav_focus_scope)]
+pub fn use_nav_focus_scope_active(context: &mut WidgetContext) {
+    context.props.write(NavFocusScopeActive);
+}
+
 pub fn use_nav_text_input(context: &mut WidgetContext) {
     context.life_cycle.mount(|context| {
         context
@@ -716,6 +807,20 @@ pub fn navigation_barrier(mut context: WidgetContext) -> WidgetNode {
     .into()
 }
 
+#[pre_hooks(use_nav_container_active, use_nav_focus_scope_active)]
+pub fn focus_scope(mut context: WidgetContext) -> WidgetNode {
+    let WidgetContext {
+        id, named_slots, ..
+    } = context;
+    unpack_named_slots!(named_slots => content);
+
+    AreaBoxNode {
+        id: id.to_owned(),
+        slot: Box::new(content),
+    }
+    .into()
+}
+
 #[pre_hooks(use_nav_tracking)]
 pub fn tracking(mut context: WidgetContext) -> WidgetNode {
     let WidgetContext {
@@ -19,6 +19,7 @@ use crate::{
     },
 };
 use intuicio_data::managed::ManagedLazy;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
@@ -114,18 +115,22 @@ impl<T: OptionsViewProxy + 'static> From<ManagedLazy<T>> for OptionsInput {
     }
 }
 
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub enum OptionsViewMode {
     Selected,
     #[default]
     Option,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct OptionsViewProps {
     #[serde(default)]
     #[serde(skip)]
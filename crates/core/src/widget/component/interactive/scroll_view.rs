@@ -1,5 +1,6 @@
 use crate::{
-    MessageData, PropsData,
+    MessageData, PropsData, Scalar,
+    animator::{AnimatedValue, Animation},
     messenger::MessageData,
     pre_hooks,
     widget::{
@@ -9,25 +10,36 @@ use crate::{
         utils::Vec2,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+const KINETICS_ANIMATION: &str = "scroll-view-kinetics";
+const TIME_VALUE: &str = "time";
+const GRAB_IDLE_TIME: Scalar = 0.1;
+
 fn is_zero(v: &Vec2) -> bool {
     v.x.abs() < 1.0e-6 && v.y.abs() < 1.0e-6
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ScrollViewState {
     #[serde(default)]
     pub value: Vec2,
     #[serde(default)]
     pub size_factor: Vec2,
+    /// Scroll value change per second, used only by scroll views with [`ScrollViewKinetics`].
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub velocity: Vec2,
 }
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ScrollViewRange {
     #[serde(default)]
     #[serde(skip_serializing_if = "is_zero")]
@@ -46,9 +58,245 @@ impl Default for ScrollViewRange {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum ScrollViewSnap {
+    #[default]
+    None,
+    /// Snaps to whole pages of view size.
+    Page,
+    /// Snaps every given fraction of view size, e.g. item height divided by view height.
+    Step(Vec2),
+}
+
+/// Enables momentum scrolling of content grabbed with [`ScrollViewGrabMessage`].
+///
+/// Distances and velocities are measured in view sizes, so the same settings feel alike
+/// regardless of content length.
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct ScrollViewKinetics {
+    /// Rate of velocity exponential decay per second.
+    #[serde(default = "ScrollViewKinetics::default_friction")]
+    pub friction: Scalar,
+    /// How far content can be pulled past [`ScrollViewRange`] bounds. Zero disables overscroll.
+    #[serde(default)]
+    pub overscroll: Scalar,
+    /// Rate at which content settles back into range or onto snap point.
+    #[serde(default = "ScrollViewKinetics::default_spring")]
+    pub spring: Scalar,
+    /// Speed below which content stops moving and settles.
+    #[serde(default = "ScrollViewKinetics::default_min_velocity")]
+    pub min_velocity: Scalar,
+    #[serde(default)]
+    pub snap: ScrollViewSnap,
+}
+
+impl Default for ScrollViewKinetics {
+    fn default() -> Self {
+        Self {
+            friction: Self::default_friction(),
+            overscroll: 0.0,
+            spring: Self::default_spring(),
+            min_velocity: Self::default_min_velocity(),
+            snap: Default::default(),
+        }
+    }
+}
+
+impl ScrollViewKinetics {
+    fn default_friction() -> Scalar {
+        4.0
+    }
+
+    fn default_spring() -> Scalar {
+        12.0
+    }
+
+    fn default_min_velocity() -> Scalar {
+        0.05
+    }
+
+    /// Applies relative change made while content is grabbed, resisting more the further content
+    /// gets pulled past range bounds.
+    pub fn drag(&self, state: &mut ScrollViewState, delta: Vec2, range: Option<&ScrollViewRange>) {
+        let axis = |value: Scalar, delta: Scalar, factor: Scalar, from: Scalar, to: Scalar| {
+            let limit = view_to_value(self.overscroll, factor);
+            let outside = if value < from && delta < 0.0 {
+                from - value
+            } else if value > to && delta > 0.0 {
+                value - to
+            } else {
+                0.0
+            };
+            let resistance = if limit > 0.0 {
+                (1.0 - outside / limit).max(0.0)
+            } else {
+                1.0
+            };
+            (value + delta * resistance).clamp(from - limit, to + limit)
+        };
+        let (from, to) = range_bounds(range);
+        state.value.x = axis(state.value.x, delta.x, state.size_factor.x, from.x, to.x);
+        state.value.y = axis(state.value.y, delta.y, state.size_factor.y, from.y, to.y);
+    }
+
+    /// Advances motion by `delta_time` seconds. `moved` is change applied by grabbing input since
+    /// last update. Returns `true` while scroll view keeps moving.
+    pub fn update(
+        &self,
+        state: &mut ScrollViewState,
+        motion: &mut ScrollViewMotion,
+        moved: Vec2,
+        delta_time: Scalar,
+        range: Option<&ScrollViewRange>,
+    ) -> bool {
+        if !motion.active {
+            return false;
+        }
+        if delta_time <= 0.0 {
+            return true;
+        }
+        if motion.grabbed {
+            if is_zero(&moved) {
+                // Input arrives in bursts, so only holding still for a while cancels the fling.
+                motion.idle += delta_time;
+                if motion.idle > GRAB_IDLE_TIME {
+                    state.velocity = Default::default();
+                }
+            } else {
+                // Smoothed, so single uneven frame does not decide fling velocity.
+                motion.idle = 0.0;
+                state.velocity.x = (state.velocity.x + moved.x / delta_time) * 0.5;
+                state.velocity.y = (state.velocity.y + moved.y / delta_time) * 0.5;
+            }
+            return true;
+        }
+        let (from, to) = range_bounds(range);
+        let friction = (-self.friction * delta_time).exp();
+        let spring = 1.0 - (-self.spring * delta_time).exp();
+        let axis = |value: &mut Scalar,
+                    velocity: &mut Scalar,
+                    factor: Scalar,
+                    from: Scalar,
+                    to: Scalar,
+                    step: Scalar| {
+            if factor <= 1.0 {
+                *value = 0.0;
+                *velocity = 0.0;
+                return false;
+            }
+            *value += *velocity * delta_time;
+            *velocity *= friction;
+            if *value < from || *value > to {
+                if self.overscroll > 0.0 {
+                    *velocity *= 1.0 - spring;
+                } else {
+                    *value = value.clamp(from, to);
+                    *velocity = 0.0;
+                }
+            }
+            if velocity.abs() >= view_to_value(self.min_velocity, factor) {
+                return true;
+            }
+            *velocity = 0.0;
+            let target = snap_target(*value, from, to, view_to_value(step, factor));
+            *value += (target - *value) * spring;
+            if (target - *value).abs() < 1.0e-4 {
+                *value = target;
+            }
+            *value != target
+        };
+        let step = match self.snap {
+            ScrollViewSnap::None => Vec2::default(),
+            ScrollViewSnap::Page => Vec2 { x: 1.0, y: 1.0 },
+            ScrollViewSnap::Step(step) => step,
+        };
+        let moving_x = axis(
+            &mut state.value.x,
+            &mut state.velocity.x,
+            state.size_factor.x,
+            from.x,
+            to.x,
+            step.x,
+        );
+        let moving_y = axis(
+            &mut state.value.y,
+            &mut state.velocity.y,
+            state.size_factor.y,
+            from.y,
+            to.y,
+            step.y,
+        );
+        motion.active = moving_x || moving_y;
+        motion.active
+    }
+}
+
+/// Converts distance in view sizes into scroll value distance.
+fn view_to_value(value: Scalar, size_factor: Scalar) -> Scalar {
+    if size_factor > 1.0 {
+        value / (size_factor - 1.0)
+    } else {
+        0.0
+    }
+}
+
+fn snap_target(value: Scalar, from: Scalar, to: Scalar, step: Scalar) -> Scalar {
+    if step <= 0.0 {
+        return value.clamp(from, to);
+    }
+    let origin = if from.is_finite() { from } else { 0.0 };
+    (((value - origin) / step).round() * step + origin).clamp(from, to)
+}
+
+fn range_bounds(range: Option<&ScrollViewRange>) -> (Vec2, Vec2) {
+    match range {
+        Some(range) => (range.from, range.to),
+        None => (
+            Vec2 {
+                x: Scalar::NEG_INFINITY,
+                y: Scalar::NEG_INFINITY,
+            },
+            Vec2 {
+                x: Scalar::INFINITY,
+                y: Scalar::INFINITY,
+            },
+        ),
+    }
+}
+
+#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[props_data(crate::props::PropsData)]
+#[prefab(crate::Prefab)]
+#[prefab_schema]
+pub struct ScrollViewMotion {
+    #[serde(default)]
+    pub grabbed: bool,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub time: Scalar,
+    /// Time since grabbed content last moved.
+    #[serde(default)]
+    pub idle: Scalar,
+}
+
+/// Scrolling driven directly by user, like dragging content with finger.
+#[derive(MessageData, Debug, Copy, Clone, PartialEq)]
+#[message_data(crate::messenger::MessageData)]
+pub enum ScrollViewGrabMessage {
+    Grab,
+    /// Moves grabbed content by distance in view sizes.
+    Move(Vec2),
+    Release,
+}
+
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ScrollViewNotifyProps(
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -99,7 +347,11 @@ pub fn use_scroll_view(context: &mut WidgetContext) {
     context.life_cycle.change(|context| {
         let mut dirty = false;
         let mut data = context.state.read_cloned_or_default::<ScrollViewState>();
-        let range = context.props.read::<ScrollViewRange>();
+        let range = context.props.read::<ScrollViewRange>().ok();
+        let kinetics = context.props.read::<ScrollViewKinetics>().ok();
+        let mut motion = context.state.read_cloned_or_default::<ScrollViewMotion>();
+        let mut motion_dirty = false;
+        let mut moved = Vec2::default();
         for msg in context.messenger.messages {
             if let Some(NavSignal::Jump(NavJump::Scroll(NavScroll::Change(
                 value,
@@ -107,11 +359,31 @@ pub fn use_scroll_view(context: &mut WidgetContext) {
                 relative,
             )))) = msg.as_any().downcast_ref()
             {
-                if *relative {
-                    data.value.x += value.x;
-                    data.value.y += value.y;
-                } else {
-                    data.value = *value;
+                data.size_factor = *factor;
+                match kinetics {
+                    Some(kinetics) if motion.grabbed && *relative => {
+                        kinetics.drag(&mut data, *value, range);
+                        moved.x += value.x;
+                        moved.y += value.y;
+                    }
+                    _ => {
+                        if *relative {
+                            data.value.x += value.x;
+                            data.value.y += value.y;
+                        } else {
+                            data.value = *value;
+                        }
+                        if let Some(range) = range {
+                            data.value.x = data.value.x.max(range.from.x).min(range.to.x);
+                            data.value.y = data.value.y.max(range.from.y).min(range.to.y);
+                        }
+                        // Direct scrolling takes over any momentum.
+                        if kinetics.is_some() && !motion.grabbed && motion.active {
+                            data.velocity = Default::default();
+                            motion.active = false;
+                            motion_dirty = true;
+                        }
+                    }
                 }
                 if factor.x <= 1.0 {
                     data.value.x = 0.0;
@@ -119,14 +391,64 @@ pub fn use_scroll_view(context: &mut WidgetContext) {
                 if factor.y <= 1.0 {
                     data.value.y = 0.0;
                 }
-                if let Ok(range) = &range {
-                    data.value.x = data.value.x.max(range.from.x).min(range.to.x);
-                    data.value.y = data.value.y.max(range.from.y).min(range.to.y);
-                }
-                data.size_factor = *factor;
                 dirty = true;
+            } else if let Some(msg) = msg.as_any().downcast_ref::<ScrollViewGrabMessage>()
+                && let Some(kinetics) = kinetics
+            {
+                if let ScrollViewGrabMessage::Move(delta) = msg {
+                    if motion.grabbed {
+                        let delta = Vec2 {
+                            x: view_to_value(delta.x, data.size_factor.x),
+                            y: view_to_value(delta.y, data.size_factor.y),
+                        };
+                        kinetics.drag(&mut data, delta, range);
+                        moved.x += delta.x;
+                        moved.y += delta.y;
+                        dirty = true;
+                    }
+                    continue;
+                }
+                motion.grabbed = *msg == ScrollViewGrabMessage::Grab;
+                if motion.grabbed {
+                    data.velocity = Default::default();
+                    motion.idle = 0.0;
+                    dirty = true;
+                }
+                if !motion.active {
+                    motion.active = true;
+                    motion.time = 0.0;
+                    let _ = context.animator.change(
+                        KINETICS_ANIMATION,
+                        Some(Animation::Value(AnimatedValue {
+                            name: TIME_VALUE.to_owned(),
+                            duration: Scalar::MAX,
+                        })),
+                    );
+                }
+                motion_dirty = true;
             }
         }
+        if let Some(kinetics) = kinetics
+            && motion.active
+        {
+            let time = context
+                .animator
+                .value_progress(KINETICS_ANIMATION, TIME_VALUE)
+                .map(|progress| progress.time)
+                .unwrap_or(motion.time);
+            let delta_time = (time - motion.time).max(0.0);
+            motion.time = time;
+            if !kinetics.update(&mut data, &mut motion, moved, delta_time, range) {
+                let _ = context.animator.change(KINETICS_ANIMATION, None);
+            }
+            motion_dirty = true;
+            dirty = true;
+        } else if motion_dirty && !motion.active {
+            let _ = context.animator.change(KINETICS_ANIMATION, None);
+        }
+        if motion_dirty {
+            let _ = context.state.write_with(motion);
+        }
         if dirty {
             notify(
                 &context,
@@ -139,3 +461,158 @@ pub fn use_scroll_view(context: &mut WidgetContext) {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interactive::default_interactions_engine::{Interaction, PointerButton},
+        layout::CoordsMapping,
+        make_widget,
+        tester::AppCycleTester,
+        widget::{
+            component::{
+                containers::{
+                    content_box::nav_content_box,
+                    scroll_box::nav_scroll_box,
+                    size_box::{SizeBoxProps, size_box},
+                },
+                interactive::navigation::NavItemActive,
+            },
+            unit::size::SizeBoxSizeValue,
+            utils::Rect,
+        },
+    };
+
+    #[test]
+    fn test_scroll_view_kinetics() {
+        let kinetics = ScrollViewKinetics {
+            overscroll: 0.5,
+            snap: ScrollViewSnap::Page,
+            ..Default::default()
+        };
+        let range = ScrollViewRange::default();
+        let mut state = ScrollViewState {
+            value: Vec2 { x: 0.0, y: 0.1 },
+            size_factor: Vec2 { x: 1.0, y: 3.0 },
+            ..Default::default()
+        };
+        let mut motion = ScrollViewMotion {
+            grabbed: true,
+            active: true,
+            ..Default::default()
+        };
+        let delta = Vec2 { x: 0.0, y: 0.02 };
+        for _ in 0..5 {
+            kinetics.drag(&mut state, delta, Some(&range));
+            assert!(kinetics.update(&mut state, &mut motion, delta, 0.02, Some(&range)));
+        }
+        assert!(state.velocity.y > 0.0);
+        let released = state.value.y;
+        motion.grabbed = false;
+        let mut frames = 0;
+        while kinetics.update(&mut state, &mut motion, Vec2::default(), 0.02, Some(&range)) {
+            frames += 1;
+            assert!(frames < 1000);
+        }
+        assert!(!motion.active);
+        assert_eq!(state.value.x, 0.0);
+        // Momentum carries content past the middle page, where it snaps.
+        assert!(released < 0.25);
+        assert_eq!(state.value.y, 0.5);
+        assert_eq!(state.velocity.y, 0.0);
+
+        motion.grabbed = true;
+        motion.active = true;
+        state.value.y = 1.0;
+        for _ in 0..10 {
+            kinetics.drag(&mut state, delta, Some(&range));
+        }
+        assert!(state.value.y > 1.0 && state.value.y <= 1.25);
+        motion.grabbed = false;
+        state.velocity = Default::default();
+        while kinetics.update(&mut state, &mut motion, Vec2::default(), 0.02, Some(&range)) {}
+        assert_eq!(state.value.y, 1.0);
+    }
+
+    #[test]
+    fn test_scroll_box_drag() {
+        let mut tester = AppCycleTester::new(
+            CoordsMapping::new(Rect {
+                left: 0.0,
+                right: 100.0,
+                top: 0.0,
+                bottom: 100.0,
+            }),
+            (),
+        );
+        tester.application.animations_delta_time = 0.02;
+        tester.application.apply(
+            make_widget!(nav_content_box).key("root").listed_slot(
+                make_widget!(nav_scroll_box)
+                    .with_props(NavItemActive)
+                    .with_props(ScrollViewRange::default())
+                    .with_props(ScrollViewKinetics::default())
+                    .named_slot(
+                        "content",
+                        make_widget!(size_box).with_props(SizeBoxProps {
+                            width: SizeBoxSizeValue::Fill,
+                            height: SizeBoxSizeValue::Exact(1000.0),
+                            ..Default::default()
+                        }),
+                    ),
+            ),
+        );
+        let content_top = |tester: &AppCycleTester<()>| {
+            tester
+                .application
+                .layout_data()
+                .items
+                .iter()
+                .find(|(id, _)| id.type_name() == "nav_scroll_box_content")
+                .map(|(_, item)| item.ui_space.top)
+                .unwrap()
+        };
+        tester.run_frame(());
+        tester.run_frame(());
+        assert_eq!(content_top(&tester), 0.0);
+
+        let mut y = 90.0;
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerMove(Vec2 { x: 50.0, y }));
+        tester
+            .interactions_engine
+            .interact(Interaction::PointerDown(
+                PointerButton::Trigger,
+                Vec2 { x: 50.0, y },
+            ));
+        for _ in 0..5 {
+            tester.run_frame(());
+        }
+        for _ in 0..8 {
+            y -= 10.0;
+            tester
+                .interactions_engine
+                .interact(Interaction::PointerMove(Vec2 { x: 50.0, y }));
+            tester.run_frame(());
+        }
+        tester.interactions_engine.interact(Interaction::PointerUp(
+            PointerButton::Trigger,
+            Vec2 { x: 50.0, y },
+        ));
+        for _ in 0..5 {
+            tester.run_frame(());
+        }
+        let released = content_top(&tester);
+        assert!(released < 0.0);
+        for _ in 0..100 {
+            tester.run_frame(());
+        }
+        // Content keeps moving after release, until friction stops it.
+        let settled = content_top(&tester);
+        assert!(settled < released - 10.0);
+        tester.run_frame(());
+        assert_eq!(content_top(&tester), settled);
+    }
+}
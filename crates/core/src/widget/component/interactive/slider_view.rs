@@ -1,5 +1,9 @@
 use crate::{
-    PropsData, Scalar, pre_hooks, unpack_named_slots,
+    PropsData, Scalar,
+    accessibility::{AccessibilityAction, AccessibilityProps, AccessibilityRange},
+    pre_hooks,
+    props::Props,
+    unpack_named_slots,
     view_model::ViewModelValue,
     widget::{
         component::interactive::{
@@ -15,6 +19,7 @@ use crate::{
     },
 };
 use intuicio_data::managed::ManagedLazy;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
@@ -125,7 +130,7 @@ impl<T: SliderViewProxy + 'static> From<ManagedLazy<T>> for SliderInput {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum SliderViewDirection {
     #[default]
     LeftToRight,
@@ -134,9 +139,10 @@ pub enum SliderViewDirection {
     BottomToTop,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct SliderViewProps {
     #[serde(default)]
     #[serde(skip)]
@@ -149,6 +155,11 @@ pub struct SliderViewProps {
     pub to: Scalar,
     #[serde(default)]
     pub direction: SliderViewDirection,
+    /// Amount applied by increment and decrement accessibility actions. Zero means step of
+    /// [`AccessibilityProps`] range, or 10% of range if that one is zero too.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub step: Scalar,
 }
 
 impl SliderViewProps {
@@ -172,6 +183,29 @@ impl SliderViewProps {
     pub fn set_percentage(&mut self, value: Scalar) {
         self.set_value(value * (self.to - self.from) + self.from)
     }
+
+    pub fn accessibility_range(&self) -> AccessibilityRange {
+        AccessibilityRange {
+            value: self.get_value(),
+            min: self.from,
+            max: self.to,
+            step: self.step,
+        }
+    }
+}
+
+/// Range of slider, with step of its accessibility properties if slider does not have one.
+fn slider_range(props: &Props, slider: &SliderViewProps) -> AccessibilityRange {
+    let mut range = slider.accessibility_range();
+    if is_zero(&range.step) {
+        range.step = props
+            .read::<AccessibilityProps>()
+            .ok()
+            .and_then(|accessibility| accessibility.range)
+            .map(|range| range.step)
+            .unwrap_or_default();
+    }
+    range
 }
 
 #[pre_hooks(use_button, use_nav_tracking_self)]
@@ -184,17 +218,30 @@ pub fn use_slider_view(context: &mut WidgetContext) {
         .write(NavTrackingNotifyProps(context.id.to_owned().into()));
 
     context.life_cycle.unmount(|context| {
+        context.signals.write(NavSignal::ReleasePointer(().into()));
         context.signals.write(NavSignal::Unlock);
     });
 
     context.life_cycle.change(|context| {
         for msg in context.messenger.messages {
-            if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>() {
+            if let Some(msg) = msg.as_any().downcast_ref::<AccessibilityAction>() {
+                let mut props = context.props.read_cloned_or_default::<SliderViewProps>();
+                let step = slider_range(context.props, &props).step_or_default();
+                let value = match msg {
+                    AccessibilityAction::Increment => props.get_value() + step,
+                    AccessibilityAction::Decrement => props.get_value() - step,
+                    AccessibilityAction::SetValue(value) => *value,
+                    _ => continue,
+                };
+                props.set_value(value.clamp(props.from.min(props.to), props.from.max(props.to)));
+            } else if let Some(msg) = msg.as_any().downcast_ref::<ButtonNotifyMessage>() {
                 if msg.trigger_start() {
                     context.signals.write(NavSignal::Lock);
+                    context.signals.write(NavSignal::CapturePointer(().into()));
                 }
                 if msg.trigger_stop() {
                     context.signals.write(NavSignal::Unlock);
+                    context.signals.write(NavSignal::ReleasePointer(().into()));
                 }
             } else if let Some(msg) = msg.as_any().downcast_ref::<NavTrackingNotifyMessage>() {
                 let button = context.state.read_cloned_or_default::<ButtonProps>();
@@ -228,9 +275,18 @@ pub fn slider_view(mut context: WidgetContext) -> WidgetNode {
     } = context;
     unpack_named_slots!(named_slots => content);
 
+    let button_props = state.read_cloned_or_default::<ButtonProps>();
+    let slider_props = props.read_cloned_or_default::<SliderViewProps>();
+    if let Ok(mut accessibility) = props.read_cloned::<AccessibilityProps>() {
+        accessibility.range = Some(slider_range(props, &slider_props));
+        accessibility.states.selected = button_props.selected;
+        accessibility.states.pressed = button_props.trigger;
+        props.write(accessibility);
+    }
+
     if let Some(p) = content.props_mut() {
-        p.write(state.read_cloned_or_default::<ButtonProps>());
-        p.write(props.read_cloned_or_default::<SliderViewProps>());
+        p.write(button_props);
+        p.write(slider_props);
     }
 
     AreaBoxNode {
@@ -16,6 +16,7 @@ use crate::{
     },
 };
 use intuicio_data::type_hash::TypeHash;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, convert::TryFrom};
 
@@ -23,9 +24,10 @@ fn is_false(v: &bool) -> bool {
     !*v
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct MessageForwardProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -106,9 +108,10 @@ pub fn use_resize_listener(context: &mut WidgetContext) {
     });
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct RelativeLayoutProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "WidgetIdOrRef::is_none")]
@@ -146,9 +149,10 @@ pub fn use_relative_layout_listener(context: &mut WidgetContext) {
     });
 }
 
-#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct WidgetAlpha(pub Scalar);
 
 impl Default for WidgetAlpha {
@@ -378,7 +382,7 @@ impl TryFrom<WidgetNode> for WidgetComponent {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct WidgetComponentPrefab {
     #[serde(default)]
     pub type_name: String,
@@ -386,9 +390,11 @@ pub(crate) struct WidgetComponentPrefab {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<crate::props::PropsGroupPrefab>")]
     pub shared_props: Option<PrefabValue>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -6,11 +6,13 @@ use crate::{
         unit::size::{SizeBoxNode, SizeBoxSizeValue},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct SpaceBoxProps {
     #[serde(default)]
     pub width: Scalar,
@@ -8,14 +8,16 @@ use crate::{
             TextBoxDirection, TextBoxFont, TextBoxHorizontalAlign, TextBoxNode, TextBoxSizeValue,
             TextBoxVerticalAlign,
         },
-        utils::{Color, Transform},
+        utils::{Color, Gradient, Shadow, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct TextBoxProps {
     #[serde(default)]
     pub text: String,
@@ -33,6 +35,13 @@ pub struct TextBoxProps {
     pub font: TextBoxFont,
     #[serde(default)]
     pub color: Color,
+    /// When set, it replaces color of the text.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<Gradient>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
     #[serde(default)]
     pub transform: Transform,
 }
@@ -54,11 +63,19 @@ pub fn text_box(context: WidgetContext) -> WidgetNode {
         direction,
         font,
         mut color,
+        mut gradient,
+        mut shadows,
         transform,
     } = props.read_cloned_or_default();
 
     let alpha = shared_props.read_cloned_or_default::<WidgetAlpha>().0;
     color.a *= alpha;
+    if let Some(gradient) = &mut gradient {
+        gradient.multiply_alpha(alpha);
+    }
+    for shadow in &mut shadows {
+        shadow.color.a *= alpha;
+    }
 
     TextBoxNode {
         id: id.to_owned(),
@@ -71,6 +88,8 @@ pub fn text_box(context: WidgetContext) -> WidgetNode {
         direction,
         font,
         color,
+        gradient,
+        shadows,
         transform,
     }
     .into()
@@ -15,6 +15,7 @@ use crate::{
         node::WidgetNode,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -26,7 +27,7 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WidgetIdDef(pub String);
 
 impl From<WidgetId> for WidgetIdDef {
@@ -103,7 +104,8 @@ impl<'a> WidgetIdMetaParams<'a> {
     }
 }
 
-#[derive(PropsData, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[prefab_schema]
 #[serde(try_from = "WidgetIdDef")]
 #[serde(into = "WidgetIdDef")]
 pub struct WidgetId {
@@ -519,7 +521,7 @@ impl<'a> FromIterator<&'a WidgetId> for WidgetIdCommon {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WidgetRefDef(pub Option<WidgetId>);
 
 impl From<WidgetRef> for WidgetRefDef {
@@ -531,7 +533,8 @@ impl From<WidgetRef> for WidgetRefDef {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[prefab_schema]
 #[serde(from = "WidgetRefDef")]
 #[serde(into = "WidgetRefDef")]
 pub struct WidgetRef(#[serde(skip)] Arc<RwLock<Option<WidgetId>>>);
@@ -590,7 +593,8 @@ impl std::fmt::Display for WidgetRef {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[prefab_schema]
 pub enum WidgetIdOrRef {
     #[default]
     None,
@@ -780,8 +784,16 @@ pub fn setup(app: &mut Application) {
     app.register_props::<component::containers::vertical_box::VerticalBoxProps>("VerticalBoxProps");
     app.register_props::<component::containers::wrap_box::WrapBoxProps>("WrapBoxProps");
     app.register_props::<component::image_box::ImageBoxProps>("ImageBoxProps");
+    app.register_props::<component::image_box::ImageBoxFlipbookState>("ImageBoxFlipbookState");
+    app.register_props::<crate::accessibility::AccessibilityProps>("AccessibilityProps");
     app.register_props::<component::interactive::button::ButtonProps>("ButtonProps");
     app.register_props::<component::interactive::button::ButtonNotifyProps>("ButtonNotifyProps");
+    app.register_props::<component::interactive::button::ButtonDetectionProps>(
+        "ButtonDetectionProps",
+    );
+    app.register_props::<component::interactive::button::ButtonDetectionState>(
+        "ButtonDetectionState",
+    );
     app.register_props::<component::interactive::input_field::TextInputMode>("TextInputMode");
     app.register_props::<component::interactive::input_field::TextInputProps>("TextInputProps");
     app.register_props::<component::interactive::input_field::TextInputState>("TextInputState");
@@ -803,10 +815,17 @@ pub fn setup(app: &mut Application) {
     app.register_props::<component::interactive::navigation::NavContainerActive>(
         "NavContainerActive",
     );
+    app.register_props::<component::interactive::navigation::NavFocusScopeActive>(
+        "NavFocusScopeActive",
+    );
     app.register_props::<component::interactive::navigation::NavJumpLooped>("NavJumpLooped");
     app.register_props::<component::interactive::navigation::NavJumpMapProps>("NavJumpMapProps");
     app.register_props::<component::interactive::scroll_view::ScrollViewState>("ScrollViewState");
     app.register_props::<component::interactive::scroll_view::ScrollViewRange>("ScrollViewRange");
+    app.register_props::<component::interactive::scroll_view::ScrollViewKinetics>(
+        "ScrollViewKinetics",
+    );
+    app.register_props::<component::interactive::scroll_view::ScrollViewMotion>("ScrollViewMotion");
     app.register_props::<component::interactive::scroll_view::ScrollViewNotifyProps>(
         "ScrollViewNotifyProps",
     );
@@ -959,6 +978,10 @@ pub fn setup(app: &mut Application) {
         "navigation_barrier",
         FnWidget::pointer(component::interactive::navigation::navigation_barrier),
     );
+    app.register_component(
+        "focus_scope",
+        FnWidget::pointer(component::interactive::navigation::focus_scope),
+    );
     app.register_component(
         "tracking",
         FnWidget::pointer(component::interactive::navigation::tracking),
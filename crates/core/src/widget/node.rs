@@ -1,11 +1,13 @@
 use crate::{
     Prefab,
     props::Props,
+    template::WidgetTemplatePrefab,
     widget::{
         component::{WidgetComponent, WidgetComponentPrefab},
-        unit::{WidgetUnitNode, WidgetUnitNodePrefab},
+        unit::{WidgetUnitNode, WidgetUnitNodePrefab, portal::PortalBoxSlotNodePrefab},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::large_enum_variant)]
@@ -163,13 +165,51 @@ impl<const N: usize> From<[WidgetNode; N]> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) enum WidgetNodePrefab {
     #[default]
     None,
     Component(WidgetComponentPrefab),
     Unit(WidgetUnitNodePrefab),
     Tuple(Vec<WidgetNodePrefab>),
+    Template(WidgetTemplatePrefab),
 }
 
 impl Prefab for WidgetNodePrefab {}
+
+impl WidgetNodePrefab {
+    /// Direct child nodes, not including slots of template instances.
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut WidgetNodePrefab> {
+        match self {
+            Self::None | Self::Template(_) => vec![],
+            Self::Component(component) => component
+                .listed_slots
+                .iter_mut()
+                .chain(component.named_slots.values_mut())
+                .collect(),
+            Self::Unit(unit) => match unit {
+                WidgetUnitNodePrefab::None
+                | WidgetUnitNodePrefab::ImageBox(_)
+                | WidgetUnitNodePrefab::TextBox(_) => vec![],
+                WidgetUnitNodePrefab::AreaBox(unit) => vec![&mut unit.slot],
+                WidgetUnitNodePrefab::PortalBox(unit) => vec![match &mut *unit.slot {
+                    PortalBoxSlotNodePrefab::Slot(slot) => slot,
+                    PortalBoxSlotNodePrefab::ContentItem(item) => &mut item.slot,
+                    PortalBoxSlotNodePrefab::FlexItem(item) => &mut item.slot,
+                    PortalBoxSlotNodePrefab::GridItem(item) => &mut item.slot,
+                }],
+                WidgetUnitNodePrefab::ContentBox(unit) => {
+                    unit.items.iter_mut().map(|item| &mut item.slot).collect()
+                }
+                WidgetUnitNodePrefab::FlexBox(unit) => {
+                    unit.items.iter_mut().map(|item| &mut item.slot).collect()
+                }
+                WidgetUnitNodePrefab::GridBox(unit) => {
+                    unit.items.iter_mut().map(|item| &mut item.slot).collect()
+                }
+                WidgetUnitNodePrefab::SizeBox(unit) => vec![&mut unit.slot],
+            },
+            Self::Tuple(nodes) => nodes.iter_mut().collect(),
+        }
+    }
+}
@@ -3,10 +3,11 @@ use crate::widget::{
     node::{WidgetNode, WidgetNodePrefab},
     unit::{WidgetUnit, WidgetUnitData},
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AreaBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -48,7 +49,7 @@ impl From<AreaBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct AreaBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
@@ -4,14 +4,15 @@ use crate::{
     widget::{
         WidgetId,
         node::{WidgetNode, WidgetNodePrefab},
-        unit::{WidgetUnit, WidgetUnitData},
+        unit::{UnitLayer, WidgetUnit, WidgetUnitData, image::ImageBoxShapeCorners},
         utils::{Rect, Transform, Vec2},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBoxItemPreserveInBounds {
     #[serde(default)]
     pub width: bool,
@@ -28,7 +29,7 @@ impl From<bool> for ContentBoxItemPreserveInBounds {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBoxItemCutInBounds {
     #[serde(default)]
     pub left: bool,
@@ -51,7 +52,7 @@ impl From<bool> for ContentBoxItemCutInBounds {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBoxItemKeepInBounds {
     #[serde(default)]
     pub preserve: ContentBoxItemPreserveInBounds,
@@ -71,9 +72,10 @@ impl From<bool> for ContentBoxItemKeepInBounds {
 /// Allows customizing how an item in a [`content_box`] is laid out
 ///
 /// [`content_box`]: crate::widget::component::containers::content_box::content_box
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct ContentBoxItemLayout {
     #[serde(default = "ContentBoxItemLayout::default_anchors")]
     pub anchors: Rect,
@@ -118,7 +120,7 @@ impl Default for ContentBoxItemLayout {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBoxItem {
     #[serde(default)]
     pub slot: WidgetUnit,
@@ -144,7 +146,7 @@ pub struct ContentBoxItemNode {
     pub layout: ContentBoxItemLayout,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBoxContentReposition {
     #[serde(default)]
     pub offset: Vec2,
@@ -167,7 +169,46 @@ impl ContentBoxContentReposition {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+/// Shape of the area that [`ContentBox`] contents are clipped to.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum ContentBoxClipShape {
+    /// Box bounds, following box transform.
+    #[default]
+    Rectangle,
+    RoundedRectangle {
+        #[serde(default)]
+        corner_radius: ImageBoxShapeCorners,
+    },
+    /// Ellipse inscribed in box bounds, which makes a circle for square boxes.
+    Circle,
+    /// Quad with corners given in box-relative factors (`0..1` covers box bounds), listed
+    /// clockwise starting from top-left.
+    Quad { points: [Vec2; 4] },
+    /// Alpha channel of an image stretched over box bounds.
+    Mask { id: String },
+}
+
+impl ContentBoxClipShape {
+    pub fn is_rectangle(&self) -> bool {
+        matches!(self, Self::Rectangle)
+    }
+
+    pub fn rounded_rectangle(corner_radius: impl Into<ImageBoxShapeCorners>) -> Self {
+        Self::RoundedRectangle {
+            corner_radius: corner_radius.into(),
+        }
+    }
+
+    pub fn quad(points: [Vec2; 4]) -> Self {
+        Self::Quad { points }
+    }
+
+    pub fn mask(id: impl ToString) -> Self {
+        Self::Mask { id: id.to_string() }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ContentBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -177,8 +218,14 @@ pub struct ContentBox {
     #[serde(default)]
     pub clipping: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "ContentBoxClipShape::is_rectangle")]
+    pub clip_shape: ContentBoxClipShape,
+    #[serde(default)]
     pub content_reposition: ContentBoxContentReposition,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -200,7 +247,9 @@ impl TryFrom<ContentBoxNode> for ContentBox {
             id,
             items,
             clipping,
+            clip_shape,
             content_reposition,
+            layer,
             transform,
             ..
         } = node;
@@ -212,7 +261,9 @@ impl TryFrom<ContentBoxNode> for ContentBox {
             id,
             items,
             clipping,
+            clip_shape,
             content_reposition,
+            layer,
             transform,
         })
     }
@@ -224,7 +275,9 @@ pub struct ContentBoxNode {
     pub props: Props,
     pub items: Vec<ContentBoxItemNode>,
     pub clipping: bool,
+    pub clip_shape: ContentBoxClipShape,
     pub content_reposition: ContentBoxContentReposition,
+    pub layer: UnitLayer,
     pub transform: Transform,
 }
 
@@ -244,11 +297,12 @@ impl From<ContentBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct ContentBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -256,12 +310,18 @@ pub(crate) struct ContentBoxNodePrefab {
     #[serde(default)]
     pub clipping: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "ContentBoxClipShape::is_rectangle")]
+    pub clip_shape: ContentBoxClipShape,
+    #[serde(default)]
     pub content_reposition: ContentBoxContentReposition,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct ContentBoxItemNodePrefab {
     #[serde(default)]
     pub slot: WidgetNodePrefab,
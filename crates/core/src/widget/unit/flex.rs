@@ -4,16 +4,18 @@ use crate::{
     widget::{
         WidgetId,
         node::{WidgetNode, WidgetNodePrefab},
-        unit::{WidgetUnit, WidgetUnitData},
+        unit::{UnitLayer, WidgetUnit, WidgetUnitData},
         utils::{Rect, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct FlexBoxItemLayout {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,7 +76,7 @@ impl Default for FlexBoxItemLayout {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FlexBoxItem {
     #[serde(default)]
     pub slot: WidgetUnit,
@@ -100,7 +102,7 @@ pub struct FlexBoxItemNode {
     pub layout: FlexBoxItemLayout,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum FlexBoxDirection {
     #[default]
     HorizontalLeftToRight,
@@ -127,7 +129,7 @@ impl FlexBoxDirection {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FlexBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -141,6 +143,9 @@ pub struct FlexBox {
     #[serde(default)]
     pub wrap: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -164,6 +169,7 @@ impl TryFrom<FlexBoxNode> for FlexBox {
             direction,
             separation,
             wrap,
+            layer,
             transform,
             ..
         } = node;
@@ -177,6 +183,7 @@ impl TryFrom<FlexBoxNode> for FlexBox {
             direction,
             separation,
             wrap,
+            layer,
             transform,
         })
     }
@@ -190,6 +197,7 @@ pub struct FlexBoxNode {
     pub direction: FlexBoxDirection,
     pub separation: Scalar,
     pub wrap: bool,
+    pub layer: UnitLayer,
     pub transform: Transform,
 }
 
@@ -209,11 +217,12 @@ impl From<FlexBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct FlexBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -225,10 +234,13 @@ pub(crate) struct FlexBoxNodePrefab {
     #[serde(default)]
     pub wrap: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct FlexBoxItemNodePrefab {
     #[serde(default)]
     pub slot: WidgetNodePrefab,
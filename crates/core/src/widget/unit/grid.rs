@@ -4,16 +4,18 @@ use crate::{
     widget::{
         WidgetId,
         node::{WidgetNode, WidgetNodePrefab},
-        unit::{WidgetUnit, WidgetUnitData},
+        unit::{UnitLayer, WidgetUnit, WidgetUnitData},
         utils::{IntRect, Rect, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct GridBoxItemLayout {
     #[serde(default)]
     pub space_occupancy: IntRect,
@@ -25,7 +27,7 @@ pub struct GridBoxItemLayout {
     pub vertical_align: Scalar,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GridBoxItem {
     #[serde(default)]
     pub slot: WidgetUnit,
@@ -51,7 +53,7 @@ pub struct GridBoxItemNode {
     pub layout: GridBoxItemLayout,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GridBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -63,6 +65,9 @@ pub struct GridBox {
     #[serde(default)]
     pub rows: usize,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -85,6 +90,7 @@ impl TryFrom<GridBoxNode> for GridBox {
             items,
             cols,
             rows,
+            layer,
             transform,
             ..
         } = node;
@@ -97,6 +103,7 @@ impl TryFrom<GridBoxNode> for GridBox {
             items,
             cols,
             rows,
+            layer,
             transform,
         })
     }
@@ -109,6 +116,7 @@ pub struct GridBoxNode {
     pub items: Vec<GridBoxItemNode>,
     pub cols: usize,
     pub rows: usize,
+    pub layer: UnitLayer,
     pub transform: Transform,
 }
 
@@ -128,11 +136,12 @@ impl From<GridBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct GridBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -142,10 +151,13 @@ pub(crate) struct GridBoxNodePrefab {
     #[serde(default)]
     pub rows: usize,
     #[serde(default)]
+    #[serde(skip_serializing_if = "UnitLayer::is_passthrough")]
+    pub layer: UnitLayer,
+    #[serde(default)]
     pub transform: Transform,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct GridBoxItemNodePrefab {
     #[serde(default)]
     pub slot: WidgetNodePrefab,
@@ -6,13 +6,18 @@ use crate::{
         WidgetId,
         node::WidgetNode,
         unit::WidgetUnitData,
-        utils::{Color, Rect, Transform, Vec2},
+        utils::{Color, Gradient, Rect, Shadow, Transform, Vec2},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, convert::TryFrom, sync::Arc};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+fn is_zero(v: &Scalar) -> bool {
+    v.abs() < 1.0e-6
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxFrame {
     #[serde(default)]
     pub source: Rect,
@@ -46,14 +51,120 @@ impl From<(Scalar, bool)> for ImageBoxFrame {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImageBoxTile {
+    /// Size of single tile relative to image size.
+    #[serde(default = "ImageBoxTile::default_scale")]
+    pub scale: Vec2,
+    /// Offset of tiles pattern from top-left corner of the box.
+    #[serde(default)]
+    pub offset: Vec2,
+}
+
+impl Default for ImageBoxTile {
+    fn default() -> Self {
+        Self {
+            scale: Self::default_scale(),
+            offset: Default::default(),
+        }
+    }
+}
+
+impl ImageBoxTile {
+    fn default_scale() -> Vec2 {
+        1.0.into()
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ImageBoxFitMode {
+    /// Whole image is visible, leaving empty space along one axis.
+    #[default]
+    Contain,
+    /// Image covers whole box, cutting off its parts along one axis.
+    Cover,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImageBoxFit {
+    #[serde(default)]
+    pub mode: ImageBoxFitMode,
+    /// Tells in percentage where to put fitted image in the box.
+    #[serde(default = "ImageBoxFit::default_alignment")]
+    pub alignment: Vec2,
+    #[serde(default)]
+    pub offset: Vec2,
+}
+
+impl Default for ImageBoxFit {
+    fn default() -> Self {
+        Self {
+            mode: Default::default(),
+            alignment: Self::default_alignment(),
+            offset: Default::default(),
+        }
+    }
+}
+
+impl From<ImageBoxFitMode> for ImageBoxFit {
+    fn from(mode: ImageBoxFitMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+}
+
+impl ImageBoxFit {
+    fn default_alignment() -> Vec2 {
+        0.5.into()
+    }
+
+    /// Returns fitted content rectangle and part of content (in percentage) visible in it.
+    pub fn fit(&self, content_size: Vec2, rect: Rect) -> (Rect, Rect) {
+        let width = rect.width();
+        let height = rect.height();
+        if content_size.x <= 0.0 || content_size.y <= 0.0 || width <= 0.0 || height <= 0.0 {
+            return (rect, Rect::from((1.0, 1.0)));
+        }
+        let scale_x = width / content_size.x;
+        let scale_y = height / content_size.y;
+        let scale = match self.mode {
+            ImageBoxFitMode::Contain => scale_x.min(scale_y),
+            ImageBoxFitMode::Cover => scale_x.max(scale_y),
+        };
+        let w = content_size.x * scale;
+        let h = content_size.y * scale;
+        let left = rect.left + (width - w) * self.alignment.x + self.offset.x;
+        let top = rect.top + (height - h) * self.alignment.y + self.offset.y;
+        let visible = Rect {
+            left: left.max(rect.left),
+            right: (left + w).min(rect.right),
+            top: top.max(rect.top),
+            bottom: (top + h).min(rect.bottom),
+        };
+        let factors = Rect {
+            left: (visible.left - left) / w,
+            right: (visible.right - left) / w,
+            top: (visible.top - top) / h,
+            bottom: (visible.bottom - top) / h,
+        };
+        (visible, factors)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ImageBoxImageScaling {
     #[default]
     Stretch,
     Frame(ImageBoxFrame),
+    /// Nine-slice frame with edges and middle part repeated instead of stretched.
+    FrameRepeat(ImageBoxFrame),
+    Tile(ImageBoxTile),
+    Fit(ImageBoxFit),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxColor {
     #[serde(default)]
     pub color: Color,
@@ -61,7 +172,7 @@ pub struct ImageBoxColor {
     pub scaling: ImageBoxImageScaling,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxImage {
     #[serde(default)]
     pub id: String,
@@ -96,7 +207,174 @@ impl ImageBoxImage {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ImageBoxFlipbookFrames {
+    /// Atlas image ids, one per frame.
+    List(Vec<String>),
+    /// Frames of equal size laid out on single image, read row by row.
+    Grid {
+        id: String,
+        columns: usize,
+        rows: usize,
+        /// Number of frames, for when last row is not completely filled.
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        count: Option<usize>,
+    },
+}
+
+impl Default for ImageBoxFlipbookFrames {
+    fn default() -> Self {
+        Self::List(Default::default())
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ImageBoxFlipbookLoop {
+    #[default]
+    Loop,
+    /// Plays frames once and stops at the last one.
+    Once,
+    /// Plays frames forward and then backward.
+    PingPong,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImageBoxFlipbook {
+    #[serde(default)]
+    pub frames: ImageBoxFlipbookFrames,
+    /// Frames per second. Non-positive value stops the animation at current frame.
+    #[serde(default = "ImageBoxFlipbook::default_fps")]
+    pub fps: Scalar,
+    #[serde(default)]
+    pub loop_mode: ImageBoxFlipbookLoop,
+    /// Frame to show. Image box overrides it with frame of its running flipbook animation.
+    #[serde(default)]
+    pub frame: usize,
+    #[serde(default)]
+    pub scaling: ImageBoxImageScaling,
+    #[serde(default = "ImageBoxImage::default_tint")]
+    pub tint: Color,
+}
+
+impl Default for ImageBoxFlipbook {
+    fn default() -> Self {
+        Self {
+            frames: Default::default(),
+            fps: Self::default_fps(),
+            loop_mode: Default::default(),
+            frame: 0,
+            scaling: Default::default(),
+            tint: ImageBoxImage::default_tint(),
+        }
+    }
+}
+
+impl ImageBoxFlipbook {
+    fn default_fps() -> Scalar {
+        12.0
+    }
+
+    pub fn new(frames: ImageBoxFlipbookFrames) -> Self {
+        Self {
+            frames,
+            ..Default::default()
+        }
+    }
+
+    pub fn fps(mut self, value: Scalar) -> Self {
+        self.fps = value;
+        self
+    }
+
+    pub fn loop_mode(mut self, value: ImageBoxFlipbookLoop) -> Self {
+        self.loop_mode = value;
+        self
+    }
+
+    pub fn frames_count(&self) -> usize {
+        match &self.frames {
+            ImageBoxFlipbookFrames::List(ids) => ids.len(),
+            ImageBoxFlipbookFrames::Grid {
+                columns,
+                rows,
+                count,
+                ..
+            } => {
+                let cells = columns * rows;
+                count.map(|count| count.min(cells)).unwrap_or(cells)
+            }
+        }
+    }
+
+    /// Time it takes to play all frames once, or forward and backward for ping-pong loop.
+    pub fn cycle_duration(&self) -> Scalar {
+        if self.fps <= 0.0 {
+            return 0.0;
+        }
+        let count = self.frames_count();
+        let steps = match self.loop_mode {
+            ImageBoxFlipbookLoop::PingPong => 2 * count.saturating_sub(1),
+            _ => count,
+        };
+        steps as Scalar / self.fps
+    }
+
+    /// Frame shown after given time since animation start.
+    pub fn frame_at(&self, time: Scalar) -> usize {
+        let count = self.frames_count();
+        if count == 0 {
+            return 0;
+        }
+        if self.fps <= 0.0 {
+            return self.frame.min(count - 1);
+        }
+        let step = (time.max(0.0) * self.fps).floor() as usize;
+        match self.loop_mode {
+            ImageBoxFlipbookLoop::Loop => step % count,
+            ImageBoxFlipbookLoop::Once => step.min(count - 1),
+            ImageBoxFlipbookLoop::PingPong => {
+                if count < 2 {
+                    return 0;
+                }
+                let period = 2 * (count - 1);
+                let step = step % period;
+                if step < count { step } else { period - step }
+            }
+        }
+    }
+
+    /// Atlas image id of current frame and region of that image (in percentage) it covers.
+    pub fn frame_image(&self) -> Option<(&str, Rect)> {
+        let count = self.frames_count();
+        if count == 0 {
+            return None;
+        }
+        let frame = self.frame.min(count - 1);
+        match &self.frames {
+            ImageBoxFlipbookFrames::List(ids) => Some((ids[frame].as_str(), (1.0, 1.0).into())),
+            ImageBoxFlipbookFrames::Grid {
+                id, columns, rows, ..
+            } => {
+                let column = (frame % columns) as Scalar;
+                let row = (frame / columns) as Scalar;
+                let width = 1.0 / *columns as Scalar;
+                let height = 1.0 / *rows as Scalar;
+                Some((
+                    id.as_str(),
+                    Rect {
+                        left: column * width,
+                        right: (column + 1.0) * width,
+                        top: row * height,
+                        bottom: (row + 1.0) * height,
+                    },
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxProceduralVertex {
     #[serde(default)]
     pub position: Vec2,
@@ -108,7 +386,7 @@ pub struct ImageBoxProceduralVertex {
     pub color: Color,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxProceduralMeshData {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -118,7 +396,7 @@ pub struct ImageBoxProceduralMeshData {
     pub triangles: Vec<[u32; 3]>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ImageBoxProceduralMesh {
     Owned(ImageBoxProceduralMeshData),
     Shared(Arc<ImageBoxProceduralMeshData>),
@@ -151,7 +429,7 @@ impl Default for ImageBoxProceduralMesh {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxProcedural {
     #[serde(default)]
     pub id: String,
@@ -235,11 +513,99 @@ impl ImageBoxProcedural {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ImageBoxShapeCorners {
+    #[serde(default)]
+    pub top_left: Scalar,
+    #[serde(default)]
+    pub top_right: Scalar,
+    #[serde(default)]
+    pub bottom_right: Scalar,
+    #[serde(default)]
+    pub bottom_left: Scalar,
+}
+
+impl From<Scalar> for ImageBoxShapeCorners {
+    fn from(v: Scalar) -> Self {
+        Self {
+            top_left: v,
+            top_right: v,
+            bottom_right: v,
+            bottom_left: v,
+        }
+    }
+}
+
+impl ImageBoxShapeCorners {
+    /// Scales radii down uniformly so that adjacent corners never overlap on given size.
+    pub fn fit(&self, size: Vec2) -> Self {
+        let top_left = self.top_left.max(0.0);
+        let top_right = self.top_right.max(0.0);
+        let bottom_right = self.bottom_right.max(0.0);
+        let bottom_left = self.bottom_left.max(0.0);
+        let factor = [
+            (size.x, top_left + top_right),
+            (size.x, bottom_left + bottom_right),
+            (size.y, top_left + bottom_left),
+            (size.y, top_right + bottom_right),
+        ]
+        .into_iter()
+        .filter(|(available, required)| *required > available.max(0.0))
+        .map(|(available, required)| available.max(0.0) / required)
+        .fold(1.0, Scalar::min);
+        Self {
+            top_left: top_left * factor,
+            top_right: top_right * factor,
+            bottom_right: bottom_right * factor,
+            bottom_left: bottom_left * factor,
+        }
+    }
+}
+
+/// Rounded rectangle with optional outline, drawn parametrically by renderers.
+///
+/// Stroke is placed inside shape bounds, so it never exceeds widget layout.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImageBoxShape {
+    #[serde(default)]
+    pub fill: Color,
+    #[serde(default)]
+    pub corner_radius: ImageBoxShapeCorners,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_zero")]
+    pub stroke_width: Scalar,
+    #[serde(default)]
+    pub stroke_color: Color,
+}
+
+impl ImageBoxShape {
+    pub fn new(fill: Color) -> Self {
+        Self {
+            fill,
+            ..Default::default()
+        }
+    }
+
+    pub fn corner_radius(mut self, value: impl Into<ImageBoxShapeCorners>) -> Self {
+        self.corner_radius = value.into();
+        self
+    }
+
+    pub fn stroke(mut self, width: Scalar, color: Color) -> Self {
+        self.stroke_width = width;
+        self.stroke_color = color;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ImageBoxMaterial {
     Color(ImageBoxColor),
     Image(ImageBoxImage),
     Procedural(ImageBoxProcedural),
+    Shape(ImageBoxShape),
+    Gradient(Gradient),
+    Flipbook(ImageBoxFlipbook),
 }
 
 impl Default for ImageBoxMaterial {
@@ -248,14 +614,14 @@ impl Default for ImageBoxMaterial {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ImageBoxSizeValue {
     #[default]
     Fill,
     Exact(Scalar),
 }
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBoxAspectRatio {
     #[serde(default)]
     pub horizontal_alignment: Scalar,
@@ -265,7 +631,7 @@ pub struct ImageBoxAspectRatio {
     pub outside: bool,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -279,6 +645,9 @@ pub struct ImageBox {
     #[serde(default)]
     pub material: ImageBoxMaterial,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
+    #[serde(default)]
     pub transform: Transform,
 }
 
@@ -298,6 +667,7 @@ impl TryFrom<ImageBoxNode> for ImageBox {
             height,
             content_keep_aspect_ratio,
             material,
+            shadows,
             transform,
             ..
         } = node;
@@ -307,6 +677,7 @@ impl TryFrom<ImageBoxNode> for ImageBox {
             height,
             content_keep_aspect_ratio,
             material,
+            shadows,
             transform,
         })
     }
@@ -320,6 +691,7 @@ pub struct ImageBoxNode {
     pub height: ImageBoxSizeValue,
     pub content_keep_aspect_ratio: Option<ImageBoxAspectRatio>,
     pub material: ImageBoxMaterial,
+    pub shadows: Vec<Shadow>,
     pub transform: Transform,
 }
 
@@ -339,11 +711,12 @@ impl From<ImageBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct ImageBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     pub width: ImageBoxSizeValue,
@@ -355,5 +728,86 @@ pub(crate) struct ImageBoxNodePrefab {
     #[serde(default)]
     pub material: ImageBoxMaterial,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
+    #[serde(default)]
     pub transform: Transform,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_corners_fit() {
+        let corners = ImageBoxShapeCorners::from(10.0).fit(Vec2 { x: 100.0, y: 50.0 });
+        assert_eq!(corners, ImageBoxShapeCorners::from(10.0));
+
+        let corners = ImageBoxShapeCorners {
+            top_left: 40.0,
+            top_right: 20.0,
+            bottom_right: 0.0,
+            bottom_left: -5.0,
+        }
+        .fit(Vec2 { x: 30.0, y: 100.0 });
+        assert_eq!(corners.top_left, 20.0);
+        assert_eq!(corners.top_right, 10.0);
+        assert_eq!(corners.bottom_right, 0.0);
+        assert_eq!(corners.bottom_left, 0.0);
+    }
+
+    #[test]
+    fn test_fit() {
+        let rect = Rect::from((100.0, 50.0));
+        let size = Vec2 { x: 20.0, y: 20.0 };
+
+        let (visible, factors) = ImageBoxFit::from(ImageBoxFitMode::Contain).fit(size, rect);
+        assert_eq!(visible, Rect::from((25.0, 75.0, 0.0, 50.0)));
+        assert_eq!(factors, Rect::from((1.0, 1.0)));
+
+        let (visible, factors) = ImageBoxFit::from(ImageBoxFitMode::Cover).fit(size, rect);
+        assert_eq!(visible, rect);
+        assert_eq!(factors, Rect::from((0.0, 1.0, 0.25, 0.75)));
+
+        let (visible, factors) = ImageBoxFit {
+            mode: ImageBoxFitMode::Contain,
+            alignment: 0.0.into(),
+            offset: Vec2 { x: -25.0, y: 0.0 },
+        }
+        .fit(size, rect);
+        assert_eq!(visible, Rect::from((0.0, 25.0, 0.0, 50.0)));
+        assert_eq!(factors, Rect::from((0.5, 1.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_flipbook() {
+        let flipbook = ImageBoxFlipbook::new(ImageBoxFlipbookFrames::Grid {
+            id: "sheet".to_owned(),
+            columns: 4,
+            rows: 2,
+            count: Some(6),
+        })
+        .fps(10.0);
+        assert_eq!(flipbook.frames_count(), 6);
+        assert_eq!(flipbook.cycle_duration(), 0.6);
+        assert_eq!(flipbook.frame_at(0.0), 0);
+        assert_eq!(flipbook.frame_at(0.25), 2);
+        assert_eq!(flipbook.frame_at(0.65), 0);
+
+        let flipbook = flipbook.loop_mode(ImageBoxFlipbookLoop::Once);
+        assert_eq!(flipbook.frame_at(10.0), 5);
+
+        let flipbook = flipbook.loop_mode(ImageBoxFlipbookLoop::PingPong);
+        assert_eq!(flipbook.cycle_duration(), 1.0);
+        assert_eq!(flipbook.frame_at(0.55), 5);
+        assert_eq!(flipbook.frame_at(0.75), 3);
+
+        let flipbook = ImageBoxFlipbook {
+            frame: 5,
+            ..flipbook
+        };
+        let (id, region) = flipbook.frame_image().unwrap();
+        assert_eq!(id, "sheet");
+        assert_eq!(region, Rect::from((0.25, 0.5, 0.5, 1.0)));
+    }
+}
@@ -8,6 +8,7 @@ pub mod size;
 pub mod text;
 
 use crate::{
+    Scalar,
     props::Props,
     widget::{
         WidgetId,
@@ -24,10 +25,53 @@ use crate::{
         },
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Additive,
+    Multiply,
+    Screen,
+}
+
+/// Describes how container contents are composited as a single group onto what lies below.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct UnitLayer {
+    #[serde(default = "UnitLayer::default_opacity")]
+    pub opacity: Scalar,
+    #[serde(default)]
+    pub blend: BlendMode,
+}
+
+impl Default for UnitLayer {
+    fn default() -> Self {
+        Self {
+            opacity: Self::default_opacity(),
+            blend: Default::default(),
+        }
+    }
+}
+
+impl UnitLayer {
+    fn default_opacity() -> Scalar {
+        1.0
+    }
+
+    pub fn new(opacity: Scalar, blend: BlendMode) -> Self {
+        Self { opacity, blend }
+    }
+
+    /// Tells if layer does not change how its contents look, so it can be skipped entirely.
+    pub fn is_passthrough(&self) -> bool {
+        self.opacity >= 1.0 && self.blend == BlendMode::Normal
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WidgetUnitInspectionNode {
     #[serde(default)]
     pub id: WidgetId,
@@ -44,7 +88,7 @@ pub trait WidgetUnitData {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum WidgetUnit {
     #[default]
     None,
@@ -227,7 +271,7 @@ implement_from_unit! {
     TextBoxNode => TextBox,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) enum WidgetUnitNodePrefab {
     #[default]
     None,
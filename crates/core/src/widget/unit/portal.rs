@@ -8,10 +8,11 @@ use crate::widget::{
         grid::{GridBoxItem, GridBoxItemNode, GridBoxItemNodePrefab},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum PortalBoxSlot {
     Slot(WidgetUnit),
     ContentItem(ContentBoxItem),
@@ -58,7 +59,7 @@ impl Default for PortalBoxSlotNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PortalBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -109,7 +110,7 @@ impl From<PortalBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct PortalBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
@@ -119,7 +120,7 @@ pub(crate) struct PortalBoxNodePrefab {
     pub owner: WidgetId,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) enum PortalBoxSlotNodePrefab {
     Slot(#[serde(default)] WidgetNodePrefab),
     ContentItem(#[serde(default)] ContentBoxItemNodePrefab),
@@ -8,10 +8,11 @@ use crate::{
         utils::{Rect, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum SizeBoxSizeValue {
     #[default]
     Content,
@@ -19,7 +20,7 @@ pub enum SizeBoxSizeValue {
     Exact(Scalar),
 }
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum SizeBoxAspectRatio {
     #[default]
     None,
@@ -27,7 +28,7 @@ pub enum SizeBoxAspectRatio {
     HeightOfWidth(Scalar),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SizeBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -109,11 +110,12 @@ impl From<SizeBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct SizeBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     pub slot: Box<WidgetNodePrefab>,
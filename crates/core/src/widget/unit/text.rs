@@ -5,13 +5,14 @@ use crate::{
         WidgetId,
         node::WidgetNode,
         unit::WidgetUnitData,
-        utils::{Color, Transform},
+        utils::{Color, Gradient, Shadow, Transform},
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum TextBoxHorizontalAlign {
     #[default]
     Left,
@@ -19,7 +20,7 @@ pub enum TextBoxHorizontalAlign {
     Right,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum TextBoxVerticalAlign {
     #[default]
     Top,
@@ -27,7 +28,7 @@ pub enum TextBoxVerticalAlign {
     Bottom,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum TextBoxDirection {
     #[default]
     HorizontalLeftToRight,
@@ -54,7 +55,7 @@ impl TextBoxDirection {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TextBoxFont {
     #[serde(default)]
     pub name: String,
@@ -62,7 +63,7 @@ pub struct TextBoxFont {
     pub size: Scalar,
 }
 
-#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum TextBoxSizeValue {
     Content,
     #[default]
@@ -70,7 +71,7 @@ pub enum TextBoxSizeValue {
     Exact(Scalar),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TextBox {
     #[serde(default)]
     pub id: WidgetId,
@@ -90,6 +91,13 @@ pub struct TextBox {
     pub font: TextBoxFont,
     #[serde(default)]
     pub color: Color,
+    /// When set, it replaces color of the text.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<Gradient>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
     #[serde(default)]
     pub transform: Transform,
 }
@@ -114,6 +122,8 @@ impl TryFrom<TextBoxNode> for TextBox {
             direction,
             font,
             color,
+            gradient,
+            shadows,
             transform,
             ..
         } = node;
@@ -127,6 +137,8 @@ impl TryFrom<TextBoxNode> for TextBox {
             direction,
             font,
             color,
+            gradient,
+            shadows,
             transform,
         })
     }
@@ -144,6 +156,8 @@ pub struct TextBoxNode {
     pub direction: TextBoxDirection,
     pub font: TextBoxFont,
     pub color: Color,
+    pub gradient: Option<Gradient>,
+    pub shadows: Vec<Shadow>,
     pub transform: Transform,
 }
 
@@ -163,11 +177,12 @@ impl From<TextBoxNode> for WidgetNode {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct TextBoxNodePrefab {
     #[serde(default)]
     pub id: WidgetId,
     #[serde(default)]
+    #[schemars(with = "crate::props::PropsGroupPrefab")]
     pub props: PrefabValue,
     #[serde(default)]
     pub text: String,
@@ -185,6 +200,13 @@ pub(crate) struct TextBoxNodePrefab {
     pub font: TextBoxFont,
     #[serde(default)]
     pub color: Color,
+    /// When set, it replaces color of the text.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<Gradient>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
     #[serde(default)]
     pub transform: Transform,
 }
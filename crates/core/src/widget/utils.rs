@@ -1,10 +1,12 @@
 use crate::{Integer, PropsData, Scalar};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[repr(C)]
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct Vec2 {
     #[serde(default)]
     pub x: Scalar,
@@ -31,9 +33,10 @@ impl From<[Scalar; 2]> for Vec2 {
 }
 
 #[repr(C)]
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct IntVec2 {
     #[serde(default)]
     pub x: Integer,
@@ -60,9 +63,10 @@ impl From<[Integer; 2]> for IntVec2 {
 }
 
 #[repr(C)]
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct Rect {
     #[serde(default)]
     pub left: Scalar,
@@ -150,9 +154,10 @@ impl Rect {
 }
 
 #[repr(C)]
-#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct IntRect {
     #[serde(default)]
     pub left: Integer,
@@ -207,9 +212,10 @@ impl From<(Integer, Integer)> for IntRect {
 }
 
 #[repr(C)]
-#[derive(PropsData, Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct Color {
     #[serde(default)]
     pub r: Scalar,
@@ -241,11 +247,21 @@ impl Color {
             a: 0.0,
         }
     }
+
+    pub fn lerp(self, other: Self, factor: Scalar) -> Self {
+        Self {
+            r: lerp(self.r, other.r, factor),
+            g: lerp(self.g, other.g, factor),
+            b: lerp(self.b, other.b, factor),
+            a: lerp(self.a, other.a, factor),
+        }
+    }
 }
 
-#[derive(PropsData, Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[props_data(crate::props::PropsData)]
 #[prefab(crate::Prefab)]
+#[prefab_schema]
 pub struct Transform {
     /// Rectangle center of mass. Values in range: <0;1>
     #[serde(default)]
@@ -287,6 +303,184 @@ impl Transform {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GradientStop {
+    /// Position along gradient. Values in range: <0;1>
+    #[serde(default)]
+    pub offset: Scalar,
+    #[serde(default)]
+    pub color: Color,
+}
+
+/// Gradient geometry, with points and sizes in rectangle fraction units.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum GradientKind {
+    /// Gradient line goes through rectangle center, so that its corners land on first and last
+    /// stop. Angle in radian units, zero goes from left to right.
+    Linear {
+        #[serde(default)]
+        angle: Scalar,
+    },
+    Radial {
+        #[serde(default)]
+        center: Vec2,
+        #[serde(default)]
+        radius: Vec2,
+    },
+    /// Stops are laid clockwise around center, starting at given radian angle.
+    Conic {
+        #[serde(default)]
+        center: Vec2,
+        #[serde(default)]
+        angle: Scalar,
+    },
+}
+
+impl Default for GradientKind {
+    fn default() -> Self {
+        Self::Linear { angle: 0.0 }
+    }
+}
+
+/// Color gradient. Stops are expected to be ordered by their offsets.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Gradient {
+    #[serde(default)]
+    pub kind: GradientKind,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    pub fn linear(angle: Scalar) -> Self {
+        Self {
+            kind: GradientKind::Linear { angle },
+            stops: Default::default(),
+        }
+    }
+
+    pub fn radial(center: impl Into<Vec2>, radius: impl Into<Vec2>) -> Self {
+        Self {
+            kind: GradientKind::Radial {
+                center: center.into(),
+                radius: radius.into(),
+            },
+            stops: Default::default(),
+        }
+    }
+
+    pub fn conic(center: impl Into<Vec2>, angle: Scalar) -> Self {
+        Self {
+            kind: GradientKind::Conic {
+                center: center.into(),
+                angle,
+            },
+            stops: Default::default(),
+        }
+    }
+
+    pub fn stop(mut self, offset: Scalar, color: Color) -> Self {
+        self.stops.push(GradientStop { offset, color });
+        self
+    }
+
+    /// Position along gradient of given point in rectangle fraction units.
+    pub fn factor(&self, point: Vec2) -> Scalar {
+        match self.kind {
+            GradientKind::Linear { angle } => {
+                let (y, x) = angle.sin_cos();
+                let extent = x.abs() + y.abs();
+                if extent > 0.0 {
+                    ((point.x - 0.5) * x + (point.y - 0.5) * y) / extent + 0.5
+                } else {
+                    0.0
+                }
+            }
+            GradientKind::Radial { center, radius } => {
+                let x = (point.x - center.x) / radius.x.abs().max(Scalar::EPSILON);
+                let y = (point.y - center.y) / radius.y.abs().max(Scalar::EPSILON);
+                (x * x + y * y).sqrt()
+            }
+            GradientKind::Conic { center, angle } => {
+                let result = (point.y - center.y).atan2(point.x - center.x) - angle;
+                (result / std::f32::consts::TAU).rem_euclid(1.0)
+            }
+        }
+    }
+
+    /// Color at given position along gradient.
+    pub fn sample(&self, factor: Scalar) -> Color {
+        let Some(first) = self.stops.first() else {
+            return Color::transparent();
+        };
+        if factor <= first.offset {
+            return first.color;
+        }
+        for pair in self.stops.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            if factor <= to.offset {
+                let range = to.offset - from.offset;
+                return if range > 0.0 {
+                    from.color.lerp(to.color, (factor - from.offset) / range)
+                } else {
+                    to.color
+                };
+            }
+        }
+        self.stops.last().unwrap().color
+    }
+
+    pub fn color_at(&self, point: Vec2) -> Color {
+        self.sample(self.factor(point))
+    }
+
+    pub fn multiply_alpha(&mut self, alpha: Scalar) {
+        for stop in &mut self.stops {
+            stop.color.a *= alpha;
+        }
+    }
+}
+
+/// Soft shadow cast by widget, or glow around it when there is no offset.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Shadow {
+    #[serde(default)]
+    pub offset: Vec2,
+    /// Width of area over which shadow fades out.
+    #[serde(default)]
+    pub blur: Scalar,
+    /// Distance by which shadow grows (or shrinks if negative) before blurring.
+    #[serde(default)]
+    pub spread: Scalar,
+    #[serde(default)]
+    pub color: Color,
+}
+
+impl Shadow {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            ..Default::default()
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    pub fn blur(mut self, blur: Scalar) -> Self {
+        self.blur = blur;
+        self
+    }
+
+    pub fn spread(mut self, spread: Scalar) -> Self {
+        self.spread = spread;
+        self
+    }
+}
+
 #[inline]
 pub fn lerp(from: Scalar, to: Scalar, factor: Scalar) -> Scalar {
     from + (to - from) * factor
@@ -296,3 +490,44 @@ pub fn lerp(from: Scalar, to: Scalar, factor: Scalar) -> Scalar {
 pub fn lerp_clamped(from: Scalar, to: Scalar, factor: Scalar) -> Scalar {
     lerp(from, to, factor.clamp(0.0, 1.0))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let black = Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        };
+        let white = Color::default();
+
+        let gradient = Gradient::linear(0.0).stop(0.0, black).stop(1.0, white);
+        assert_eq!(gradient.factor(Vec2 { x: 0.0, y: 0.3 }), 0.0);
+        assert_eq!(gradient.factor(Vec2 { x: 0.75, y: 1.0 }), 0.75);
+        assert_eq!(gradient.color_at(Vec2 { x: 0.5, y: 0.5 }).r, 0.5);
+        assert_eq!(gradient.sample(-1.0), black);
+        assert_eq!(gradient.sample(2.0), white);
+
+        let gradient = Gradient::linear(std::f32::consts::FRAC_PI_4);
+        assert!(gradient.factor(Vec2 { x: 0.0, y: 0.0 }).abs() < 1.0e-6);
+        assert!((gradient.factor(Vec2 { x: 1.0, y: 1.0 }) - 1.0).abs() < 1.0e-6);
+
+        let gradient = Gradient::radial(0.5, 0.5)
+            .stop(0.0, black)
+            .stop(0.5, black)
+            .stop(1.0, white);
+        assert_eq!(gradient.color_at(Vec2 { x: 0.6, y: 0.5 }), black);
+        assert_eq!(gradient.factor(Vec2 { x: 1.0, y: 0.5 }), 1.0);
+
+        let gradient = Gradient::conic(0.5, 0.0);
+        assert_eq!(gradient.factor(Vec2 { x: 1.0, y: 0.5 }), 0.0);
+        assert_eq!(gradient.factor(Vec2 { x: 0.5, y: 1.0 }), 0.25);
+        assert_eq!(gradient.factor(Vec2 { x: 0.0, y: 0.5 }), 0.5);
+
+        assert_eq!(Gradient::default().sample(0.5), Color::transparent());
+    }
+}
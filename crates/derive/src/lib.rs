@@ -1,10 +1,11 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use proc_macro_crate::{FoundCrate, crate_name};
 use quote::quote;
 use syn::{
-    DeriveInput, FnArg, Ident, ItemFn, Pat, PatIdent, Path, Result, Token, Type, TypePath,
-    TypeReference,
+    DeriveInput, FnArg, Generics, Ident, Item, ItemFn, LitStr, Pat, PatIdent, Path, Result, Token,
+    Type, TypePath, TypeReference,
     parse::{Parse, ParseStream},
     parse_macro_input, parse_str,
     punctuated::Punctuated,
@@ -49,6 +50,40 @@ fn is_arg_context(arg: &FnArg) -> Option<Ident> {
     }
 }
 
+fn core_crate_path() -> Path {
+    // `raui-core` refers to itself by name too, so doctests and tests resolve the same path.
+    let path = match crate_name("raui-core") {
+        Ok(FoundCrate::Itself) => "::raui_core".to_owned(),
+        Ok(FoundCrate::Name(name)) => format!("::{name}"),
+        Err(_) => match crate_name("raui") {
+            Ok(FoundCrate::Itself) => "crate::core".to_owned(),
+            Ok(FoundCrate::Name(name)) => format!("::{name}::core"),
+            Err(_) => "::raui_core".to_owned(),
+        },
+    };
+    parse_str::<Path>(&path).unwrap()
+}
+
+fn registration_name(attr: TokenStream, ident: &Ident) -> Result<String> {
+    if attr.is_empty() {
+        Ok(ident.to_string())
+    } else {
+        Ok(syn::parse::<LitStr>(attr)?.value())
+    }
+}
+
+/// Registered items are instantiated by name, so there is nothing to pick generic arguments.
+fn registration_without_generics(generics: &Generics) -> Result<()> {
+    if generics.params.is_empty() {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            generics,
+            "Registered items can not be generic, register non-generic wrapper or type alias instead!",
+        ))
+    }
+}
+
 // The links won't be broken when built in the context of the `raui` crate
 /// An attribute macro that allows you to add hooks that will execute before your component body
 ///
@@ -122,12 +157,16 @@ pub fn post_hooks(attr: TokenStream, input: TokenStream) -> TokenStream {
 // The links won't be broken when built in the context of the `raui` crate
 /// Derive macro for the [`PropsData`][raui_core::props::PropsData] trait
 ///
+/// With `#[prefab_schema]` attribute, [`Prefab::prefab_schema`][raui_core::Prefab::prefab_schema]
+/// uses `schemars::JsonSchema` implementation of the type.
+///
 /// # Example
 ///
 /// ```ignore
-/// #[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize)]
+/// #[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 /// #[props_data(crate::props::PropsData)]
 /// #[prefab(crate::Prefab)]
+/// #[prefab_schema]
 /// pub struct ButtonProps {
 ///     #[serde(default)]
 ///     pub selected: bool,
@@ -139,13 +178,14 @@ pub fn post_hooks(attr: TokenStream, input: TokenStream) -> TokenStream {
 ///     pub pointer: Vec2,
 /// }
 /// ```
-#[proc_macro_derive(PropsData, attributes(remote, props_data, prefab))]
+#[proc_macro_derive(PropsData, attributes(remote, props_data, prefab, prefab_schema))]
 pub fn derive_props(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, attrs, .. } = parse_macro_input!(input as DeriveInput);
 
     let mut path = Path::from(ident);
     let mut props_data = parse_str::<Path>("PropsData").unwrap();
     let mut prefab = parse_str::<Path>("Prefab").unwrap();
+    let mut prefab_schema = false;
     for attr in attrs {
         if let Some(ident) = attr.path.get_ident() {
             if ident == "remote" {
@@ -154,9 +194,21 @@ pub fn derive_props(input: TokenStream) -> TokenStream {
                 props_data = attr.parse_args::<Path>().unwrap();
             } else if ident == "prefab" {
                 prefab = attr.parse_args::<Path>().unwrap();
+            } else if ident == "prefab_schema" {
+                prefab_schema = true;
             }
         }
     }
+    let prefab_schema = if prefab_schema {
+        let core = core_crate_path();
+        quote! {
+            fn prefab_schema(generator: &mut #core::schemars::SchemaGenerator) -> #core::schemars::Schema {
+                generator.subschema_for::<Self>()
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let tokens = quote! {
         impl #props_data for #path
@@ -172,7 +224,9 @@ pub fn derive_props(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl #prefab for #path {}
+        impl #prefab for #path {
+            #prefab_schema
+        }
     };
     tokens.into()
 }
@@ -221,3 +275,91 @@ pub fn derive_message(input: TokenStream) -> TokenStream {
     };
     tokens.into()
 }
+
+// The links won't be broken when built in the context of the `raui` crate
+/// Registers widget component function to be found by
+/// [`registration::setup`][raui_core::registration::setup], under function name or the name
+/// given in attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// #[register_component]
+/// fn app(context: WidgetContext) -> WidgetNode {
+///     make_widget!(text_box).into()
+/// }
+///
+/// #[register_component("app_v2")]
+/// fn app_next(context: WidgetContext) -> WidgetNode {
+///     make_widget!(image_box).into()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register_component(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemFn);
+    if let Err(error) = registration_without_generics(&item.sig.generics) {
+        return error.to_compile_error().into();
+    }
+    let ident = &item.sig.ident;
+    let name = match registration_name(attr, ident) {
+        Ok(name) => name,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let core = core_crate_path();
+
+    let tokens = quote! {
+        #item
+
+        #core::registration::inventory::submit! {
+            #core::registration::ComponentRegistration::new(#name, #ident)
+        }
+    };
+    tokens.into()
+}
+
+// The links won't be broken when built in the context of the `raui` crate
+/// Registers [`PropsData`][raui_core::props::PropsData] type to be found by
+/// [`registration::setup`][raui_core::registration::setup], under type name or the name given in
+/// attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// #[register_props]
+/// #[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+/// struct AppProps {
+///     #[serde(default)]
+///     pub title: String,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register_props(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as Item);
+    let (ident, generics) = match &item {
+        Item::Struct(item) => (&item.ident, &item.generics),
+        Item::Enum(item) => (&item.ident, &item.generics),
+        Item::Type(item) => (&item.ident, &item.generics),
+        _ => {
+            return syn::Error::new_spanned(item, "Expected struct, enum or type alias!")
+                .to_compile_error()
+                .into();
+        }
+    };
+    if let Err(error) = registration_without_generics(generics) {
+        return error.to_compile_error().into();
+    }
+    let name = match registration_name(attr, ident) {
+        Ok(name) => name,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let core = core_crate_path();
+
+    let tokens = quote! {
+        #item
+
+        #core::registration::inventory::submit! {
+            #core::registration::PropsRegistration::new::<#ident>(#name)
+        }
+    };
+    tokens.into()
+}
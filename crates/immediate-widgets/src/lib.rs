@@ -244,6 +244,7 @@ pub mod core {
         impl_content_components! {
             "content":
             float_view_control,
+            focus_scope,
             navigation_barrier,
         }
 
@@ -425,6 +426,7 @@ pub mod core {
                 from,
                 to,
                 direction,
+                step,
                 ..
             } = props.read_cloned_or_default();
             let button_state = use_state(ImmediateButton::default);
@@ -444,6 +446,7 @@ pub mod core {
                         from,
                         to,
                         direction,
+                        step,
                     })
                     .named_slot("content", node),
             );
@@ -631,6 +634,7 @@ pub mod material {
                 from,
                 to,
                 direction,
+                step,
                 ..
             } = props.read_cloned_or_default();
             let button_state = use_state(ImmediateButton::default);
@@ -650,6 +654,7 @@ pub mod material {
                         from,
                         to,
                         direction,
+                        step,
                     })
                     .named_slot("content", node),
             );
@@ -667,6 +672,7 @@ pub mod material {
                 from,
                 to,
                 direction,
+                step,
                 ..
             } = props.read_cloned_or_default();
             let button_state = use_state(ImmediateButton::default);
@@ -683,6 +689,7 @@ pub mod material {
                         from,
                         to,
                         direction,
+                        step,
                     }),
             );
             (result, button_result)
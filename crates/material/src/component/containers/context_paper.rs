@@ -3,7 +3,9 @@ use crate::{
     theme::{ThemeColor, ThemedWidgetProps},
 };
 use raui_core::{
-    PropsData, Scalar, make_widget, unpack_named_slots,
+    PropsData, Scalar, make_widget,
+    schemars::JsonSchema,
+    unpack_named_slots,
     widget::{
         WidgetIdOrRef,
         component::{
@@ -22,9 +24,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct ContextPaperProps {
     #[serde(default = "ContextPaperProps::default_margin")]
     pub margin: Rect,
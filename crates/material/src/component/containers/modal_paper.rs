@@ -1,11 +1,13 @@
 use crate::theme::ThemeProps;
 use raui_core::{
-    PropsData, make_widget, unpack_named_slots,
+    PropsData, make_widget,
+    schemars::JsonSchema,
+    unpack_named_slots,
     widget::{
         component::{
             containers::{content_box::content_box, portal_box::portal_box},
             image_box::{ImageBoxProps, image_box},
-            interactive::navigation::navigation_barrier,
+            interactive::navigation::{focus_scope, navigation_barrier},
         },
         context::WidgetContext,
         node::WidgetNode,
@@ -15,20 +17,29 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct ModalPaperProps {
     #[serde(default = "ModalPaperProps::default_shadow_shown")]
     pub shadow_shown: bool,
     #[serde(default)]
     pub shadow_variant: String,
+    /// Keeps navigation inside modal content and restores previous selection when closed.
+    #[serde(default = "ModalPaperProps::default_focus_trapped")]
+    pub focus_trapped: bool,
 }
 
 impl ModalPaperProps {
     fn default_shadow_shown() -> bool {
         true
     }
+
+    fn default_focus_trapped() -> bool {
+        true
+    }
 }
 
 impl Default for ModalPaperProps {
@@ -36,6 +47,7 @@ impl Default for ModalPaperProps {
         Self {
             shadow_shown: Self::default_shadow_shown(),
             shadow_variant: Default::default(),
+            focus_trapped: Self::default_focus_trapped(),
         }
     }
 }
@@ -53,6 +65,7 @@ pub fn modal_paper(context: WidgetContext) -> WidgetNode {
     let ModalPaperProps {
         shadow_shown,
         shadow_variant,
+        focus_trapped,
     } = props.read_cloned_or_default();
 
     let mut color = Color::transparent();
@@ -71,6 +84,15 @@ pub fn modal_paper(context: WidgetContext) -> WidgetNode {
         ..Default::default()
     };
 
+    let content = if focus_trapped {
+        make_widget!(focus_scope)
+            .key("focus-scope")
+            .named_slot("content", content)
+            .into()
+    } else {
+        content
+    };
+
     make_widget!(portal_box)
         .key(key)
         .named_slot(
@@ -2,6 +2,7 @@ use crate::theme::{ThemeColor, ThemeProps, ThemeVariant, ThemedImageMaterial, Th
 use raui_core::{
     PropsData, Scalar, make_widget,
     props::Props,
+    schemars::JsonSchema,
     widget::{
         component::{
             WidgetComponent,
@@ -18,20 +19,28 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct PaperProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frame: Option<ImageBoxFrame>,
     #[serde(default)]
     pub variant: String,
+    /// Key of theme shadows cast by filled background.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_variant: Option<String>,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct PaperContentLayoutProps(pub ContentBoxItemLayout);
 
 pub fn nav_paper(context: WidgetContext) -> WidgetNode {
@@ -79,7 +88,7 @@ pub fn paper_impl(component: WidgetComponent, context: WidgetContext) -> WidgetN
             });
             let background_colors = shared_props
                 .map_or_default::<ThemeProps, _, _>(|props| props.background_colors.clone());
-            let image = match content_background {
+            let mut image = match content_background {
                 ThemedImageMaterial::Color => {
                     let color = match themed_props.color {
                         ThemeColor::Default => background_colors.main.default.main,
@@ -102,7 +111,32 @@ pub fn paper_impl(component: WidgetComponent, context: WidgetContext) -> WidgetN
                     material: ImageBoxMaterial::Procedural(material),
                     ..Default::default()
                 },
+                ThemedImageMaterial::Shape(material) => {
+                    let palette = match themed_props.color {
+                        ThemeColor::Default => &background_colors.main.default,
+                        ThemeColor::Primary => &background_colors.main.primary,
+                        ThemeColor::Secondary => &background_colors.main.secondary,
+                    };
+                    ImageBoxProps::shape(material.shape(palette.main, palette.dark))
+                }
+                ThemedImageMaterial::Gradient(material) => {
+                    let palette = match themed_props.color {
+                        ThemeColor::Default => &background_colors.main.default,
+                        ThemeColor::Primary => &background_colors.main.primary,
+                        ThemeColor::Secondary => &background_colors.main.secondary,
+                    };
+                    ImageBoxProps::gradient(palette.gradient(&material))
+                }
             };
+            if let Some(shadow_variant) = &paper_props.shadow_variant {
+                image.shadows = shared_props.map_or_default::<ThemeProps, _, _>(|props| {
+                    props
+                        .shadow_variants
+                        .get(shadow_variant)
+                        .cloned()
+                        .unwrap_or_default()
+                });
+            }
             let props = Props::new(ContentBoxItemLayout {
                 depth: Scalar::NEG_INFINITY,
                 ..Default::default()
@@ -3,7 +3,9 @@ use crate::{
     theme::{ThemeColor, ThemeProps, ThemedImageMaterial, ThemedWidgetProps},
 };
 use raui_core::{
-    PropsData, Scalar, make_widget, unpack_named_slots,
+    PropsData, Scalar, make_widget,
+    schemars::JsonSchema,
+    unpack_named_slots,
     widget::{
         component::containers::scroll_box::{
             SideScrollbarsProps, nav_scroll_box, nav_scroll_box_side_scrollbars,
@@ -18,9 +20,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct SideScrollbarsPaperProps {
     #[serde(default)]
     pub size: Scalar,
@@ -103,6 +107,22 @@ pub fn scroll_paper_side_scrollbars(context: WidgetContext) -> WidgetNode {
             }
             ThemedImageMaterial::Image(material) => ImageBoxMaterial::Image(material),
             ThemedImageMaterial::Procedural(material) => ImageBoxMaterial::Procedural(material),
+            ThemedImageMaterial::Shape(material) => {
+                let palette = match themed_props.color {
+                    ThemeColor::Default => &colors.main.default,
+                    ThemeColor::Primary => &colors.main.primary,
+                    ThemeColor::Secondary => &colors.main.secondary,
+                };
+                ImageBoxMaterial::Shape(material.shape(palette.main, palette.dark))
+            }
+            ThemedImageMaterial::Gradient(material) => {
+                let palette = match themed_props.color {
+                    ThemeColor::Default => &colors.main.default,
+                    ThemeColor::Primary => &colors.main.primary,
+                    ThemeColor::Secondary => &colors.main.secondary,
+                };
+                ImageBoxMaterial::Gradient(palette.gradient(&material))
+            }
         })
     } else {
         None
@@ -131,6 +151,22 @@ pub fn scroll_paper_side_scrollbars(context: WidgetContext) -> WidgetNode {
             }
             ThemedImageMaterial::Image(material) => ImageBoxMaterial::Image(material),
             ThemedImageMaterial::Procedural(material) => ImageBoxMaterial::Procedural(material),
+            ThemedImageMaterial::Shape(material) => {
+                let palette = match themed_props.color {
+                    ThemeColor::Default => &colors.main.default,
+                    ThemeColor::Primary => &colors.main.primary,
+                    ThemeColor::Secondary => &colors.main.secondary,
+                };
+                ImageBoxMaterial::Shape(material.shape(palette.main, palette.dark))
+            }
+            ThemedImageMaterial::Gradient(material) => {
+                let palette = match themed_props.color {
+                    ThemeColor::Default => &colors.main.default,
+                    ThemeColor::Primary => &colors.main.primary,
+                    ThemeColor::Secondary => &colors.main.secondary,
+                };
+                ImageBoxMaterial::Gradient(palette.gradient(&material))
+            }
         }
     };
 
@@ -3,7 +3,9 @@ use crate::{
     theme::{ThemeColor, ThemedWidgetProps},
 };
 use raui_core::{
-    PropsData, Scalar, make_widget, unpack_named_slots,
+    PropsData, Scalar, make_widget,
+    schemars::JsonSchema,
+    unpack_named_slots,
     widget::{
         component::containers::{
             size_box::{SizeBoxProps, size_box},
@@ -18,9 +20,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct TooltipPaperProps {
     #[serde(default = "TooltipPaperProps::default_margin")]
     pub margin: Rect,
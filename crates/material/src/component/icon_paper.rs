@@ -1,20 +1,22 @@
 use crate::theme::{ThemeColor, ThemeProps, ThemedWidgetProps};
 use raui_core::{
     PropsData, make_widget,
+    schemars::JsonSchema,
     widget::{
         component::image_box::{ImageBoxProps, image_box},
         context::WidgetContext,
         node::WidgetNode,
         unit::image::{
-            ImageBoxAspectRatio, ImageBoxImage, ImageBoxImageScaling, ImageBoxMaterial,
-            ImageBoxSizeValue,
+            ImageBoxAspectRatio, ImageBoxFlipbook, ImageBoxImage, ImageBoxImageScaling,
+            ImageBoxMaterial, ImageBoxSizeValue,
         },
         utils::{Rect, Transform},
     },
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct IconImage {
     #[serde(default)]
     pub id: String,
@@ -25,12 +27,18 @@ pub struct IconImage {
     pub scaling: ImageBoxImageScaling,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct IconPaperProps {
     #[serde(default)]
     pub image: IconImage,
+    /// When set, it replaces image with flipbook animation.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flipbook: Option<ImageBoxFlipbook>,
     #[serde(default)]
     pub size_level: usize,
     #[serde(default)]
@@ -69,11 +77,14 @@ pub fn icon_paper(context: WidgetContext) -> WidgetNode {
         source_rect,
         scaling,
     } = icon_props.image;
-    let image = ImageBoxImage {
-        id,
-        source_rect,
-        scaling,
-        tint,
+    let material = match icon_props.flipbook {
+        Some(flipbook) => ImageBoxMaterial::Flipbook(ImageBoxFlipbook { tint, ..flipbook }),
+        None => ImageBoxMaterial::Image(ImageBoxImage {
+            id,
+            source_rect,
+            scaling,
+            tint,
+        }),
     };
     let props = ImageBoxProps {
         width: ImageBoxSizeValue::Exact(size),
@@ -83,7 +94,8 @@ pub fn icon_paper(context: WidgetContext) -> WidgetNode {
             vertical_alignment: 0.5,
             outside: false,
         }),
-        material: ImageBoxMaterial::Image(image),
+        material,
+        shadows: Default::default(),
         transform: icon_props.transform,
     };
 
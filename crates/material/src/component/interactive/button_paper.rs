@@ -5,6 +5,7 @@ use crate::{
 use raui_core::{
     PropsData, Scalar, make_widget,
     props::Props,
+    schemars::JsonSchema,
     unpack_named_slots,
     widget::{
         component::{
@@ -23,9 +24,13 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(
+    PropsData, Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema,
+)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub enum ButtonPaperOverrideStyle {
     #[default]
     None,
@@ -84,7 +89,7 @@ fn button_paper_content(context: WidgetContext) -> WidgetNode {
             });
             let button_colors = shared_props
                 .map_or_default::<ThemeProps, _, _>(|props| props.active_colors.clone());
-            let image = match button_background {
+            let mut image = match button_background {
                 ThemedImageMaterial::Color => {
                     let color = match themed_props.color {
                         ThemeColor::Default => button_colors.main.default.main,
@@ -107,7 +112,32 @@ fn button_paper_content(context: WidgetContext) -> WidgetNode {
                     material: ImageBoxMaterial::Procedural(material),
                     ..Default::default()
                 },
+                ThemedImageMaterial::Shape(material) => {
+                    let palette = match themed_props.color {
+                        ThemeColor::Default => &button_colors.main.default,
+                        ThemeColor::Primary => &button_colors.main.primary,
+                        ThemeColor::Secondary => &button_colors.main.secondary,
+                    };
+                    ImageBoxProps::shape(material.shape(palette.main, palette.dark))
+                }
+                ThemedImageMaterial::Gradient(material) => {
+                    let palette = match themed_props.color {
+                        ThemeColor::Default => &button_colors.main.default,
+                        ThemeColor::Primary => &button_colors.main.primary,
+                        ThemeColor::Secondary => &button_colors.main.secondary,
+                    };
+                    ImageBoxProps::gradient(palette.gradient(&material))
+                }
             };
+            if let Some(shadow_variant) = &paper_props.shadow_variant {
+                image.shadows = shared_props.map_or_default::<ThemeProps, _, _>(|props| {
+                    props
+                        .shadow_variants
+                        .get(shadow_variant)
+                        .cloned()
+                        .unwrap_or_default()
+                });
+            }
             let props = Props::new(ContentBoxItemLayout {
                 depth: Scalar::NEG_INFINITY,
                 ..Default::default()
@@ -3,7 +3,9 @@ use crate::{
     theme::{ThemeColor, ThemeProps, ThemedImageMaterial, ThemedSliderMaterial},
 };
 use raui_core::{
-    PropsData, make_widget, unpack_named_slots,
+    PropsData, make_widget,
+    schemars::JsonSchema,
+    unpack_named_slots,
     widget::{
         component::{
             WidgetComponent,
@@ -23,9 +25,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct SliderPaperProps {
     #[serde(default)]
     pub variant: String,
@@ -55,9 +59,11 @@ impl SliderPaperProps {
     }
 }
 
-#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct NumericSliderPaperProps {
     #[serde(default)]
     pub fractional_digits_count: Option<usize>,
@@ -119,16 +125,18 @@ pub fn slider_paper_impl(component: WidgetComponent, context: WidgetContext) ->
     let (background, filling) = match shared_props.read::<ThemeProps>() {
         Ok(props) => {
             if let Some(material) = props.slider_variants.get(&variant).cloned() {
-                let background_color = match background_color {
-                    ThemeColor::Default => props.active_colors.main.default.main,
-                    ThemeColor::Primary => props.active_colors.main.primary.main,
-                    ThemeColor::Secondary => props.active_colors.main.secondary.main,
+                let background_palette = match background_color {
+                    ThemeColor::Default => &props.active_colors.main.default,
+                    ThemeColor::Primary => &props.active_colors.main.primary,
+                    ThemeColor::Secondary => &props.active_colors.main.secondary,
                 };
-                let filling_color = match filling_color {
-                    ThemeColor::Default => props.active_colors.main.default.main,
-                    ThemeColor::Primary => props.active_colors.main.primary.main,
-                    ThemeColor::Secondary => props.active_colors.main.secondary.main,
+                let filling_palette = match filling_color {
+                    ThemeColor::Default => &props.active_colors.main.default,
+                    ThemeColor::Primary => &props.active_colors.main.primary,
+                    ThemeColor::Secondary => &props.active_colors.main.secondary,
                 };
+                let background_color = background_palette.main;
+                let filling_color = filling_palette.main;
                 let ThemedSliderMaterial {
                     background,
                     filling,
@@ -152,6 +160,16 @@ pub fn slider_paper_impl(component: WidgetComponent, context: WidgetContext) ->
                         material: ImageBoxMaterial::Procedural(data),
                         ..Default::default()
                     },
+                    ThemedImageMaterial::Shape(data) => ImageBoxProps {
+                        material: ImageBoxMaterial::Shape(
+                            data.shape(background_color, background_color),
+                        ),
+                        ..Default::default()
+                    },
+                    ThemedImageMaterial::Gradient(data) => ImageBoxProps {
+                        material: ImageBoxMaterial::Gradient(background_palette.gradient(&data)),
+                        ..Default::default()
+                    },
                 };
                 let filling = match filling {
                     ThemedImageMaterial::Color => ImageBoxProps {
@@ -172,6 +190,14 @@ pub fn slider_paper_impl(component: WidgetComponent, context: WidgetContext) ->
                         material: ImageBoxMaterial::Procedural(data),
                         ..Default::default()
                     },
+                    ThemedImageMaterial::Shape(data) => ImageBoxProps {
+                        material: ImageBoxMaterial::Shape(data.shape(filling_color, filling_color)),
+                        ..Default::default()
+                    },
+                    ThemedImageMaterial::Gradient(data) => ImageBoxProps {
+                        material: ImageBoxMaterial::Gradient(filling_palette.gradient(&data)),
+                        ..Default::default()
+                    },
                 };
                 (background, filling)
             } else {
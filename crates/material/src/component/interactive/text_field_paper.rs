@@ -7,6 +7,7 @@ use crate::{
 };
 use raui_core::{
     PropsData, Scalar, make_widget,
+    schemars::JsonSchema,
     widget::{
         component::{
             WidgetAlpha, WidgetComponent,
@@ -25,9 +26,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct TextFieldPaperProps {
     #[serde(default)]
     pub hint: String,
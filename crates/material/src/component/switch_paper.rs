@@ -1,18 +1,22 @@
-use crate::theme::{ThemeColor, ThemeProps, ThemedImageMaterial, ThemedWidgetProps};
+use crate::theme::{ThemeColor, ThemeColorSet, ThemeProps, ThemedImageMaterial, ThemedWidgetProps};
 use raui_core::{
     PropsData, Scalar, make_widget,
+    schemars::JsonSchema,
     widget::{
         component::image_box::{ImageBoxProps, image_box},
         context::WidgetContext,
         node::WidgetNode,
         unit::image::{ImageBoxColor, ImageBoxImageScaling, ImageBoxMaterial, ImageBoxSizeValue},
+        utils::Color,
     },
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct SwitchPaperProps {
     #[serde(default)]
     pub on: bool,
@@ -37,14 +41,15 @@ pub fn switch_paper(context: WidgetContext) -> WidgetNode {
         size_level,
     } = props.read_cloned_or_default();
     let themed_props = props.read_cloned_or_default::<ThemedWidgetProps>();
-    let color = match shared_props.read::<ThemeProps>() {
+    let palette = match shared_props.read::<ThemeProps>() {
         Ok(props) => match themed_props.color {
-            ThemeColor::Default => props.active_colors.main.default.main,
-            ThemeColor::Primary => props.active_colors.main.primary.main,
-            ThemeColor::Secondary => props.active_colors.main.secondary.main,
+            ThemeColor::Default => props.active_colors.main.default.clone(),
+            ThemeColor::Primary => props.active_colors.main.primary.clone(),
+            ThemeColor::Secondary => props.active_colors.main.secondary.clone(),
         },
-        Err(_) => Default::default(),
+        Err(_) => ThemeColorSet::uniform(Default::default()),
     };
+    let color = palette.main;
     let (size, material) = match shared_props.read::<ThemeProps>() {
         Ok(props) => {
             let size = props
@@ -94,6 +99,21 @@ pub fn switch_paper(context: WidgetContext) -> WidgetNode {
             height: ImageBoxSizeValue::Exact(size),
             ..Default::default()
         },
+        ThemedImageMaterial::Shape(data) => {
+            let fill = if on { color } else { Color::transparent() };
+            ImageBoxProps {
+                material: ImageBoxMaterial::Shape(data.shape(fill, color)),
+                width: ImageBoxSizeValue::Exact(size),
+                height: ImageBoxSizeValue::Exact(size),
+                ..Default::default()
+            }
+        }
+        ThemedImageMaterial::Gradient(data) => ImageBoxProps {
+            material: ImageBoxMaterial::Gradient(palette.gradient(&data)),
+            width: ImageBoxSizeValue::Exact(size),
+            height: ImageBoxSizeValue::Exact(size),
+            ..Default::default()
+        },
     };
 
     make_widget!(image_box)
@@ -1,6 +1,7 @@
 use crate::theme::{ThemeColor, ThemeProps, ThemedTextMaterial, ThemedWidgetProps};
 use raui_core::{
     PropsData, make_widget,
+    schemars::JsonSchema,
     widget::{
         component::text_box::{TextBoxProps, text_box},
         context::WidgetContext,
@@ -11,9 +12,11 @@ use raui_core::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct TextPaperProps {
     #[serde(default)]
     pub text: String,
@@ -63,6 +66,8 @@ pub fn text_paper(context: WidgetContext) -> WidgetNode {
         mut vertical_align,
         direction,
         font,
+        gradient,
+        shadows,
     } = match shared_props.read::<ThemeProps>() {
         Ok(props) => props
             .text_variants
@@ -77,24 +82,25 @@ pub fn text_paper(context: WidgetContext) -> WidgetNode {
     if let Some(alignment_override) = vertical_align_override {
         vertical_align = alignment_override;
     }
-    let color = if let Some(color_override) = color_override {
-        color_override
+    let (color, gradient) = if let Some(color_override) = color_override {
+        (color_override, None)
     } else {
         match shared_props.read::<ThemeProps>() {
             Ok(props) => {
-                if use_main_color {
-                    match themed_props.color {
-                        ThemeColor::Default => props.active_colors.main.default.main,
-                        ThemeColor::Primary => props.active_colors.main.primary.main,
-                        ThemeColor::Secondary => props.active_colors.main.secondary.main,
-                    }
+                let colors = if use_main_color {
+                    &props.active_colors.main
                 } else {
-                    match themed_props.color {
-                        ThemeColor::Default => props.active_colors.contrast.default.main,
-                        ThemeColor::Primary => props.active_colors.contrast.primary.main,
-                        ThemeColor::Secondary => props.active_colors.contrast.secondary.main,
-                    }
-                }
+                    &props.active_colors.contrast
+                };
+                let set = match themed_props.color {
+                    ThemeColor::Default => &colors.default,
+                    ThemeColor::Primary => &colors.primary,
+                    ThemeColor::Secondary => &colors.secondary,
+                };
+                (
+                    set.main,
+                    gradient.as_ref().map(|gradient| set.gradient(gradient)),
+                )
             }
             Err(_) => Default::default(),
         }
@@ -108,6 +114,8 @@ pub fn text_paper(context: WidgetContext) -> WidgetNode {
         direction,
         font,
         color,
+        gradient,
+        shadows,
         transform,
     };
 
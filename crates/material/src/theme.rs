@@ -1,10 +1,14 @@
 use raui_core::{
+    schemars::JsonSchema,
     widget::{
         unit::{
-            image::{ImageBoxImage, ImageBoxProcedural},
+            image::{
+                ImageBoxFit, ImageBoxFrame, ImageBoxImage, ImageBoxImageScaling,
+                ImageBoxProcedural, ImageBoxShape, ImageBoxShapeCorners, ImageBoxTile,
+            },
             text::{TextBoxDirection, TextBoxFont, TextBoxHorizontalAlign, TextBoxVerticalAlign},
         },
-        utils::{Color, lerp_clamped},
+        utils::{Color, Gradient, GradientKind, GradientStop, Shadow, lerp_clamped},
     },
     {PropsData, Scalar},
 };
@@ -15,7 +19,8 @@ use std::f32::consts::PI;
 const DEFAULT_BACKGROUND_MIXING_FACTOR: Scalar = 0.1;
 const DEFAULT_VARIANT_MIXING_FACTOR: Scalar = 0.2;
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub enum ThemeColor {
     #[default]
     Default,
@@ -23,7 +28,8 @@ pub enum ThemeColor {
     Secondary,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub enum ThemeColorVariant {
     #[default]
     Main,
@@ -31,7 +37,8 @@ pub enum ThemeColorVariant {
     Dark,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub enum ThemeVariant {
     ContentOnly,
     #[default]
@@ -39,9 +46,11 @@ pub enum ThemeVariant {
     Outline,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct ThemedWidgetProps {
     #[serde(default)]
     pub color: ThemeColor,
@@ -51,7 +60,8 @@ pub struct ThemedWidgetProps {
     pub variant: ThemeVariant,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemeColorSet {
     #[serde(default)]
     pub main: Color,
@@ -81,9 +91,24 @@ impl ThemeColorSet {
     pub fn get_themed(&self, themed: &ThemedWidgetProps) -> Color {
         self.get(themed.color_variant)
     }
+
+    pub fn gradient(&self, material: &ThemedGradientMaterial) -> Gradient {
+        Gradient {
+            kind: material.kind,
+            stops: material
+                .stops
+                .iter()
+                .map(|stop| GradientStop {
+                    offset: stop.offset,
+                    color: self.get(stop.variant),
+                })
+                .collect(),
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemeColors {
     #[serde(default)]
     pub default: ThemeColorSet,
@@ -115,7 +140,8 @@ impl ThemeColors {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemeColorsBundle {
     #[serde(default)]
     pub main: ThemeColors,
@@ -144,15 +170,107 @@ impl ThemeColorsBundle {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+/// Parametric shape whose fill and stroke colors come from theme palette of the widget.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
+pub struct ThemedShapeMaterial {
+    #[serde(default)]
+    pub corner_radius: ImageBoxShapeCorners,
+    #[serde(default)]
+    pub stroke_width: Scalar,
+}
+
+impl ThemedShapeMaterial {
+    pub fn new(corner_radius: impl Into<ImageBoxShapeCorners>, stroke_width: Scalar) -> Self {
+        Self {
+            corner_radius: corner_radius.into(),
+            stroke_width,
+        }
+    }
+
+    pub fn shape(&self, fill: Color, stroke: Color) -> ImageBoxShape {
+        ImageBoxShape::new(fill)
+            .corner_radius(self.corner_radius)
+            .stroke(self.stroke_width, stroke)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
+pub struct ThemedGradientStop {
+    #[serde(default)]
+    pub offset: Scalar,
+    #[serde(default)]
+    pub variant: ThemeColorVariant,
+}
+
+/// Gradient whose stop colors are picked from theme color set of the widget.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
+pub struct ThemedGradientMaterial {
+    #[serde(default)]
+    pub kind: GradientKind,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stops: Vec<ThemedGradientStop>,
+}
+
+impl ThemedGradientMaterial {
+    pub fn new(kind: GradientKind) -> Self {
+        Self {
+            kind,
+            stops: Default::default(),
+        }
+    }
+
+    pub fn stop(mut self, offset: Scalar, variant: ThemeColorVariant) -> Self {
+        self.stops.push(ThemedGradientStop { offset, variant });
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub enum ThemedImageMaterial {
     #[default]
     Color,
     Image(ImageBoxImage),
     Procedural(ImageBoxProcedural),
+    Shape(ThemedShapeMaterial),
+    Gradient(ThemedGradientMaterial),
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+impl ThemedImageMaterial {
+    pub fn image(id: impl ToString, scaling: ImageBoxImageScaling) -> Self {
+        Self::Image(ImageBoxImage {
+            id: id.to_string(),
+            scaling,
+            ..Default::default()
+        })
+    }
+
+    pub fn tiled(id: impl ToString, tile: ImageBoxTile) -> Self {
+        Self::image(id, ImageBoxImageScaling::Tile(tile))
+    }
+
+    /// Nine-slice image, with edges and middle part either stretched or repeated.
+    pub fn nine_slice(id: impl ToString, frame: impl Into<ImageBoxFrame>, repeat: bool) -> Self {
+        let frame = frame.into();
+        let scaling = if repeat {
+            ImageBoxImageScaling::FrameRepeat(frame)
+        } else {
+            ImageBoxImageScaling::Frame(frame)
+        };
+        Self::image(id, scaling)
+    }
+
+    pub fn fitted(id: impl ToString, fit: impl Into<ImageBoxFit>) -> Self {
+        Self::image(id, ImageBoxImageScaling::Fit(fit.into()))
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemedTextMaterial {
     #[serde(default)]
     pub horizontal_align: TextBoxHorizontalAlign,
@@ -162,9 +280,16 @@ pub struct ThemedTextMaterial {
     pub direction: TextBoxDirection,
     #[serde(default)]
     pub font: TextBoxFont,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<ThemedGradientMaterial>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shadows: Vec<Shadow>,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemedButtonMaterial {
     #[serde(default)]
     pub default: ThemedImageMaterial,
@@ -174,7 +299,8 @@ pub struct ThemedButtonMaterial {
     pub trigger: ThemedImageMaterial,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemedSwitchMaterial {
     #[serde(default)]
     pub on: ThemedImageMaterial,
@@ -182,7 +308,8 @@ pub struct ThemedSwitchMaterial {
     pub off: ThemedImageMaterial,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 pub struct ThemedSliderMaterial {
     #[serde(default)]
     pub background: ThemedImageMaterial,
@@ -190,9 +317,11 @@ pub struct ThemedSliderMaterial {
     pub filling: ThemedImageMaterial,
 }
 
-#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(PropsData, Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "raui_core::schemars")]
 #[props_data(raui_core::props::PropsData)]
 #[prefab(raui_core::Prefab)]
+#[prefab_schema]
 pub struct ThemeProps {
     #[serde(default)]
     pub active_colors: ThemeColorsBundle,
@@ -219,6 +348,9 @@ pub struct ThemeProps {
     #[serde(default)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub modal_shadow_variants: HashMap<String, Color>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub shadow_variants: HashMap<String, Vec<Shadow>>,
 }
 
 impl ThemeProps {
@@ -266,6 +398,11 @@ impl ThemeProps {
         self.modal_shadow_variants.insert(id.to_string(), color);
         self
     }
+
+    pub fn shadow_variant(mut self, id: impl ToString, shadows: Vec<Shadow>) -> Self {
+        self.shadow_variants.insert(id.to_string(), shadows);
+        self
+    }
 }
 
 pub fn new_light_theme() -> ThemeProps {
@@ -397,6 +534,7 @@ pub fn new_default_theme_parameterized(
         switch_variants,
         slider_variants,
         modal_shadow_variants,
+        shadow_variants: Default::default(),
     }
 }
 
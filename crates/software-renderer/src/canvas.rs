@@ -0,0 +1,142 @@
+use crate::Error;
+use raui_core::widget::{unit::BlendMode, utils::Color};
+use std::{fs::File, io::BufWriter, path::Path};
+
+/// RGBA pixels buffer, storing colors with premultiplied alpha.
+#[derive(Debug, Clone)]
+pub struct SoftwareCanvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl SoftwareCanvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, Color::transparent())
+    }
+
+    pub fn filled(width: usize, height: usize, color: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![premultiply(color); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Color of pixel, with alpha not premultiplied.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let [r, g, b, a] = self.pixels[y * self.width + x];
+        if a > 0.0 {
+            Some(Color {
+                r: r / a,
+                g: g / a,
+                b: b / a,
+                a,
+            })
+        } else {
+            Some(Color::transparent())
+        }
+    }
+
+    /// Pixels as tightly packed RGBA bytes, with alpha not premultiplied.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.pixels.len() * 4);
+        for [r, g, b, a] in self.pixels.iter().copied() {
+            let a = a.clamp(0.0, 1.0);
+            let [r, g, b] = if a > 0.0 {
+                [r / a, g / a, b / a]
+            } else {
+                [0.0; 3]
+            };
+            result.extend([r, g, b, a].map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8));
+        }
+        result
+    }
+
+    pub fn encode_png(&self) -> Result<Vec<u8>, Error> {
+        let mut result = vec![];
+        self.write_png(&mut result)?;
+        Ok(result)
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let file = File::create(path).map_err(Error::Io)?;
+        self.write_png(BufWriter::new(file))
+    }
+
+    fn write_png(&self, writer: impl std::io::Write) -> Result<(), Error> {
+        let mut encoder = png::Encoder::new(writer, self.width as _, self.height as _);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.to_rgba8()))
+            .map_err(Error::PngEncoding)
+    }
+
+    pub(crate) fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub(crate) fn blend(&mut self, index: usize, source: [f32; 4], mode: BlendMode) {
+        let target = &mut self.pixels[index];
+        let [sr, sg, sb, sa] = source;
+        let [tr, tg, tb, ta] = *target;
+        *target = match mode {
+            BlendMode::Normal => [
+                sr + tr * (1.0 - sa),
+                sg + tg * (1.0 - sa),
+                sb + tb * (1.0 - sa),
+                sa + ta * (1.0 - sa),
+            ],
+            BlendMode::Additive => [
+                (sr + tr).min(1.0),
+                (sg + tg).min(1.0),
+                (sb + tb).min(1.0),
+                (sa + ta).min(1.0),
+            ],
+            BlendMode::Multiply => {
+                let multiply = |s: f32, t: f32| s * t + s * (1.0 - ta) + t * (1.0 - sa);
+                [
+                    multiply(sr, tr),
+                    multiply(sg, tg),
+                    multiply(sb, tb),
+                    sa + ta - sa * ta,
+                ]
+            }
+            BlendMode::Screen => [
+                sr + tr - sr * tr,
+                sg + tg - sg * tg,
+                sb + tb - sb * tb,
+                sa + ta - sa * ta,
+            ],
+        };
+    }
+
+    /// Composites other canvas of the same size on top of this one.
+    pub(crate) fn composite(&mut self, other: &Self, opacity: f32, mode: BlendMode) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        for index in 0..self.pixels.len().min(other.pixels.len()) {
+            let source = other.pixels[index].map(|v| v * opacity);
+            if source[3] > 0.0 {
+                self.blend(index, source, mode);
+            }
+        }
+    }
+}
+
+pub(crate) fn premultiply(color: Color) -> [f32; 4] {
+    let a = color.a.clamp(0.0, 1.0);
+    [color.r * a, color.g * a, color.b * a, a]
+}
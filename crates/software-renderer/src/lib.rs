@@ -0,0 +1,311 @@
+mod canvas;
+mod raster;
+mod resources;
+
+pub use crate::{
+    canvas::SoftwareCanvas,
+    resources::{SoftwareImage, SoftwareResources, SoftwareTextMeasurementsEngine},
+};
+
+use bytemuck::{Pod, Zeroable};
+use raui_core::{
+    layout::{CoordsMapping, Layout},
+    renderer::Renderer,
+    widget::{unit::WidgetUnit, utils::Color},
+};
+use raui_tesselate_renderer::{TesselateBatch, TesselateRenderer, TesselateVertex};
+use spitfire_core::VertexStream;
+use spitfire_fontdue::{TextRenderer, TextVertex};
+
+#[derive(Debug)]
+pub enum Error {
+    Tesselation(raui_tesselate_renderer::Error),
+    InvalidImageData {
+        width: usize,
+        height: usize,
+        bytes: usize,
+    },
+    InvalidFontData(String),
+    PngDecoding(png::DecodingError),
+    PngEncoding(png::EncodingError),
+    Io(std::io::Error),
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub struct SoftwareVertex {
+    pub position: [f32; 2],
+    pub tex_coord: [f32; 3],
+    pub color: [f32; 4],
+}
+
+impl Default for SoftwareVertex {
+    fn default() -> Self {
+        Self {
+            position: Default::default(),
+            tex_coord: Default::default(),
+            color: [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+}
+
+impl TesselateVertex for SoftwareVertex {
+    const CAN_MULTIPLY_ALPHA: bool = true;
+    const CAN_CLIP: bool = true;
+
+    fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: [f32; 4]) {
+        self.position = position;
+        self.tex_coord = tex_coord;
+        self.color = color;
+    }
+
+    fn transform(&mut self, matrix: vek::Mat4<f32>) {
+        let result = matrix.mul_point(vek::Vec3 {
+            x: self.position[0],
+            y: self.position[1],
+            z: 0.0,
+        });
+        self.position[0] = result.x;
+        self.position[1] = result.y;
+    }
+
+    fn multiply_alpha(&mut self, factor: f32) {
+        self.color[3] *= factor;
+    }
+
+    fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    fn lerp(&self, other: &Self, factor: f32) -> Self {
+        fn lerp<const N: usize>(from: [f32; N], to: [f32; N], factor: f32) -> [f32; N] {
+            std::array::from_fn(|index| from[index] + (to[index] - from[index]) * factor)
+        }
+
+        Self {
+            position: lerp(self.position, other.position, factor),
+            tex_coord: lerp(self.tex_coord, other.tex_coord, factor),
+            color: lerp(self.color, other.color, factor),
+        }
+    }
+}
+
+impl TextVertex<Color> for SoftwareVertex {
+    fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: Color) {
+        self.position = position;
+        self.tex_coord = tex_coord;
+        self.color = [color.r, color.g, color.b, color.a];
+    }
+}
+
+/// Renders widget tree on CPU into [`SoftwareCanvas`] of given size in real pixels, without
+/// any graphics device - useful for screenshots and visual regression tests.
+///
+/// Geometry comes from [`TesselateRenderer`], so output matches GPU backends except procedural
+/// meshes, which need custom shaders and are skipped. Edges are not anti-aliased to keep output
+/// stable across platforms.
+pub struct SoftwareRenderer<'a> {
+    pub resources: &'a SoftwareResources,
+    pub width: usize,
+    pub height: usize,
+    pub clear_color: Color,
+}
+
+impl<'a> SoftwareRenderer<'a> {
+    pub fn new(resources: &'a SoftwareResources, width: usize, height: usize) -> Self {
+        Self {
+            resources,
+            width,
+            height,
+            clear_color: Color::transparent(),
+        }
+    }
+
+    pub fn clear_color(mut self, color: Color) -> Self {
+        self.clear_color = color;
+        self
+    }
+}
+
+impl Renderer<SoftwareCanvas, Error> for SoftwareRenderer<'_> {
+    fn render(
+        &mut self,
+        tree: &WidgetUnit,
+        mapping: &CoordsMapping,
+        layout: &Layout,
+    ) -> Result<SoftwareCanvas, Error> {
+        let mut stream = VertexStream::<SoftwareVertex, TesselateBatch>::default();
+        let mut text_renderer = TextRenderer::new(1024, 1024);
+        TesselateRenderer::new(
+            self.resources,
+            &mut (),
+            &mut stream,
+            &mut text_renderer,
+            None,
+        )
+        .render(tree, mapping, layout)
+        .map_err(Error::Tesselation)?;
+        let canvas = raster::Rasterizer::new(
+            self.resources,
+            text_renderer.image(),
+            text_renderer.atlas_size(),
+            self.width,
+            self.height,
+            self.clear_color,
+        )
+        .rasterize(&stream);
+        Ok(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raui_core::{
+        application::Application,
+        layout::default_layout_engine::DefaultLayoutEngine,
+        make_widget,
+        widget::{
+            component::{
+                WidgetComponent,
+                containers::content_box::{ContentBoxProps, content_box},
+                image_box::{ImageBoxProps, image_box},
+            },
+            node::WidgetNode,
+            unit::{
+                BlendMode, UnitLayer,
+                content::{ContentBoxClipShape, ContentBoxItemLayout},
+                image::{ImageBoxColor, ImageBoxImage, ImageBoxMaterial},
+            },
+            utils::Rect,
+        },
+    };
+
+    fn render(resources: &SoftwareResources, tree: WidgetNode) -> SoftwareCanvas {
+        let mut application = Application::default();
+        application.setup(raui_core::widget::setup);
+        application.apply(tree);
+        application.forced_process();
+        let mapping = CoordsMapping::new(Rect {
+            left: 0.0,
+            right: 10.0,
+            top: 0.0,
+            bottom: 10.0,
+        });
+        application
+            .layout(&mapping, &mut DefaultLayoutEngine::<()>::default())
+            .unwrap();
+        application
+            .render(&mapping, &mut SoftwareRenderer::new(resources, 10, 10))
+            .unwrap()
+    }
+
+    fn color_box(color: Color) -> WidgetComponent {
+        make_widget!(image_box).with_props(ImageBoxProps {
+            material: ImageBoxMaterial::Color(ImageBoxColor {
+                color,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    fn assert_color(canvas: &SoftwareCanvas, x: usize, y: usize, expected: [f32; 4]) {
+        let color = canvas.pixel(x, y).unwrap();
+        let color = [color.r, color.g, color.b, color.a];
+        for (value, expected) in color.into_iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 0.01,
+                "pixel at {x}x{y}: {color:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_color() {
+        let resources = SoftwareResources::default();
+        let canvas = render(
+            &resources,
+            color_box(Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            })
+            .into(),
+        );
+        for y in 0..10 {
+            for x in 0..10 {
+                assert_color(&canvas, x, y, [1.0, 0.0, 0.0, 1.0]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_image() {
+        let mut resources = SoftwareResources::default();
+        resources.add_image(
+            "checker",
+            SoftwareImage::new(2, 1, vec![0, 0, 255, 255, 0, 255, 0, 255]).unwrap(),
+        );
+        let tree = make_widget!(image_box)
+            .with_props(ImageBoxProps {
+                material: ImageBoxMaterial::Image(ImageBoxImage {
+                    id: "checker".to_owned(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .into();
+        let canvas = render(&resources, tree);
+        assert_color(&canvas, 0, 5, [0.0, 0.0, 1.0, 1.0]);
+        assert_color(&canvas, 9, 5, [0.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_layer_and_clip_mask() {
+        let resources = SoftwareResources::default();
+        let tree = make_widget!(content_box)
+            .with_props(ContentBoxProps {
+                clipping: true,
+                clip_shape: ContentBoxClipShape::Circle,
+                layer: UnitLayer {
+                    opacity: 0.5,
+                    blend: BlendMode::Normal,
+                },
+                ..Default::default()
+            })
+            .listed_slot(
+                color_box(Color {
+                    r: 0.0,
+                    g: 1.0,
+                    b: 0.0,
+                    a: 1.0,
+                })
+                .with_props(ContentBoxItemLayout::default()),
+            )
+            .into();
+        let canvas = render(&resources, tree);
+        assert_color(&canvas, 0, 0, [0.0, 0.0, 0.0, 0.0]);
+        assert_color(&canvas, 9, 9, [0.0, 0.0, 0.0, 0.0]);
+        assert_color(&canvas, 5, 5, [0.0, 1.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_png() {
+        let canvas = SoftwareCanvas::filled(
+            3,
+            2,
+            Color {
+                r: 1.0,
+                g: 0.5,
+                b: 0.0,
+                a: 1.0,
+            },
+        );
+        let image = SoftwareImage::decode_png(&canvas.encode_png().unwrap()).unwrap();
+        assert_eq!(image.width(), 3);
+        assert_eq!(image.height(), 2);
+        assert_eq!(image.pixels(), canvas.to_rgba8().as_slice());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    SoftwareVertex,
+    canvas::{SoftwareCanvas, premultiply},
+    resources::{SoftwareImage, SoftwareResources, bilinear},
+};
+use raui_core::widget::{unit::BlendMode, utils::Color};
+use raui_tesselate_renderer::TesselateBatch;
+use spitfire_core::{Triangle, VertexStream};
+
+/// Pixel area in `[left, top, right, bottom)` form.
+type Scissor = [usize; 4];
+
+enum Paint<'a> {
+    Color,
+    Image(Option<&'a crate::SoftwareImage>),
+    Text,
+}
+
+struct Layer {
+    canvas: SoftwareCanvas,
+    opacity: f32,
+    blend: BlendMode,
+}
+
+pub(crate) struct Rasterizer<'a> {
+    resources: &'a SoftwareResources,
+    glyphs: &'a [u8],
+    glyphs_size: [usize; 3],
+    canvas: SoftwareCanvas,
+    layers: Vec<Layer>,
+    scissors: Vec<Scissor>,
+    masks: Vec<Vec<f32>>,
+}
+
+impl<'a> Rasterizer<'a> {
+    pub fn new(
+        resources: &'a SoftwareResources,
+        glyphs: &'a [u8],
+        glyphs_size: [usize; 3],
+        width: usize,
+        height: usize,
+        clear_color: Color,
+    ) -> Self {
+        Self {
+            resources,
+            glyphs,
+            glyphs_size,
+            canvas: SoftwareCanvas::filled(width, height, clear_color),
+            layers: Default::default(),
+            scissors: Default::default(),
+            masks: Default::default(),
+        }
+    }
+
+    pub fn rasterize(
+        mut self,
+        stream: &VertexStream<SoftwareVertex, TesselateBatch>,
+    ) -> SoftwareCanvas {
+        let vertices = stream.vertices();
+        for (batch, range) in stream.batches() {
+            let triangles = stream.triangles().get(range.clone()).unwrap_or_default();
+            match batch {
+                TesselateBatch::Color => self.paint(vertices, triangles, Paint::Color),
+                TesselateBatch::Image { id } => {
+                    let image = self.resources.image(id);
+                    self.paint(vertices, triangles, Paint::Image(image));
+                }
+                TesselateBatch::Text => self.paint(vertices, triangles, Paint::Text),
+                // Procedural meshes need custom shaders and debug wireframes are not filled,
+                // so neither of them can be represented here.
+                TesselateBatch::Procedural { .. } | TesselateBatch::Debug => {}
+                TesselateBatch::ClipPush { x, y, w, h } => {
+                    let [width, height] = self.size();
+                    let left = x.round().clamp(0.0, width as f32) as usize;
+                    let top = y.round().clamp(0.0, height as f32) as usize;
+                    let right = (x + w).round().clamp(0.0, width as f32) as usize;
+                    let bottom = (y + h).round().clamp(0.0, height as f32) as usize;
+                    let [l, t, r, b] = self.scissor();
+                    self.scissors.push([
+                        left.max(l),
+                        top.max(t),
+                        right.min(r).max(left.max(l)),
+                        bottom.min(b).max(top.max(t)),
+                    ]);
+                }
+                TesselateBatch::ClipPop => {
+                    self.scissors.pop();
+                }
+                TesselateBatch::ClipMaskPush { image } => {
+                    let image = image.as_deref().and_then(|id| self.resources.image(id));
+                    self.push_mask(vertices, triangles, image);
+                }
+                TesselateBatch::ClipMaskPop { .. } => {
+                    self.masks.pop();
+                }
+                TesselateBatch::LayerPush { opacity, blend } => {
+                    let [width, height] = self.size();
+                    self.layers.push(Layer {
+                        canvas: SoftwareCanvas::new(width, height),
+                        opacity: *opacity,
+                        blend: *blend,
+                    });
+                }
+                TesselateBatch::LayerPop => self.pop_layer(),
+            }
+        }
+        while !self.layers.is_empty() {
+            self.pop_layer();
+        }
+        self.canvas
+    }
+
+    fn size(&self) -> [usize; 2] {
+        [self.canvas.width(), self.canvas.height()]
+    }
+
+    fn scissor(&self) -> Scissor {
+        self.scissors
+            .last()
+            .copied()
+            .unwrap_or([0, 0, self.canvas.width(), self.canvas.height()])
+    }
+
+    fn target(&mut self) -> &mut SoftwareCanvas {
+        match self.layers.last_mut() {
+            Some(layer) => &mut layer.canvas,
+            None => &mut self.canvas,
+        }
+    }
+
+    fn pop_layer(&mut self) {
+        if let Some(layer) = self.layers.pop() {
+            let opacity = layer.opacity;
+            let blend = layer.blend;
+            self.target().composite(&layer.canvas, opacity, blend);
+        }
+    }
+
+    fn sample_glyph(&self, tex_coord: [f32; 3]) -> f32 {
+        let [width, height, depth] = self.glyphs_size;
+        if width == 0 || height == 0 || depth == 0 {
+            return 0.0;
+        }
+        let page = (tex_coord[2].max(0.0) as usize).min(depth - 1);
+        let offset = page * width * height;
+        bilinear(width, height, tex_coord[0], tex_coord[1], |x, y| {
+            let value = self.glyphs[offset + y * width + x] as f32 / 255.0;
+            [value; 4]
+        })[0]
+    }
+
+    fn paint(&mut self, vertices: &[SoftwareVertex], triangles: &[Triangle], paint: Paint) {
+        let scissor = self.scissor();
+        let mut fragments = vec![];
+        for triangle in triangles {
+            rasterize_triangle(vertices, triangle, scissor, |x, y, vertex| {
+                let color = premultiply(Color {
+                    r: vertex.color[0],
+                    g: vertex.color[1],
+                    b: vertex.color[2],
+                    a: vertex.color[3],
+                });
+                let source = match &paint {
+                    Paint::Color | Paint::Image(None) => color,
+                    Paint::Image(Some(image)) => {
+                        let texel = image.sample(vertex.tex_coord[0], vertex.tex_coord[1]);
+                        std::array::from_fn(|index| texel[index] * color[index])
+                    }
+                    Paint::Text => {
+                        let coverage = self.sample_glyph(vertex.tex_coord);
+                        color.map(|value| value * coverage)
+                    }
+                };
+                fragments.push((x, y, source));
+            });
+        }
+        let mask = self.masks.pop();
+        let target = self.target();
+        for (x, y, mut source) in fragments {
+            let index = target.index(x, y);
+            if let Some(mask) = mask.as_ref() {
+                let coverage = mask[index];
+                source = source.map(|value| value * coverage);
+            }
+            if source[3] > 0.0 {
+                target.blend(index, source, BlendMode::Normal);
+            }
+        }
+        if let Some(mask) = mask {
+            self.masks.push(mask);
+        }
+    }
+
+    fn push_mask(
+        &mut self,
+        vertices: &[SoftwareVertex],
+        triangles: &[Triangle],
+        image: Option<&SoftwareImage>,
+    ) {
+        let [width, height] = self.size();
+        let scissor = [0, 0, width, height];
+        let mut mask = vec![0.0f32; width * height];
+        for triangle in triangles {
+            rasterize_triangle(vertices, triangle, scissor, |x, y, vertex| {
+                let coverage = match image {
+                    Some(image) => image.sample(vertex.tex_coord[0], vertex.tex_coord[1])[3],
+                    None => 1.0,
+                };
+                let value = &mut mask[y * width + x];
+                *value = value.max(coverage);
+            });
+        }
+        if let Some(previous) = self.masks.last() {
+            for (value, previous) in mask.iter_mut().zip(previous) {
+                *value *= previous;
+            }
+        }
+        self.masks.push(mask);
+    }
+}
+
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+// Pixels lying exactly on shared edge belong to only one of triangles sharing it, so
+// semi-transparent meshes do not get darker seams.
+fn is_top_left(a: [f32; 2], b: [f32; 2]) -> bool {
+    let dx = b[0] - a[0];
+    let dy = b[1] - a[1];
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+/// Calls `f` for every pixel inside scissor whose center lies inside triangle, with vertex
+/// attributes interpolated at that center.
+fn rasterize_triangle(
+    vertices: &[SoftwareVertex],
+    triangle: &Triangle,
+    scissor: Scissor,
+    mut f: impl FnMut(usize, usize, SoftwareVertex),
+) {
+    let (Some(a), Some(mut b), Some(mut c)) = (
+        vertices.get(triangle.a as usize),
+        vertices.get(triangle.b as usize),
+        vertices.get(triangle.c as usize),
+    ) else {
+        return;
+    };
+    let mut area = edge(a.position, b.position, c.position);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    if area < 0.0 {
+        std::mem::swap(&mut b, &mut c);
+        area = -area;
+    }
+    let [left, top, right, bottom] = scissor;
+    let min_x = a.position[0].min(b.position[0]).min(c.position[0]);
+    let min_y = a.position[1].min(b.position[1]).min(c.position[1]);
+    let max_x = a.position[0].max(b.position[0]).max(c.position[0]);
+    let max_y = a.position[1].max(b.position[1]).max(c.position[1]);
+    let from_x = (min_x.floor().max(0.0) as usize).max(left);
+    let from_y = (min_y.floor().max(0.0) as usize).max(top);
+    let to_x = (max_x.ceil().max(0.0) as usize).min(right);
+    let to_y = (max_y.ceil().max(0.0) as usize).min(bottom);
+    let top_left = [
+        is_top_left(b.position, c.position),
+        is_top_left(c.position, a.position),
+        is_top_left(a.position, b.position),
+    ];
+    for y in from_y..to_y {
+        for x in from_x..to_x {
+            let point = [x as f32 + 0.5, y as f32 + 0.5];
+            let weights = [
+                edge(b.position, c.position, point),
+                edge(c.position, a.position, point),
+                edge(a.position, b.position, point),
+            ];
+            let inside = weights
+                .iter()
+                .zip(top_left)
+                .all(|(weight, top_left)| *weight > 0.0 || (*weight == 0.0 && top_left));
+            if !inside {
+                continue;
+            }
+            let [wa, wb, wc] = weights.map(|weight| weight / area);
+            let vertex = SoftwareVertex {
+                position: point,
+                tex_coord: std::array::from_fn(|index| {
+                    a.tex_coord[index] * wa + b.tex_coord[index] * wb + c.tex_coord[index] * wc
+                }),
+                color: std::array::from_fn(|index| {
+                    a.color[index] * wa + b.color[index] * wb + c.color[index] * wc
+                }),
+            };
+            f(x, y, vertex);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::Error;
+use fontdue::{
+    Font, FontSettings,
+    layout::{CoordinateSystem, HorizontalAlign, Layout, LayoutSettings, TextStyle, VerticalAlign},
+};
+use raui_core::{
+    layout::{CoordsMapping, default_layout_engine::TextMeasurementEngine},
+    widget::{
+        unit::text::{TextBox, TextBoxHorizontalAlign, TextBoxSizeValue, TextBoxVerticalAlign},
+        utils::{Rect, Vec2},
+    },
+};
+use raui_tesselate_renderer::TesselateResourceProvider;
+use spitfire_fontdue::TextRenderer;
+use std::{collections::HashMap, io::Cursor};
+
+/// Image with RGBA pixels stored as bytes, with alpha not premultiplied.
+#[derive(Debug, Clone)]
+pub struct SoftwareImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl SoftwareImage {
+    pub fn new(width: usize, height: usize, pixels: Vec<u8>) -> Result<Self, Error> {
+        if pixels.len() != width * height * 4 {
+            return Err(Error::InvalidImageData {
+                width,
+                height,
+                bytes: pixels.len(),
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn decode_png(bytes: &[u8]) -> Result<Self, Error> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(Error::PngDecoding)?;
+        let mut buffer = vec![0; reader.output_buffer_size().unwrap_or_default()];
+        let info = reader.next_frame(&mut buffer).map_err(Error::PngDecoding)?;
+        buffer.truncate(info.buffer_size());
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|c| [c[0], c[1], c[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|c| [c[0], c[0], c[0], c[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|v| [*v, *v, *v, 255]).collect(),
+            png::ColorType::Indexed => unreachable!("Indexed colors are expanded by decoder"),
+        };
+        Self::new(info.width as _, info.height as _, pixels)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Bilinearly filtered color with premultiplied alpha at normalized coordinates, clamped to
+    /// image edges.
+    pub(crate) fn sample(&self, u: f32, v: f32) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 {
+            return [0.0; 4];
+        }
+        bilinear(self.width, self.height, u, v, |x, y| {
+            let index = (y * self.width + x) * 4;
+            let [r, g, b, a] = [0, 1, 2, 3].map(|c| self.pixels[index + c] as f32 / 255.0);
+            [r * a, g * a, b * a, a]
+        })
+    }
+}
+
+pub(crate) fn bilinear(
+    width: usize,
+    height: usize,
+    u: f32,
+    v: f32,
+    fetch: impl Fn(usize, usize) -> [f32; 4],
+) -> [f32; 4] {
+    let x = (u * width as f32 - 0.5).clamp(0.0, (width - 1) as f32);
+    let y = (v * height as f32 - 0.5).clamp(0.0, (height - 1) as f32);
+    let x0 = x.floor() as usize;
+    let y0 = y.floor() as usize;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let fx = x - x0 as f32;
+    let fy = y - y0 as f32;
+    let a = fetch(x0, y0);
+    let b = fetch(x1, y0);
+    let c = fetch(x0, y1);
+    let d = fetch(x1, y1);
+    std::array::from_fn(|i| {
+        let top = a[i] + (b[i] - a[i]) * fx;
+        let bottom = c[i] + (d[i] - c[i]) * fx;
+        top + (bottom - top) * fy
+    })
+}
+
+/// Images and fonts that software renderer can draw with.
+#[derive(Default)]
+pub struct SoftwareResources {
+    images: HashMap<String, SoftwareImage>,
+    fonts: Vec<Font>,
+    font_ids: HashMap<String, usize>,
+}
+
+impl SoftwareResources {
+    pub fn add_image(&mut self, id: impl ToString, image: SoftwareImage) {
+        self.images.insert(id.to_string(), image);
+    }
+
+    pub fn image(&self, id: &str) -> Option<&SoftwareImage> {
+        self.images.get(id)
+    }
+
+    pub fn add_font(&mut self, id: impl ToString, font: Font) {
+        let id = id.to_string();
+        if let Some(index) = self.font_ids.get(&id) {
+            self.fonts[*index] = font;
+        } else {
+            self.font_ids.insert(id, self.fonts.len());
+            self.fonts.push(font);
+        }
+    }
+
+    pub fn load_font(&mut self, id: impl ToString, bytes: &[u8]) -> Result<(), Error> {
+        let font = Font::from_bytes(bytes, FontSettings::default())
+            .map_err(|error| Error::InvalidFontData(error.to_owned()))?;
+        self.add_font(id, font);
+        Ok(())
+    }
+}
+
+impl TesselateResourceProvider for SoftwareResources {
+    fn image_id_and_uv_and_size_by_atlas_id(&self, id: &str) -> Option<(String, Rect, Vec2)> {
+        let image = self.images.get(id)?;
+        Some((
+            id.to_owned(),
+            Rect {
+                left: 0.0,
+                right: 1.0,
+                top: 0.0,
+                bottom: 1.0,
+            },
+            Vec2 {
+                x: image.width as _,
+                y: image.height as _,
+            },
+        ))
+    }
+
+    fn fonts(&self) -> &[Font] {
+        &self.fonts
+    }
+
+    fn font_index_by_id(&self, id: &str) -> Option<usize> {
+        self.font_ids.get(id).copied()
+    }
+}
+
+/// Measures text boxes with fonts of software resources, to be used by layout engine.
+pub struct SoftwareTextMeasurementsEngine<'a> {
+    pub resources: &'a SoftwareResources,
+}
+
+impl TextMeasurementEngine for SoftwareTextMeasurementsEngine<'_> {
+    fn measure_text(
+        &self,
+        size_available: Vec2,
+        mapping: &CoordsMapping,
+        unit: &TextBox,
+    ) -> Option<Rect> {
+        let font_index = self.resources.font_index_by_id(&unit.font.name)?;
+        let text = TextStyle::with_user_data(
+            &unit.text,
+            unit.font.size * mapping.scalar_scale(false),
+            font_index,
+            unit.color,
+        );
+        let max_width = match unit.width {
+            TextBoxSizeValue::Content => None,
+            TextBoxSizeValue::Fill => Some(size_available.x),
+            TextBoxSizeValue::Exact(v) => Some(v),
+        };
+        let max_height = match unit.height {
+            TextBoxSizeValue::Content => None,
+            TextBoxSizeValue::Fill => Some(size_available.y),
+            TextBoxSizeValue::Exact(v) => Some(v),
+        };
+        let mut layout = Layout::new(CoordinateSystem::PositiveYDown);
+        layout.reset(&LayoutSettings {
+            max_width,
+            max_height,
+            horizontal_align: match unit.horizontal_align {
+                TextBoxHorizontalAlign::Left => HorizontalAlign::Left,
+                TextBoxHorizontalAlign::Center => HorizontalAlign::Center,
+                TextBoxHorizontalAlign::Right => HorizontalAlign::Right,
+            },
+            vertical_align: match unit.vertical_align {
+                TextBoxVerticalAlign::Top => VerticalAlign::Top,
+                TextBoxVerticalAlign::Middle => VerticalAlign::Middle,
+                TextBoxVerticalAlign::Bottom => VerticalAlign::Bottom,
+            },
+            ..Default::default()
+        });
+        layout.append(self.resources.fonts(), &text);
+        let aabb = TextRenderer::measure(&layout, self.resources.fonts(), false);
+        if aabb.iter().all(|v| v.is_finite()) {
+            Some(Rect {
+                left: aabb[0],
+                top: aabb[1],
+                right: aabb[2],
+                bottom: aabb[3],
+            })
+        } else {
+            None
+        }
+    }
+}
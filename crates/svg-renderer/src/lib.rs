@@ -0,0 +1,902 @@
+use raui_core::{
+    Scalar,
+    layout::{CoordsMapping, Layout},
+    renderer::Renderer,
+    widget::{
+        WidgetId,
+        unit::{
+            BlendMode, UnitLayer, WidgetUnit,
+            content::ContentBoxClipShape,
+            image::{
+                ImageBox, ImageBoxAspectRatio, ImageBoxFitMode, ImageBoxImage,
+                ImageBoxImageScaling, ImageBoxMaterial, ImageBoxShape, ImageBoxShapeCorners,
+            },
+            text::{TextBox, TextBoxHorizontalAlign, TextBoxVerticalAlign},
+        },
+        utils::{Color, Gradient, GradientKind, Rect, Transform, Vec2, lerp},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    WidgetHasNoLayout(WidgetId),
+}
+
+/// Renders widget tree with its computed layout into SVG document.
+///
+/// Images are referenced by their ids in `href` attribute instead of being embedded, and text is
+/// emitted as `text` elements laid out by SVG viewer, so line wrapping is not reproduced.
+/// Widget shadows, procedural meshes and image scaling modes other than stretch and fit have no
+/// SVG representation and are skipped or stretched.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+pub struct SvgRenderer {
+    #[serde(default)]
+    pub pretty: bool,
+    /// Adds `data-widget` attribute with widget id to elements produced for widgets.
+    #[serde(default)]
+    pub widget_ids: bool,
+}
+
+impl Renderer<String, Error> for SvgRenderer {
+    fn render(
+        &mut self,
+        tree: &WidgetUnit,
+        mapping: &CoordsMapping,
+        layout: &Layout,
+    ) -> Result<String, Error> {
+        let mut context = SvgContext {
+            renderer: *self,
+            mapping,
+            layout,
+            defs: Default::default(),
+        };
+        let content = context.render_node(tree, false)?;
+        let area = mapping.virtual_to_real_rect(layout.ui_space, false);
+        let mut root = Element::new("svg")
+            .attribute("xmlns", "http://www.w3.org/2000/svg")
+            .attribute("width", number(area.width()))
+            .attribute("height", number(area.height()))
+            .attribute(
+                "viewBox",
+                format!(
+                    "{} {} {} {}",
+                    number(area.left),
+                    number(area.top),
+                    number(area.width()),
+                    number(area.height())
+                ),
+            );
+        if !context.defs.is_empty() {
+            root = root.child(Element::new("defs").children(context.defs));
+        }
+        if let Some(content) = content {
+            root = root.child(content);
+        }
+        let mut result = String::new();
+        root.write(&mut result, self.pretty, 0);
+        Ok(result)
+    }
+}
+
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+struct Element {
+    tag: &'static str,
+    attributes: Vec<(&'static str, String)>,
+    children: Vec<Node>,
+}
+
+impl Element {
+    fn new(tag: &'static str) -> Self {
+        Self {
+            tag,
+            attributes: Default::default(),
+            children: Default::default(),
+        }
+    }
+
+    fn rename(mut self, tag: &'static str) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    fn attribute(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.attributes.push((name, value.to_string()));
+        self
+    }
+
+    fn maybe_attribute(self, name: &'static str, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.attribute(name, value),
+            None => self,
+        }
+    }
+
+    fn child(mut self, element: Element) -> Self {
+        self.children.push(Node::Element(element));
+        self
+    }
+
+    fn children(mut self, elements: impl IntoIterator<Item = Element>) -> Self {
+        self.children
+            .extend(elements.into_iter().map(Node::Element));
+        self
+    }
+
+    fn text(mut self, text: impl ToString) -> Self {
+        self.children.push(Node::Text(text.to_string()));
+        self
+    }
+
+    fn write(&self, output: &mut String, pretty: bool, depth: usize) {
+        if pretty {
+            output.push_str(&"  ".repeat(depth));
+        }
+        output.push('<');
+        output.push_str(self.tag);
+        for (name, value) in &self.attributes {
+            output.push(' ');
+            output.push_str(name);
+            output.push_str("=\"");
+            output.push_str(&escape(value));
+            output.push('"');
+        }
+        if self.children.is_empty() {
+            output.push_str("/>");
+        } else if self
+            .children
+            .iter()
+            .all(|child| matches!(child, Node::Text(_)))
+        {
+            output.push('>');
+            for child in &self.children {
+                if let Node::Text(text) = child {
+                    output.push_str(&escape(text));
+                }
+            }
+            output.push_str("</");
+            output.push_str(self.tag);
+            output.push('>');
+        } else {
+            output.push('>');
+            for child in &self.children {
+                if pretty {
+                    output.push('\n');
+                }
+                match child {
+                    Node::Element(element) => element.write(output, pretty, depth + 1),
+                    Node::Text(text) => output.push_str(&escape(text)),
+                }
+            }
+            if pretty {
+                output.push('\n');
+                output.push_str(&"  ".repeat(depth));
+            }
+            output.push_str("</");
+            output.push_str(self.tag);
+            output.push('>');
+        }
+    }
+}
+
+/// 2D affine transform in SVG `matrix(a b c d e f)` order.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Affine([Scalar; 6]);
+
+impl Affine {
+    const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    fn translation(x: Scalar, y: Scalar) -> Self {
+        Self([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    fn linear(a: Scalar, b: Scalar, c: Scalar, d: Scalar) -> Self {
+        Self([a, b, c, d, 0.0, 0.0])
+    }
+
+    /// Transform that applies `other` first and then `self`.
+    fn multiply(self, other: Self) -> Self {
+        let [a1, b1, c1, d1, e1, f1] = self.0;
+        let [a2, b2, c2, d2, e2, f2] = other.0;
+        Self([
+            a1 * a2 + c1 * b2,
+            b1 * a2 + d1 * b2,
+            a1 * c2 + c1 * d2,
+            b1 * c2 + d1 * d2,
+            a1 * e2 + c1 * f2 + e1,
+            b1 * e2 + d1 * f2 + f1,
+        ])
+    }
+
+    /// Same composition as tesselated geometry uses, so both renderers place widgets alike.
+    fn from_transform(transform: &Transform, rect: Rect) -> Self {
+        let size = rect.size();
+        let pivot = Vec2 {
+            x: lerp(0.0, size.x, transform.pivot.x),
+            y: lerp(0.0, size.y, transform.pivot.y),
+        };
+        let (sin, cos) = transform.rotation.sin_cos();
+        [
+            Self::translation(rect.left, rect.top),
+            Self::translation(
+                lerp(0.0, size.x, transform.align.x),
+                lerp(0.0, size.y, transform.align.y),
+            ),
+            Self::translation(pivot.x, pivot.y),
+            Self::translation(transform.translation.x, transform.translation.y),
+            Self::linear(cos, sin, -sin, cos),
+            Self::linear(transform.scale.x, 0.0, 0.0, transform.scale.y),
+            Self::linear(1.0, transform.skew.x.tan(), transform.skew.y.tan(), 1.0),
+            Self::translation(-pivot.x, -pivot.y),
+        ]
+        .into_iter()
+        .fold(Self::IDENTITY, Self::multiply)
+    }
+
+    fn to_attribute(self) -> Option<String> {
+        let [a, b, c, d, e, f] = self.0.map(number);
+        if [&a, &b, &c, &d] == ["1", "0", "0", "1"] {
+            if e == "0" && f == "0" {
+                None
+            } else {
+                Some(format!("translate({e} {f})"))
+            }
+        } else {
+            Some(format!("matrix({a} {b} {c} {d} {e} {f})"))
+        }
+    }
+}
+
+struct SvgContext<'a> {
+    renderer: SvgRenderer,
+    mapping: &'a CoordsMapping,
+    layout: &'a Layout,
+    defs: Vec<Element>,
+}
+
+impl SvgContext<'_> {
+    fn item_rect(&self, id: &WidgetId, local: bool) -> Result<Rect, Error> {
+        match self.layout.items.get(id) {
+            Some(item) => Ok(self.mapping.virtual_to_real_rect(item.local_space, local)),
+            None => Err(Error::WidgetHasNoLayout(id.to_owned())),
+        }
+    }
+
+    fn scale(&self) -> Scalar {
+        self.mapping.scalar_scale(false)
+    }
+
+    fn identify(&self, element: Element, id: &WidgetId) -> Element {
+        if self.renderer.widget_ids {
+            element.attribute("data-widget", id)
+        } else {
+            element
+        }
+    }
+
+    /// Adds definition and returns its unique id.
+    fn define(&mut self, prefix: &str, element: Element) -> String {
+        let id = format!("{}-{}", prefix, self.defs.len());
+        self.defs.push(element.attribute("id", &id));
+        id
+    }
+
+    fn render_items<'b>(
+        &mut self,
+        items: impl Iterator<Item = &'b WidgetUnit>,
+    ) -> Result<Vec<Element>, Error> {
+        let mut result = vec![];
+        for item in items {
+            if let Some(element) = self.render_node(item, true)? {
+                result.push(element);
+            }
+        }
+        Ok(result)
+    }
+
+    fn render_node(&mut self, unit: &WidgetUnit, local: bool) -> Result<Option<Element>, Error> {
+        match unit {
+            WidgetUnit::None | WidgetUnit::PortalBox(_) => Ok(None),
+            WidgetUnit::AreaBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let children = self.render_items(std::iter::once(unit.slot.as_ref()))?;
+                let group = Element::new("g")
+                    .maybe_attribute(
+                        "transform",
+                        Affine::translation(rect.left, rect.top).to_attribute(),
+                    )
+                    .children(children);
+                Ok(Some(self.identify(group, &unit.id)))
+            }
+            WidgetUnit::ContentBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let mut items = unit
+                    .items
+                    .iter()
+                    .map(|item| (item.layout.depth, &item.slot))
+                    .collect::<Vec<_>>();
+                items.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+                let children = self.render_items(items.into_iter().map(|(_, slot)| slot))?;
+                let mut group = Element::new("g").maybe_attribute(
+                    "transform",
+                    Affine::from_transform(&unit.transform, rect).to_attribute(),
+                );
+                group = Self::layer(group, &unit.layer);
+                if unit.clipping {
+                    group = self.clip(group, &unit.clip_shape, rect.size());
+                }
+                Ok(Some(self.identify(group.children(children), &unit.id)))
+            }
+            WidgetUnit::FlexBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let children = self.render_items(unit.items.iter().map(|item| &item.slot))?;
+                let group = Element::new("g").maybe_attribute(
+                    "transform",
+                    Affine::from_transform(&unit.transform, rect).to_attribute(),
+                );
+                let group = Self::layer(group, &unit.layer).children(children);
+                Ok(Some(self.identify(group, &unit.id)))
+            }
+            WidgetUnit::GridBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let children = self.render_items(unit.items.iter().map(|item| &item.slot))?;
+                let group = Element::new("g").maybe_attribute(
+                    "transform",
+                    Affine::from_transform(&unit.transform, rect).to_attribute(),
+                );
+                let group = Self::layer(group, &unit.layer).children(children);
+                Ok(Some(self.identify(group, &unit.id)))
+            }
+            WidgetUnit::SizeBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let children = self.render_items(std::iter::once(unit.slot.as_ref()))?;
+                let group = Element::new("g")
+                    .maybe_attribute(
+                        "transform",
+                        Affine::from_transform(&unit.transform, rect).to_attribute(),
+                    )
+                    .children(children);
+                Ok(Some(self.identify(group, &unit.id)))
+            }
+            WidgetUnit::ImageBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let Some(element) = self.image_box(unit, rect.size()) else {
+                    return Ok(None);
+                };
+                let element = element.maybe_attribute(
+                    "transform",
+                    Affine::from_transform(&unit.transform, rect).to_attribute(),
+                );
+                Ok(Some(self.identify(element, &unit.id)))
+            }
+            WidgetUnit::TextBox(unit) => {
+                let rect = self.item_rect(&unit.id, local)?;
+                let element = self.text_box(unit, rect.size()).maybe_attribute(
+                    "transform",
+                    Affine::from_transform(&unit.transform, rect).to_attribute(),
+                );
+                Ok(Some(self.identify(element, &unit.id)))
+            }
+        }
+    }
+
+    fn layer(group: Element, layer: &UnitLayer) -> Element {
+        if layer.is_passthrough() {
+            return group;
+        }
+        let blend = match layer.blend {
+            BlendMode::Normal => None,
+            BlendMode::Additive => Some("mix-blend-mode:plus-lighter"),
+            BlendMode::Multiply => Some("mix-blend-mode:multiply"),
+            BlendMode::Screen => Some("mix-blend-mode:screen"),
+        };
+        group
+            .maybe_attribute(
+                "opacity",
+                (layer.opacity < 1.0).then(|| number(layer.opacity.max(0.0))),
+            )
+            .maybe_attribute("style", blend)
+    }
+
+    fn clip(&mut self, group: Element, shape: &ContentBoxClipShape, size: Vec2) -> Element {
+        let outline = match shape {
+            ContentBoxClipShape::Rectangle => rectangle(size),
+            ContentBoxClipShape::RoundedRectangle { corner_radius } => {
+                rounded_rectangle(size, &self.scaled_corners(corner_radius, size), 0.0)
+            }
+            ContentBoxClipShape::Circle => Element::new("ellipse")
+                .attribute("cx", number(size.x * 0.5))
+                .attribute("cy", number(size.y * 0.5))
+                .attribute("rx", number(size.x * 0.5))
+                .attribute("ry", number(size.y * 0.5)),
+            ContentBoxClipShape::Quad { points } => Element::new("polygon").attribute(
+                "points",
+                points
+                    .iter()
+                    .map(|point| {
+                        format!("{},{}", number(point.x * size.x), number(point.y * size.y))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            ContentBoxClipShape::Mask { id } => {
+                let mask = Element::new("mask")
+                    .attribute("maskUnits", "userSpaceOnUse")
+                    .attribute("x", 0)
+                    .attribute("y", 0)
+                    .attribute("width", number(size.x))
+                    .attribute("height", number(size.y))
+                    .attribute("style", "mask-type:alpha")
+                    .child(
+                        rectangle(size)
+                            .attribute("href", id)
+                            .attribute("preserveAspectRatio", "none")
+                            .rename("image"),
+                    );
+                let id = self.define("mask", mask);
+                return group.attribute("mask", format!("url(#{id})"));
+            }
+        };
+        let id = self.define("clip", Element::new("clipPath").child(outline));
+        group.attribute("clip-path", format!("url(#{id})"))
+    }
+
+    fn scaled_corners(&self, corners: &ImageBoxShapeCorners, size: Vec2) -> ImageBoxShapeCorners {
+        let scale = self.scale();
+        ImageBoxShapeCorners {
+            top_left: corners.top_left * scale,
+            top_right: corners.top_right * scale,
+            bottom_right: corners.bottom_right * scale,
+            bottom_left: corners.bottom_left * scale,
+        }
+        .fit(size)
+    }
+
+    fn image_box(&mut self, unit: &ImageBox, size: Vec2) -> Option<Element> {
+        match &unit.material {
+            ImageBoxMaterial::Color(color) => Some(paint(rectangle(size), "fill", color.color)),
+            ImageBoxMaterial::Gradient(gradient) => {
+                Some(self.gradient(rectangle(size), "fill", gradient))
+            }
+            ImageBoxMaterial::Shape(shape) => Some(self.shape(shape, size)),
+            ImageBoxMaterial::Image(image) => {
+                Some(self.image(image, None, unit.content_keep_aspect_ratio.as_ref(), size))
+            }
+            ImageBoxMaterial::Flipbook(flipbook) => {
+                let (id, region) = flipbook.frame_image()?;
+                let image = ImageBoxImage {
+                    id: id.to_owned(),
+                    source_rect: None,
+                    scaling: flipbook.scaling.clone(),
+                    tint: flipbook.tint,
+                };
+                Some(self.image(
+                    &image,
+                    Some(region),
+                    unit.content_keep_aspect_ratio.as_ref(),
+                    size,
+                ))
+            }
+            ImageBoxMaterial::Procedural(_) => None,
+        }
+    }
+
+    fn shape(&mut self, shape: &ImageBoxShape, size: Vec2) -> Element {
+        let corners = self.scaled_corners(&shape.corner_radius, size);
+        let stroke_width = (shape.stroke_width * self.scale()).max(0.0);
+        if stroke_width <= 0.0 || shape.stroke_color.a <= 0.0 {
+            return paint(rounded_rectangle(size, &corners, 0.0), "fill", shape.fill);
+        }
+        // SVG strokes are centered on outline, so both parts are inset to keep the stroke
+        // inside shape bounds with fill only covering what stroke leaves.
+        let fill = paint(
+            rounded_rectangle(size, &corners, stroke_width),
+            "fill",
+            shape.fill,
+        );
+        let stroke = paint(
+            rounded_rectangle(size, &corners, stroke_width * 0.5),
+            "stroke",
+            shape.stroke_color,
+        )
+        .attribute("fill", "none")
+        .attribute("stroke-width", number(stroke_width));
+        Element::new("g").child(fill).child(stroke)
+    }
+
+    fn image(
+        &mut self,
+        image: &ImageBoxImage,
+        region: Option<Rect>,
+        aspect: Option<&ImageBoxAspectRatio>,
+        size: Vec2,
+    ) -> Element {
+        let preserve_aspect_ratio = match (aspect, &image.scaling) {
+            (Some(aspect), _) => format!(
+                "{} {}",
+                alignment(aspect.horizontal_alignment, aspect.vertical_alignment),
+                if aspect.outside { "slice" } else { "meet" }
+            ),
+            (None, ImageBoxImageScaling::Fit(fit)) => format!(
+                "{} {}",
+                alignment(fit.alignment.x, fit.alignment.y),
+                match fit.mode {
+                    ImageBoxFitMode::Contain => "meet",
+                    ImageBoxFitMode::Cover => "slice",
+                }
+            ),
+            _ => "none".to_owned(),
+        };
+        let mut result = match region {
+            // Region is given in image fraction units, so viewport that shows only that part of
+            // unit-sized image does not need to know image size.
+            Some(region) => Element::new("svg")
+                .attribute("width", number(size.x))
+                .attribute("height", number(size.y))
+                .attribute(
+                    "viewBox",
+                    format!(
+                        "{} {} {} {}",
+                        number(region.left),
+                        number(region.top),
+                        number(region.width()),
+                        number(region.height())
+                    ),
+                )
+                .attribute("preserveAspectRatio", "none")
+                .child(
+                    Element::new("image")
+                        .attribute("width", 1)
+                        .attribute("height", 1)
+                        .attribute("preserveAspectRatio", "none")
+                        .attribute("href", &image.id),
+                ),
+            None => rectangle(size)
+                .rename("image")
+                .attribute("preserveAspectRatio", preserve_aspect_ratio)
+                .attribute("href", &image.id),
+        };
+        let tint = image.tint;
+        if tint.r < 1.0 || tint.g < 1.0 || tint.b < 1.0 {
+            let filter = Element::new("filter").child(
+                Element::new("feColorMatrix")
+                    .attribute("type", "matrix")
+                    .attribute(
+                        "values",
+                        format!(
+                            "{} 0 0 0 0 0 {} 0 0 0 0 0 {} 0 0 0 0 0 {} 0",
+                            number(tint.r),
+                            number(tint.g),
+                            number(tint.b),
+                            number(tint.a)
+                        ),
+                    ),
+            );
+            let id = self.define("tint", filter);
+            result = result.attribute("filter", format!("url(#{id})"));
+        } else if tint.a < 1.0 {
+            result = result.attribute("opacity", number(tint.a.max(0.0)));
+        }
+        result
+    }
+
+    fn text_box(&mut self, unit: &TextBox, size: Vec2) -> Element {
+        let (x, anchor) = match unit.horizontal_align {
+            TextBoxHorizontalAlign::Left => (0.0, None),
+            TextBoxHorizontalAlign::Center => (size.x * 0.5, Some("middle")),
+            TextBoxHorizontalAlign::Right => (size.x, Some("end")),
+        };
+        let (y, baseline) = match unit.vertical_align {
+            TextBoxVerticalAlign::Top => (0.0, "text-before-edge"),
+            TextBoxVerticalAlign::Middle => (size.y * 0.5, "central"),
+            TextBoxVerticalAlign::Bottom => (size.y, "text-after-edge"),
+        };
+        let mut result = Element::new("text")
+            .attribute("x", number(x))
+            .attribute("y", number(y))
+            .attribute("font-family", &unit.font.name)
+            .attribute("font-size", number(unit.font.size * self.scale()))
+            .maybe_attribute("text-anchor", anchor)
+            .attribute("dominant-baseline", baseline);
+        result = match &unit.gradient {
+            Some(gradient) => self.gradient(result, "fill", gradient),
+            None => paint(result, "fill", unit.color),
+        };
+        let lines = unit.text.lines().collect::<Vec<_>>();
+        if lines.len() <= 1 {
+            return result.text(unit.text.trim_end_matches(['\r', '\n']));
+        }
+        // Line height matches what text layout uses by default for most fonts.
+        let first = match unit.vertical_align {
+            TextBoxVerticalAlign::Top => 0.0,
+            TextBoxVerticalAlign::Middle => (lines.len() - 1) as Scalar * -0.6,
+            TextBoxVerticalAlign::Bottom => (lines.len() - 1) as Scalar * -1.2,
+        };
+        result.children(lines.into_iter().enumerate().map(|(index, line)| {
+            Element::new("tspan")
+                .attribute("x", number(x))
+                .attribute(
+                    "dy",
+                    format!("{}em", number(if index == 0 { first } else { 1.2 })),
+                )
+                .text(line)
+        }))
+    }
+
+    fn gradient(
+        &mut self,
+        element: Element,
+        attribute: &'static str,
+        gradient: &Gradient,
+    ) -> Element {
+        let stops = gradient.stops.iter().map(|stop| {
+            Element::new("stop")
+                .attribute("offset", number(stop.offset))
+                .attribute("stop-color", rgb(stop.color))
+                .maybe_attribute(
+                    "stop-opacity",
+                    (stop.color.a < 1.0).then(|| number(stop.color.a.max(0.0))),
+                )
+        });
+        let definition = match gradient.kind {
+            GradientKind::Linear { angle } => {
+                let (y, x) = angle.sin_cos();
+                let extent = (x.abs() + y.abs()) * 0.5;
+                Element::new("linearGradient")
+                    .attribute("x1", number(0.5 - x * extent))
+                    .attribute("y1", number(0.5 - y * extent))
+                    .attribute("x2", number(0.5 + x * extent))
+                    .attribute("y2", number(0.5 + y * extent))
+            }
+            GradientKind::Radial { center, radius } => Element::new("radialGradient")
+                .attribute("cx", 0)
+                .attribute("cy", 0)
+                .attribute("r", 1)
+                .attribute(
+                    "gradientTransform",
+                    format!(
+                        "matrix({} 0 0 {} {} {})",
+                        number(radius.x.abs()),
+                        number(radius.y.abs()),
+                        number(center.x),
+                        number(center.y)
+                    ),
+                ),
+            // SVG has no conic gradients, so average color stands in for them.
+            GradientKind::Conic { .. } => {
+                const SAMPLES: usize = 32;
+                let color = (0..SAMPLES)
+                    .map(|index| gradient.sample(index as Scalar / (SAMPLES - 1) as Scalar))
+                    .fold(Color::transparent(), |result, color| Color {
+                        r: result.r + color.r / SAMPLES as Scalar,
+                        g: result.g + color.g / SAMPLES as Scalar,
+                        b: result.b + color.b / SAMPLES as Scalar,
+                        a: result.a + color.a / SAMPLES as Scalar,
+                    });
+                return paint(element, attribute, color);
+            }
+        };
+        let id = self.define("gradient", definition.children(stops));
+        element.attribute(attribute, format!("url(#{id})"))
+    }
+}
+
+fn rectangle(size: Vec2) -> Element {
+    Element::new("rect")
+        .attribute("width", number(size.x.max(0.0)))
+        .attribute("height", number(size.y.max(0.0)))
+}
+
+fn rounded_rectangle(size: Vec2, corners: &ImageBoxShapeCorners, inset: Scalar) -> Element {
+    let left = inset;
+    let top = inset;
+    let right = (size.x - inset).max(left);
+    let bottom = (size.y - inset).max(top);
+    let radius = |value: Scalar| (value - inset).max(0.0);
+    let top_left = radius(corners.top_left);
+    let top_right = radius(corners.top_right);
+    let bottom_right = radius(corners.bottom_right);
+    let bottom_left = radius(corners.bottom_left);
+    if top_left == top_right && top_left == bottom_right && top_left == bottom_left {
+        return Element::new("rect")
+            .maybe_attribute("x", (left != 0.0).then(|| number(left)))
+            .maybe_attribute("y", (top != 0.0).then(|| number(top)))
+            .attribute("width", number(right - left))
+            .attribute("height", number(bottom - top))
+            .maybe_attribute("rx", (top_left > 0.0).then(|| number(top_left)));
+    }
+    let arc = |radius: Scalar, x: Scalar, y: Scalar| {
+        format!(
+            "A{} {} 0 0 1 {} {}",
+            number(radius),
+            number(radius),
+            number(x),
+            number(y)
+        )
+    };
+    let path = [
+        format!("M{} {}", number(left + top_left), number(top)),
+        format!("H{}", number(right - top_right)),
+        arc(top_right, right, top + top_right),
+        format!("V{}", number(bottom - bottom_right)),
+        arc(bottom_right, right - bottom_right, bottom),
+        format!("H{}", number(left + bottom_left)),
+        arc(bottom_left, left, bottom - bottom_left),
+        format!("V{}", number(top + top_left)),
+        arc(top_left, left + top_left, top),
+        "Z".to_owned(),
+    ];
+    Element::new("path").attribute("d", path.join(" "))
+}
+
+fn paint(element: Element, attribute: &'static str, color: Color) -> Element {
+    let opacity = match attribute {
+        "stroke" => "stroke-opacity",
+        _ => "fill-opacity",
+    };
+    element
+        .attribute(attribute, rgb(color))
+        .maybe_attribute(opacity, (color.a < 1.0).then(|| number(color.a.max(0.0))))
+}
+
+fn rgb(color: Color) -> String {
+    let [r, g, b] =
+        [color.r, color.g, color.b].map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn alignment(horizontal: Scalar, vertical: Scalar) -> String {
+    let name = |value: Scalar| {
+        if value < 1.0 / 3.0 {
+            "Min"
+        } else if value > 2.0 / 3.0 {
+            "Max"
+        } else {
+            "Mid"
+        }
+    };
+    format!("x{}Y{}", name(horizontal), name(vertical))
+}
+
+/// Formats number with at most three decimal places, so output stays stable and diffable.
+fn number(value: Scalar) -> String {
+    let value = (value * 1000.0).round() / 1000.0;
+    if value == 0.0 {
+        "0".to_owned()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(character),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raui_core::{
+        application::Application,
+        layout::default_layout_engine::DefaultLayoutEngine,
+        make_widget,
+        widget::{
+            component::{
+                containers::content_box::{ContentBoxProps, content_box},
+                image_box::{ImageBoxProps, image_box},
+                text_box::{TextBoxProps, text_box},
+            },
+            unit::{
+                content::ContentBoxItemLayout,
+                image::{ImageBoxColor, ImageBoxImage},
+                text::TextBoxFont,
+            },
+            utils::Rect,
+        },
+    };
+
+    #[test]
+    fn test_render() {
+        let tree = make_widget!(content_box)
+            .key("root")
+            .with_props(ContentBoxProps {
+                clipping: true,
+                clip_shape: ContentBoxClipShape::Circle,
+                layer: UnitLayer {
+                    opacity: 0.5,
+                    blend: BlendMode::Multiply,
+                },
+                ..Default::default()
+            })
+            .listed_slot(
+                make_widget!(image_box)
+                    .key("color")
+                    .with_props(ImageBoxProps {
+                        material: ImageBoxMaterial::Color(ImageBoxColor {
+                            color: Color {
+                                r: 1.0,
+                                g: 0.0,
+                                b: 0.0,
+                                a: 1.0,
+                            },
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+            )
+            .listed_slot(
+                make_widget!(image_box)
+                    .key("image")
+                    .with_props(ContentBoxItemLayout {
+                        margin: 10.0.into(),
+                        ..Default::default()
+                    })
+                    .with_props(ImageBoxProps {
+                        material: ImageBoxMaterial::Image(ImageBoxImage {
+                            id: "icons/star.png".to_owned(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+            )
+            .listed_slot(make_widget!(text_box).key("text").with_props(TextBoxProps {
+                text: "Tom & Jerry".to_owned(),
+                font: TextBoxFont {
+                    name: "verdana".to_owned(),
+                    size: 16.0,
+                },
+                ..Default::default()
+            }));
+        let mut application = Application::default();
+        application.setup(raui_core::widget::setup);
+        application.apply(tree);
+        application.forced_process();
+        let mapping = CoordsMapping::new(Rect {
+            left: 0.0,
+            right: 100.0,
+            top: 0.0,
+            bottom: 50.0,
+        });
+        application
+            .layout(&mapping, &mut DefaultLayoutEngine::<()>::default())
+            .unwrap();
+        let svg = application
+            .render(
+                &mapping,
+                &mut SvgRenderer {
+                    pretty: true,
+                    widget_ids: false,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            svg,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50" viewBox="0 0 100 50">
+  <defs>
+    <clipPath id="clip-0">
+      <ellipse cx="50" cy="25" rx="50" ry="25"/>
+    </clipPath>
+  </defs>
+  <g opacity="0.5" style="mix-blend-mode:multiply" clip-path="url(#clip-0)">
+    <rect width="100" height="50" fill="#ff0000"/>
+    <image width="80" height="30" preserveAspectRatio="none" href="icons/star.png" transform="translate(10 10)"/>
+    <text x="0" y="0" font-family="verdana" font-size="16" dominant-baseline="text-before-edge" fill="#ffffff">Tom &amp; Jerry</text>
+  </g>
+</svg>"##
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use raui_core::widget::utils::Color;
+
+/// Single character cell, with colors left to terminal defaults when not set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TerminalCell {
+    pub character: char,
+    pub foreground: Option<[u8; 3]>,
+    pub background: Option<[u8; 3]>,
+}
+
+impl Default for TerminalCell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            foreground: None,
+            background: None,
+        }
+    }
+}
+
+/// Grid of character cells that widget tree gets rendered into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalBuffer {
+    width: usize,
+    height: usize,
+    cells: Vec<TerminalCell>,
+}
+
+impl TerminalBuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Default::default(); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> Option<&TerminalCell> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut TerminalCell> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.fill(Default::default());
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[TerminalCell]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Characters only, with trailing spaces of each row trimmed - handy for assertions.
+    pub fn to_plain_text(&self) -> String {
+        self.rows()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.character)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Characters with 24-bit ANSI color escape codes, rows separated with `\r\n` so output
+    /// looks the same in raw mode. Cursor is not moved, so callers usually prepend `\x1b[H`.
+    pub fn to_ansi(&self) -> String {
+        let mut result = String::with_capacity(self.cells.len() * 2);
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                result.push_str("\r\n");
+            }
+            let mut colors = (None, None);
+            for cell in row {
+                let current = (cell.foreground, cell.background);
+                if current != colors {
+                    result.push_str("\x1b[0m");
+                    if let Some([r, g, b]) = cell.foreground {
+                        result.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    }
+                    if let Some([r, g, b]) = cell.background {
+                        result.push_str(&format!("\x1b[48;2;{r};{g};{b}m"));
+                    }
+                    colors = current;
+                }
+                result.push(cell.character);
+            }
+            if colors != (None, None) {
+                result.push_str("\x1b[0m");
+            }
+        }
+        result
+    }
+}
+
+/// Color blended over given cell color, with terminal default treated as black.
+pub(crate) fn blend(under: Option<[u8; 3]>, color: Color) -> [u8; 3] {
+    let under = under.unwrap_or_default();
+    let alpha = color.a.clamp(0.0, 1.0);
+    let [r, g, b] = [color.r, color.g, color.b].map(|value| value.clamp(0.0, 1.0) * 255.0);
+    [
+        (under[0] as f32 + (r - under[0] as f32) * alpha).round() as u8,
+        (under[1] as f32 + (g - under[1] as f32) * alpha).round() as u8,
+        (under[2] as f32 + (b - under[2] as f32) * alpha).round() as u8,
+    ]
+}
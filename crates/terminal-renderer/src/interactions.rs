@@ -0,0 +1,319 @@
+use raui_core::{
+    Scalar,
+    application::Application,
+    interactive::{
+        InteractionsEngine,
+        default_interactions_engine::{
+            DefaultInteractionsEngine, DefaultInteractionsEngineResult, Interaction,
+        },
+    },
+    widget::{
+        component::interactive::navigation::{NavJump, NavScroll, NavSignal, NavTextChange},
+        utils::Vec2,
+    },
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TerminalKey {
+    Char(char),
+    Enter,
+    Tab,
+    BackTab,
+    Backspace,
+    Delete,
+    Escape,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TerminalKeyEvent {
+    pub key: TerminalKey,
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+}
+
+impl From<TerminalKey> for TerminalKeyEvent {
+    fn from(key: TerminalKey) -> Self {
+        Self::new(key)
+    }
+}
+
+impl TerminalKeyEvent {
+    pub fn new(key: TerminalKey) -> Self {
+        Self {
+            key,
+            shift: false,
+            control: false,
+            alt: false,
+        }
+    }
+
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub fn control(mut self) -> Self {
+        self.control = true;
+        self
+    }
+
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    fn with_modifiers(mut self, code: u32) -> Self {
+        // Modifiers code is bit mask of modifiers increased by one.
+        let mask = code.saturating_sub(1);
+        self.shift |= mask & 1 != 0;
+        self.alt |= mask & 2 != 0;
+        self.control |= mask & 4 != 0;
+        self
+    }
+
+    /// Decodes key presses from input read from terminal in raw mode, including escape
+    /// sequences of cursor and editing keys. Unknown sequences are skipped.
+    pub fn parse(input: &str) -> Vec<Self> {
+        let mut result = vec![];
+        let mut characters = input.chars().peekable();
+        while let Some(character) = characters.next() {
+            let event = match character {
+                '\x1b' => match characters.next() {
+                    None => Some(TerminalKey::Escape.into()),
+                    Some('[') => {
+                        let mut parameters = String::new();
+                        let mut last = None;
+                        for character in characters.by_ref() {
+                            if character.is_ascii_digit() || character == ';' {
+                                parameters.push(character);
+                            } else {
+                                last = Some(character);
+                                break;
+                            }
+                        }
+                        last.and_then(|last| Self::parse_sequence(&parameters, last))
+                    }
+                    Some('O') => characters
+                        .next()
+                        .and_then(|last| Self::parse_sequence("", last)),
+                    Some('\x1b') => Some(TerminalKey::Escape.into()),
+                    Some(character) => Self::parse_character(character).map(Self::alt),
+                },
+                character => Self::parse_character(character),
+            };
+            result.extend(event);
+        }
+        result
+    }
+
+    fn parse_character(character: char) -> Option<Self> {
+        let result = match character {
+            '\r' | '\n' => TerminalKey::Enter.into(),
+            '\t' => TerminalKey::Tab.into(),
+            '\x7f' | '\x08' => TerminalKey::Backspace.into(),
+            '\x01'..='\x1a' => {
+                Self::new(TerminalKey::Char((character as u8 - 1 + b'a') as char)).control()
+            }
+            character if character.is_control() => return None,
+            character => TerminalKey::Char(character).into(),
+        };
+        Some(result)
+    }
+
+    fn parse_sequence(parameters: &str, last: char) -> Option<Self> {
+        let mut parameters = parameters
+            .split(';')
+            .map(|parameter| parameter.parse::<u32>().unwrap_or(1));
+        let number = parameters.next().unwrap_or(1);
+        let modifiers = parameters.next().unwrap_or(1);
+        let key = match last {
+            'A' => TerminalKey::Up,
+            'B' => TerminalKey::Down,
+            'C' => TerminalKey::Right,
+            'D' => TerminalKey::Left,
+            'H' => TerminalKey::Home,
+            'F' => TerminalKey::End,
+            'Z' => TerminalKey::BackTab,
+            '~' => match number {
+                1 | 7 => TerminalKey::Home,
+                4 | 8 => TerminalKey::End,
+                3 => TerminalKey::Delete,
+                5 => TerminalKey::PageUp,
+                6 => TerminalKey::PageDown,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(Self::new(key).with_modifiers(modifiers))
+    }
+}
+
+/// Turns terminal key presses into navigation interactions, so `nav_*` widgets can be used
+/// with keyboard alone.
+///
+/// Terminals report no key releases, so every press of accepting or cancelling key sends both
+/// press and release signals. Directional key pressed while nothing is selected selects the
+/// top-left button instead of moving the selection.
+#[derive(Debug)]
+pub struct TerminalInteractionsEngine {
+    pub engine: DefaultInteractionsEngine,
+    /// Units scrolled by page up and page down keys.
+    pub page_scroll_units: Scalar,
+    select_first: bool,
+}
+
+impl Default for TerminalInteractionsEngine {
+    fn default() -> Self {
+        Self {
+            engine: Default::default(),
+            page_scroll_units: Self::default_page_scroll_units(),
+            select_first: false,
+        }
+    }
+}
+
+impl TerminalInteractionsEngine {
+    fn default_page_scroll_units() -> Scalar {
+        10.0
+    }
+
+    /// Applies key presses decoded from raw terminal input.
+    pub fn input(&mut self, input: &str) {
+        for event in TerminalKeyEvent::parse(input) {
+            self.key(event);
+        }
+    }
+
+    pub fn key(&mut self, event: impl Into<TerminalKeyEvent>) {
+        let event = event.into();
+        if self.engine.focused_text_input().is_some() {
+            let change = match event.key {
+                TerminalKey::Char(character) if !event.control && !event.alt => {
+                    NavTextChange::InsertCharacter(character)
+                }
+                TerminalKey::Left => NavTextChange::MoveCursorLeft,
+                TerminalKey::Right => NavTextChange::MoveCursorRight,
+                TerminalKey::Home => NavTextChange::MoveCursorStart,
+                TerminalKey::End => NavTextChange::MoveCursorEnd,
+                TerminalKey::Backspace => NavTextChange::DeleteLeft,
+                TerminalKey::Delete => NavTextChange::DeleteRight,
+                TerminalKey::Enter => NavTextChange::NewLine,
+                TerminalKey::Escape | TerminalKey::Tab | TerminalKey::BackTab => {
+                    self.navigate(NavSignal::FocusTextInput(().into()));
+                    return;
+                }
+                _ => return,
+            };
+            self.navigate(NavSignal::TextChange(change));
+            return;
+        }
+        let signal = match event.key {
+            TerminalKey::Up => NavSignal::Up,
+            TerminalKey::Down => NavSignal::Down,
+            TerminalKey::Left if event.shift => NavSignal::Prev,
+            TerminalKey::Right if event.shift => NavSignal::Next,
+            TerminalKey::Left => NavSignal::Left,
+            TerminalKey::Right => NavSignal::Right,
+            TerminalKey::Tab if event.shift => NavSignal::Prev,
+            TerminalKey::Tab => NavSignal::Next,
+            TerminalKey::BackTab => NavSignal::Prev,
+            TerminalKey::Home => NavSignal::Jump(NavJump::First),
+            TerminalKey::End => NavSignal::Jump(NavJump::Last),
+            TerminalKey::PageUp | TerminalKey::PageDown => {
+                let y = if event.key == TerminalKey::PageUp {
+                    -self.page_scroll_units
+                } else {
+                    self.page_scroll_units
+                };
+                NavSignal::Jump(NavJump::Scroll(NavScroll::Units(Vec2 { x: 0.0, y }, true)))
+            }
+            TerminalKey::Enter | TerminalKey::Char(' ') => {
+                self.navigate(NavSignal::Accept(true));
+                self.navigate(NavSignal::Accept(false));
+                return;
+            }
+            TerminalKey::Escape | TerminalKey::Backspace => {
+                self.navigate(NavSignal::Cancel(true));
+                self.navigate(NavSignal::Cancel(false));
+                return;
+            }
+            _ => return,
+        };
+        if self.engine.selected_item().is_none() && !matches!(signal, NavSignal::Jump(_)) {
+            self.select_first = true;
+        } else {
+            self.navigate(signal);
+        }
+    }
+
+    fn navigate(&mut self, signal: NavSignal) {
+        self.engine.interact(Interaction::Navigate(signal));
+    }
+
+    fn select_first_button(&mut self, app: &mut Application) {
+        let layout = app.layout_data();
+        let found = self
+            .engine
+            .buttons()
+            .filter(|id| self.engine.is_in_focus_scope(id))
+            .filter_map(|id| {
+                let rect = layout.items.get(id)?.ui_space;
+                Some((rect.top, rect.left, id))
+            })
+            .min_by(|a, b| {
+                a.0.partial_cmp(&b.0)
+                    .unwrap()
+                    .then(a.1.partial_cmp(&b.1).unwrap())
+                    .then_with(|| a.2.path().cmp(b.2.path()))
+            })
+            .map(|(_, _, id)| id.to_owned());
+        if found.is_some() {
+            self.engine.select_item(app, found);
+        }
+    }
+}
+
+impl InteractionsEngine<DefaultInteractionsEngineResult, ()> for TerminalInteractionsEngine {
+    fn perform_interactions(
+        &mut self,
+        app: &mut Application,
+    ) -> Result<DefaultInteractionsEngineResult, ()> {
+        if std::mem::take(&mut self.select_first) && self.engine.selected_item().is_none() {
+            self.select_first_button(app);
+        }
+        self.engine.perform_interactions(app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            TerminalKeyEvent::parse("a\r\x1b[A\x1b[1;2C\x1b[3~\x1bOH\x7f\x03\x1bx\x1b"),
+            vec![
+                TerminalKey::Char('a').into(),
+                TerminalKey::Enter.into(),
+                TerminalKey::Up.into(),
+                TerminalKeyEvent::new(TerminalKey::Right).shift(),
+                TerminalKey::Delete.into(),
+                TerminalKey::Home.into(),
+                TerminalKey::Backspace.into(),
+                TerminalKeyEvent::new(TerminalKey::Char('c')).control(),
+                TerminalKeyEvent::new(TerminalKey::Char('x')).alt(),
+                TerminalKey::Escape.into(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,543 @@
+mod buffer;
+mod interactions;
+mod text;
+
+pub use crate::{
+    buffer::{TerminalBuffer, TerminalCell},
+    interactions::{TerminalInteractionsEngine, TerminalKey, TerminalKeyEvent},
+    text::TerminalTextMeasurementsEngine,
+};
+
+use crate::{buffer::blend, text::wrap_text};
+use raui_core::{
+    layout::{CoordsMapping, Layout},
+    renderer::Renderer,
+    widget::{
+        WidgetId,
+        unit::{
+            WidgetUnit,
+            image::{ImageBox, ImageBoxMaterial},
+            text::{TextBox, TextBoxHorizontalAlign, TextBoxVerticalAlign},
+        },
+        utils::{Color, Rect, Transform, Vec2, lerp},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    WidgetHasNoLayout(WidgetId),
+}
+
+/// Renders widget tree into [`TerminalBuffer`], where single real unit of coords mapping is
+/// single character cell.
+///
+/// Color materials and gradients fill cells background, shapes and images are outlined with
+/// box-drawing characters (images labeled with their ids) and text boxes are word-wrapped with
+/// every character taking single cell. Transforms only move widgets, since cells cannot be
+/// rotated or scaled, and container clipping uses box bounds regardless of clip shape.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TerminalRenderer {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl TerminalRenderer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height }
+    }
+}
+
+impl Renderer<TerminalBuffer, Error> for TerminalRenderer {
+    fn render(
+        &mut self,
+        tree: &WidgetUnit,
+        mapping: &CoordsMapping,
+        layout: &Layout,
+    ) -> Result<TerminalBuffer, Error> {
+        let mut context = TerminalContext {
+            buffer: TerminalBuffer::new(self.width, self.height),
+            mapping,
+            layout,
+            clip_stack: vec![],
+        };
+        context.render_node(tree, Default::default(), false)?;
+        Ok(context.buffer)
+    }
+}
+
+/// Area of cells in `[left, top, right, bottom)` form.
+type CellRect = [i32; 4];
+
+struct TerminalContext<'a> {
+    buffer: TerminalBuffer,
+    mapping: &'a CoordsMapping,
+    layout: &'a Layout,
+    clip_stack: Vec<CellRect>,
+}
+
+impl TerminalContext<'_> {
+    fn item_rect(
+        &self,
+        id: &WidgetId,
+        transform: Option<&Transform>,
+        offset: Vec2,
+        local: bool,
+    ) -> Result<Rect, Error> {
+        let Some(item) = self.layout.items.get(id) else {
+            return Err(Error::WidgetHasNoLayout(id.to_owned()));
+        };
+        let rect = self.mapping.virtual_to_real_rect(item.local_space, local);
+        let (x, y) = match transform {
+            Some(transform) => (
+                offset.x + transform.translation.x + lerp(0.0, rect.width(), transform.align.x),
+                offset.y + transform.translation.y + lerp(0.0, rect.height(), transform.align.y),
+            ),
+            None => (offset.x, offset.y),
+        };
+        Ok(Rect {
+            left: rect.left + x,
+            right: rect.right + x,
+            top: rect.top + y,
+            bottom: rect.bottom + y,
+        })
+    }
+
+    fn cells(rect: Rect) -> CellRect {
+        [
+            rect.left.round() as i32,
+            rect.top.round() as i32,
+            rect.right.round() as i32,
+            rect.bottom.round() as i32,
+        ]
+    }
+
+    fn clip(&self) -> CellRect {
+        let [left, top, right, bottom] = self.clip_stack.last().copied().unwrap_or([
+            0,
+            0,
+            self.buffer.width() as i32,
+            self.buffer.height() as i32,
+        ]);
+        [left.max(0), top.max(0), right, bottom]
+    }
+
+    fn cell_mut(&mut self, x: i32, y: i32) -> Option<&mut TerminalCell> {
+        let [left, top, right, bottom] = self.clip();
+        if x >= left && x < right && y >= top && y < bottom {
+            self.buffer.cell_mut(x as usize, y as usize)
+        } else {
+            None
+        }
+    }
+
+    fn render_node(&mut self, unit: &WidgetUnit, offset: Vec2, local: bool) -> Result<(), Error> {
+        match unit {
+            WidgetUnit::None | WidgetUnit::PortalBox(_) => Ok(()),
+            WidgetUnit::AreaBox(unit) => {
+                let rect = self.item_rect(&unit.id, None, offset, local)?;
+                self.render_node(&unit.slot, top_left(rect), true)
+            }
+            WidgetUnit::ContentBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                if unit.layer.opacity <= 0.0 {
+                    return Ok(());
+                }
+                let mut items = unit
+                    .items
+                    .iter()
+                    .map(|item| (item.layout.depth, &item.slot))
+                    .collect::<Vec<_>>();
+                items.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+                if unit.clipping {
+                    let [left, top, right, bottom] = Self::cells(rect);
+                    let [l, t, r, b] = self.clip();
+                    self.clip_stack
+                        .push([left.max(l), top.max(t), right.min(r), bottom.min(b)]);
+                }
+                for (_, slot) in items {
+                    self.render_node(slot, top_left(rect), true)?;
+                }
+                if unit.clipping {
+                    self.clip_stack.pop();
+                }
+                Ok(())
+            }
+            WidgetUnit::FlexBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                if unit.layer.opacity <= 0.0 {
+                    return Ok(());
+                }
+                for item in &unit.items {
+                    self.render_node(&item.slot, top_left(rect), true)?;
+                }
+                Ok(())
+            }
+            WidgetUnit::GridBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                if unit.layer.opacity <= 0.0 {
+                    return Ok(());
+                }
+                for item in &unit.items {
+                    self.render_node(&item.slot, top_left(rect), true)?;
+                }
+                Ok(())
+            }
+            WidgetUnit::SizeBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                self.render_node(&unit.slot, top_left(rect), true)
+            }
+            WidgetUnit::ImageBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                self.render_image_box(unit, Self::cells(rect));
+                Ok(())
+            }
+            WidgetUnit::TextBox(unit) => {
+                let rect = self.item_rect(&unit.id, Some(&unit.transform), offset, local)?;
+                self.render_text_box(unit, Self::cells(rect));
+                Ok(())
+            }
+        }
+    }
+
+    fn render_image_box(&mut self, unit: &ImageBox, rect: CellRect) {
+        match &unit.material {
+            ImageBoxMaterial::Color(color) => self.fill(rect, |_, _| color.color),
+            ImageBoxMaterial::Gradient(gradient) => {
+                let [left, top, right, bottom] = rect;
+                let width = (right - left).max(1) as f32;
+                let height = (bottom - top).max(1) as f32;
+                self.fill(rect, |x, y| {
+                    gradient.color_at(Vec2 {
+                        x: ((x - left) as f32 + 0.5) / width,
+                        y: ((y - top) as f32 + 0.5) / height,
+                    })
+                });
+            }
+            ImageBoxMaterial::Shape(shape) => {
+                self.fill(rect, |_, _| shape.fill);
+                if shape.stroke_width > 0.0 && shape.stroke_color.a > 0.0 {
+                    let corners = shape.corner_radius;
+                    let rounded = corners.top_left > 0.0
+                        || corners.top_right > 0.0
+                        || corners.bottom_right > 0.0
+                        || corners.bottom_left > 0.0;
+                    self.frame(rect, shape.stroke_color, rounded, None);
+                }
+            }
+            ImageBoxMaterial::Image(image) => {
+                self.frame(rect, image.tint, false, Some(&image.id));
+            }
+            ImageBoxMaterial::Flipbook(flipbook) => {
+                if let Some((id, _)) = flipbook.frame_image() {
+                    self.frame(rect, flipbook.tint, false, Some(id));
+                }
+            }
+            ImageBoxMaterial::Procedural(_) => {}
+        }
+    }
+
+    fn fill(&mut self, rect: CellRect, color: impl Fn(i32, i32) -> Color) {
+        let [left, top, right, bottom] = rect;
+        for y in top..bottom {
+            for x in left..right {
+                let color = color(x, y);
+                if color.a <= 0.0 {
+                    continue;
+                }
+                if let Some(cell) = self.cell_mut(x, y) {
+                    // Painting over cell hides whatever was drawn there before.
+                    *cell = TerminalCell {
+                        background: Some(blend(cell.background, color)),
+                        ..Default::default()
+                    };
+                }
+            }
+        }
+    }
+
+    fn put(&mut self, x: i32, y: i32, character: char, color: Color) {
+        if let Some(cell) = self.cell_mut(x, y) {
+            cell.character = character;
+            cell.foreground = Some(blend(cell.background, color));
+        }
+    }
+
+    fn frame(&mut self, rect: CellRect, color: Color, rounded: bool, label: Option<&str>) {
+        if color.a <= 0.0 {
+            return;
+        }
+        let [left, top, right, bottom] = rect;
+        if right <= left || bottom <= top {
+            return;
+        }
+        let (right, bottom) = (right - 1, bottom - 1);
+        if left == right || top == bottom {
+            let character = if left == right { '│' } else { '─' };
+            for y in top..=bottom {
+                for x in left..=right {
+                    self.put(x, y, character, color);
+                }
+            }
+            return;
+        }
+        let corners = if rounded {
+            ['╭', '╮', '╰', '╯']
+        } else {
+            ['┌', '┐', '└', '┘']
+        };
+        for x in left + 1..right {
+            self.put(x, top, '─', color);
+            self.put(x, bottom, '─', color);
+        }
+        for y in top + 1..bottom {
+            self.put(left, y, '│', color);
+            self.put(right, y, '│', color);
+        }
+        self.put(left, top, corners[0], color);
+        self.put(right, top, corners[1], color);
+        self.put(left, bottom, corners[2], color);
+        self.put(right, bottom, corners[3], color);
+        if let Some(label) = label {
+            for (index, character) in label.chars().take((right - left - 1) as usize).enumerate() {
+                self.put(left + 1 + index as i32, top, character, color);
+            }
+        }
+    }
+
+    fn render_text_box(&mut self, unit: &TextBox, rect: CellRect) {
+        let [left, top, right, bottom] = rect;
+        let width = (right - left).max(0);
+        let height = (bottom - top).max(0);
+        let lines = wrap_text(&unit.text, Some(width as usize));
+        let count = lines.len() as i32;
+        let first = match unit.vertical_align {
+            TextBoxVerticalAlign::Top => 0,
+            TextBoxVerticalAlign::Middle => (height - count) / 2,
+            TextBoxVerticalAlign::Bottom => height - count,
+        };
+        for (index, line) in lines.iter().enumerate() {
+            let row = first + index as i32;
+            if row < 0 || row >= height {
+                continue;
+            }
+            let length = line.chars().count() as i32;
+            let column = match unit.horizontal_align {
+                TextBoxHorizontalAlign::Left => 0,
+                TextBoxHorizontalAlign::Center => (width - length) / 2,
+                TextBoxHorizontalAlign::Right => width - length,
+            };
+            for (offset, character) in line.chars().enumerate() {
+                let x = column + offset as i32;
+                if x < 0 || x >= width {
+                    continue;
+                }
+                let color = match &unit.gradient {
+                    Some(gradient) => gradient.color_at(Vec2 {
+                        x: (x as f32 + 0.5) / width.max(1) as f32,
+                        y: (row as f32 + 0.5) / height.max(1) as f32,
+                    }),
+                    None => unit.color,
+                };
+                if color.a > 0.0 {
+                    self.put(left + x, top + row, character, color);
+                }
+            }
+        }
+    }
+}
+
+fn top_left(rect: Rect) -> Vec2 {
+    Vec2 {
+        x: rect.left,
+        y: rect.top,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raui_core::{
+        application::Application,
+        layout::default_layout_engine::DefaultLayoutEngine,
+        make_widget,
+        widget::{
+            component::{
+                containers::{
+                    content_box::content_box,
+                    vertical_box::{VerticalBoxProps, nav_vertical_box},
+                },
+                image_box::{ImageBoxProps, image_box},
+                interactive::{button::button, navigation::NavItemActive},
+                text_box::{TextBoxProps, text_box},
+            },
+            unit::{
+                content::ContentBoxItemLayout,
+                flex::FlexBoxItemLayout,
+                image::{ImageBoxColor, ImageBoxImage, ImageBoxShape},
+            },
+            utils::Color,
+        },
+    };
+
+    fn mapping() -> CoordsMapping {
+        CoordsMapping::new(Rect {
+            left: 0.0,
+            right: 12.0,
+            top: 0.0,
+            bottom: 5.0,
+        })
+    }
+
+    fn update(application: &mut Application, engine: &mut TerminalInteractionsEngine) {
+        application.process();
+        application
+            .layout(
+                &mapping(),
+                &mut DefaultLayoutEngine::new(TerminalTextMeasurementsEngine),
+            )
+            .unwrap();
+        application.interact(engine).unwrap();
+    }
+
+    #[test]
+    fn test_render() {
+        let red = Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        };
+        let tree = make_widget!(content_box)
+            .listed_slot(make_widget!(image_box).with_props(ImageBoxProps {
+                material: ImageBoxMaterial::Color(ImageBoxColor {
+                    color: red,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))
+            .listed_slot(
+                make_widget!(image_box)
+                    .with_props(ContentBoxItemLayout {
+                        margin: Rect {
+                            left: 0.0,
+                            right: 6.0,
+                            top: 0.0,
+                            bottom: 2.0,
+                        },
+                        ..Default::default()
+                    })
+                    .with_props(ImageBoxProps {
+                        material: ImageBoxMaterial::Image(ImageBoxImage {
+                            id: "logo".to_owned(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+            )
+            .listed_slot(
+                make_widget!(image_box)
+                    .with_props(ContentBoxItemLayout {
+                        margin: Rect {
+                            left: 6.0,
+                            right: 0.0,
+                            top: 0.0,
+                            bottom: 2.0,
+                        },
+                        ..Default::default()
+                    })
+                    .with_props(ImageBoxProps {
+                        material: ImageBoxMaterial::Shape(ImageBoxShape {
+                            stroke_width: 1.0,
+                            corner_radius: 1.0.into(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+            )
+            .listed_slot(
+                make_widget!(text_box)
+                    .with_props(ContentBoxItemLayout {
+                        margin: Rect {
+                            left: 0.0,
+                            right: 0.0,
+                            top: 3.0,
+                            bottom: 0.0,
+                        },
+                        ..Default::default()
+                    })
+                    .with_props(TextBoxProps {
+                        text: "Hello terminal".to_owned(),
+                        horizontal_align: TextBoxHorizontalAlign::Center,
+                        ..Default::default()
+                    }),
+            );
+        let mut application = Application::default();
+        application.setup(raui_core::widget::setup);
+        application.apply(tree);
+        update(&mut application, &mut Default::default());
+        let buffer = application
+            .render(&mapping(), &mut TerminalRenderer::new(12, 5))
+            .unwrap();
+        assert_eq!(
+            buffer.to_plain_text(),
+            "┌logo┐╭────╮\n│    ││    │\n└────┘╰────╯\n   Hello\n  terminal"
+        );
+        assert_eq!(buffer.cell(0, 4).unwrap().background, Some([255, 0, 0]));
+        assert_eq!(buffer.cell(2, 4).unwrap().foreground, Some([255, 255, 255]));
+        assert!(
+            buffer
+                .to_ansi()
+                .starts_with("\x1b[0m\x1b[38;2;255;255;255m\x1b[48;2;255;0;0m┌")
+        );
+    }
+
+    #[test]
+    fn test_navigation() {
+        let item = |key: &str| {
+            make_widget!(button)
+                .key(key)
+                .with_props(NavItemActive)
+                .with_props(FlexBoxItemLayout::no_growing_and_shrinking())
+                .named_slot(
+                    "content",
+                    make_widget!(text_box).with_props(TextBoxProps {
+                        text: key.to_owned(),
+                        height: raui_core::widget::unit::text::TextBoxSizeValue::Exact(1.0),
+                        ..Default::default()
+                    }),
+                )
+        };
+        let tree = make_widget!(nav_vertical_box)
+            .key("list")
+            .with_props(VerticalBoxProps::default())
+            .listed_slot(item("first"))
+            .listed_slot(item("second"));
+        let mut application = Application::default();
+        application.setup(raui_core::widget::setup);
+        application.apply(tree);
+        let mut engine = TerminalInteractionsEngine::default();
+        update(&mut application, &mut engine);
+        update(&mut application, &mut engine);
+        assert!(engine.engine.selected_item().is_none());
+        engine.input("\x1b[B");
+        update(&mut application, &mut engine);
+        assert_eq!(
+            engine.engine.selected_item().map(|id| id.key()),
+            Some("first")
+        );
+        engine.key(TerminalKey::Down);
+        update(&mut application, &mut engine);
+        update(&mut application, &mut engine);
+        assert_eq!(
+            engine.engine.selected_item().map(|id| id.key()),
+            Some("second")
+        );
+        engine.input("\x1b[A");
+        update(&mut application, &mut engine);
+        update(&mut application, &mut engine);
+        assert_eq!(
+            engine.engine.selected_item().map(|id| id.key()),
+            Some("first")
+        );
+    }
+}
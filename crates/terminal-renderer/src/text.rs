@@ -0,0 +1,78 @@
+use raui_core::{
+    layout::{CoordsMapping, default_layout_engine::TextMeasurementEngine},
+    widget::{
+        unit::text::{TextBox, TextBoxSizeValue},
+        utils::{Rect, Vec2},
+    },
+};
+
+/// Measures text boxes as if every character took single cell and every line took single row,
+/// ignoring font size.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TerminalTextMeasurementsEngine;
+
+impl TextMeasurementEngine for TerminalTextMeasurementsEngine {
+    fn measure_text(
+        &self,
+        size_available: Vec2,
+        mapping: &CoordsMapping,
+        unit: &TextBox,
+    ) -> Option<Rect> {
+        let max_width = match unit.width {
+            TextBoxSizeValue::Content => None,
+            TextBoxSizeValue::Fill => Some(size_available.x),
+            TextBoxSizeValue::Exact(v) => Some(v),
+        };
+        let max_width = max_width.map(|v| (v * mapping.scalar_scale(false)).floor().max(0.0));
+        let lines = wrap_text(&unit.text, max_width.map(|v| v as usize));
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        Some(Rect {
+            left: 0.0,
+            right: width as _,
+            top: 0.0,
+            bottom: lines.len() as _,
+        })
+    }
+}
+
+/// Breaks text into lines at new line characters and, when width is limited, between words.
+/// Words longer than available width get split.
+pub(crate) fn wrap_text(text: &str, max_width: Option<usize>) -> Vec<String> {
+    let mut result = vec![];
+    for paragraph in text.split('\n') {
+        let paragraph = paragraph.trim_end_matches('\r');
+        let Some(max_width) = max_width else {
+            result.push(paragraph.to_owned());
+            continue;
+        };
+        let max_width = max_width.max(1);
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in paragraph.split(' ') {
+            let mut word = word.chars().collect::<Vec<_>>();
+            let separator = usize::from(line_width > 0);
+            if line_width > 0 && line_width + separator + word.len() > max_width {
+                result.push(std::mem::take(&mut line));
+                line_width = 0;
+            } else if separator > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            while line_width + word.len() > max_width {
+                let rest = word.split_off(max_width - line_width);
+                line.extend(word);
+                result.push(std::mem::take(&mut line));
+                line_width = 0;
+                word = rest;
+            }
+            line_width += word.len();
+            line.extend(word);
+        }
+        result.push(line);
+    }
+    result
+}
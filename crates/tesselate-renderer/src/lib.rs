@@ -1,4 +1,4 @@
-use bytemuck::Pod;
+use bytemuck::{Pod, Zeroable};
 use fontdue::{
     Font,
     layout::{
@@ -13,14 +13,16 @@ use raui_core::{
     widget::{
         WidgetId,
         unit::{
-            WidgetUnit,
+            BlendMode, UnitLayer, WidgetUnit,
+            content::ContentBoxClipShape,
             image::{
-                ImageBoxColor, ImageBoxImage, ImageBoxImageScaling, ImageBoxMaterial,
-                ImageBoxProceduralMesh,
+                ImageBox, ImageBoxColor, ImageBoxFit, ImageBoxFrame, ImageBoxImage,
+                ImageBoxImageScaling, ImageBoxMaterial, ImageBoxProceduralMesh, ImageBoxShape,
+                ImageBoxShapeCorners,
             },
             text::{TextBoxHorizontalAlign, TextBoxVerticalAlign},
         },
-        utils::{Color, Rect, Transform, Vec2, lerp},
+        utils::{Color, Gradient, GradientKind, Rect, Shadow, Transform, Vec2, lerp},
     },
 };
 use spitfire_core::{Triangle, VertexStream};
@@ -38,6 +40,51 @@ pub enum Error {
 pub trait TesselateVertex: Pod {
     fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: [f32; 4]);
     fn transform(&mut self, matrix: vek::Mat4<f32>);
+    /// Tells if vertex implements [`Self::multiply_alpha`].
+    const CAN_MULTIPLY_ALPHA: bool = false;
+    /// Used to fade out contents of layers that batch converter does not composite by itself.
+    /// Without it these layers are drawn fully opaque.
+    fn multiply_alpha(&mut self, _factor: f32) {}
+    /// Tells if vertex implements [`Self::position`] and [`Self::lerp`].
+    const CAN_CLIP: bool = false;
+    fn position(&self) -> [f32; 2] {
+        [0.0, 0.0]
+    }
+    /// Interpolates all vertex attributes, used to split triangles along clip shape edges when
+    /// batch converter does not support clip masks. Without it these shapes clip to their bounds.
+    fn lerp(&self, _other: &Self, _factor: f32) -> Self {
+        *self
+    }
+}
+
+/// Intermediate glyph vertex, used when text glyphs have to be recolored or duplicated.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Pod, Zeroable)]
+struct GlyphVertex {
+    position: [f32; 2],
+    tex_coord: [f32; 3],
+    color: [f32; 4],
+}
+
+impl TextVertex<Color> for GlyphVertex {
+    fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: Color) {
+        self.position = position;
+        self.tex_coord = tex_coord;
+        self.color = [color.r, color.g, color.b, color.a];
+    }
+}
+
+/// Limit of tiles along single axis, so tiny tiles cannot flood vertex stream.
+const MAX_IMAGE_TILES: usize = 128;
+
+/// Part of image area along single axis: `(from, to, uv_from, uv_to)`.
+type ImageSpan = (Scalar, Scalar, Scalar, Scalar);
+
+/// Clip mask geometry in screen space, with image used for alpha testing.
+struct ClipMask<V> {
+    image: Option<String>,
+    vertices: Vec<V>,
+    triangles: Vec<Triangle>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -59,6 +106,29 @@ pub enum TesselateBatch {
         h: f32,
     },
     ClipPop,
+    /// Starts stencil-style clipping: vertices of this batch form mask geometry (alpha tested
+    /// with `image` if set) that should mark covered area instead of being drawn, and following
+    /// batches should only be drawn inside all currently pushed masks.
+    /// Converters returning no batch for it make renderer clip convex shapes on CPU (if
+    /// [`TesselateVertex::CAN_CLIP`]) and fall back to [`TesselateBatch::ClipPush`] with shape
+    /// bounds otherwise.
+    ClipMaskPush {
+        image: Option<String>,
+    },
+    /// Ends clipping started by [`TesselateBatch::ClipMaskPush`], carrying the same mask geometry
+    /// so it can be unmarked. Only requested when matching push produced a batch.
+    ClipMaskPop {
+        image: Option<String>,
+    },
+    /// Starts group of batches that should be composited together onto what lies below.
+    /// Converters returning no batch for it make renderer fade layer contents with vertex alpha
+    /// instead (if [`TesselateVertex::CAN_MULTIPLY_ALPHA`]), but still receive matching
+    /// [`TesselateBatch::LayerPop`].
+    LayerPush {
+        opacity: f32,
+        blend: BlendMode,
+    },
+    LayerPop,
     Debug,
 }
 
@@ -196,7 +266,9 @@ where
         let bl = vec2_to_raui(matrix.mul_point(vek::Vec2::new(0.0, size.y)));
         let c = data.color;
         match &data.scaling {
-            ImageBoxImageScaling::Stretch => {
+            ImageBoxImageScaling::Stretch
+            | ImageBoxImageScaling::Tile(_)
+            | ImageBoxImageScaling::Fit(_) => {
                 if let Some(batch) = self.converter.convert(TesselateBatch::Color) {
                     self.stream.batch_optimized(batch);
                     self.stream.quad([
@@ -207,7 +279,7 @@ where
                     ]);
                 }
             }
-            ImageBoxImageScaling::Frame(frame) => {
+            ImageBoxImageScaling::Frame(frame) | ImageBoxImageScaling::FrameRepeat(frame) => {
                 let mut d = frame.destination;
                 d.left *= scale.x;
                 d.right *= scale.x;
@@ -286,6 +358,533 @@ where
         }
     }
 
+    /// Points of shape outline shrunk by `inset`, going clockwise from top-left corner.
+    /// Every corner produces `segments + 1` points so outlines of the same shape can be stitched.
+    fn shape_contour(
+        size: Vec2,
+        radius: &ImageBoxShapeCorners,
+        segments: usize,
+        inset: Scalar,
+    ) -> impl Iterator<Item = vek::Vec2<Scalar>> {
+        let ix = inset.min(size.x * 0.5);
+        let iy = inset.min(size.y * 0.5);
+        [
+            (radius.top_left, 0.0, 0.0, 1.0, 1.0, 0.5),
+            (radius.top_right, size.x, 0.0, -1.0, 1.0, 0.75),
+            (radius.bottom_right, size.x, size.y, -1.0, -1.0, 0.0),
+            (radius.bottom_left, 0.0, size.y, 1.0, -1.0, 0.25),
+        ]
+        .into_iter()
+        .flat_map(move |(radius, x, y, dx, dy, start)| {
+            let ox = radius.max(ix);
+            let oy = radius.max(iy);
+            let cx = x + ox * dx;
+            let cy = y + oy * dy;
+            let rx = ox - ix;
+            let ry = oy - iy;
+            (0..=segments).map(move |index| {
+                let angle = (start + 0.25 * index as Scalar / segments.max(1) as Scalar)
+                    * std::f32::consts::TAU;
+                vek::Vec2::new(cx + rx * angle.cos(), cy + ry * angle.sin())
+            })
+        })
+    }
+
+    fn shape_segments(radius: Scalar) -> usize {
+        if radius > 0.0 {
+            (radius.sqrt() * 2.0).ceil().clamp(2.0, 32.0) as usize
+        } else {
+            0
+        }
+    }
+
+    fn scaled_corners(
+        corner_radius: &ImageBoxShapeCorners,
+        scale: Scalar,
+        size: Vec2,
+    ) -> ImageBoxShapeCorners {
+        ImageBoxShapeCorners {
+            top_left: corner_radius.top_left * scale,
+            top_right: corner_radius.top_right * scale,
+            bottom_right: corner_radius.bottom_right * scale,
+            bottom_left: corner_radius.bottom_left * scale,
+        }
+        .fit(size)
+    }
+
+    /// Stitches consecutive shape outlines (inset, color) into rings, optionally closing the
+    /// innermost one with a filled fan.
+    fn produce_contour_rings(
+        &mut self,
+        size: Vec2,
+        corner_radius: &ImageBoxShapeCorners,
+        segments: usize,
+        offset: vek::Vec2<Scalar>,
+        rings: &[(Scalar, Color)],
+        fill: Option<Color>,
+    ) {
+        let matrix = self.top_transform();
+        let count = 4 * (segments + 1);
+        let mut vertices = Vec::with_capacity(count * rings.len() + 1);
+        for (inset, color) in rings {
+            vertices.extend(
+                Self::shape_contour(size, corner_radius, segments, *inset).map(|point| {
+                    Self::make_vertex(
+                        vec2_to_raui(matrix.mul_point(point + offset)),
+                        Default::default(),
+                        0.0,
+                        *color,
+                    )
+                }),
+            );
+        }
+        let mut triangles = Vec::with_capacity(count * (rings.len() * 2 - 1));
+        for ring in 0..(rings.len() - 1) {
+            let outer = ring * count;
+            let inner = outer + count;
+            for index in 0..count {
+                let next = (index + 1) % count;
+                triangles.push(Triangle {
+                    a: (outer + index) as u32,
+                    b: (outer + next) as u32,
+                    c: (inner + next) as u32,
+                });
+                triangles.push(Triangle {
+                    a: (inner + next) as u32,
+                    b: (inner + index) as u32,
+                    c: (outer + index) as u32,
+                });
+            }
+        }
+        if let Some(fill) = fill {
+            let inner = (rings.len() - 1) * count;
+            let center = vertices.len();
+            vertices.push(Self::make_vertex(
+                vec2_to_raui(matrix.mul_point(vek::Vec2::new(size.x * 0.5, size.y * 0.5) + offset)),
+                Default::default(),
+                0.0,
+                fill,
+            ));
+            for index in 0..count {
+                triangles.push(Triangle {
+                    a: center as u32,
+                    b: (inner + index) as u32,
+                    c: (inner + (index + 1) % count) as u32,
+                });
+            }
+        }
+        if let Some(batch) = self.converter.convert(TesselateBatch::Color) {
+            self.stream.batch_optimized(batch);
+            self.stream.extend(vertices, triangles);
+        }
+    }
+
+    fn produce_shape_triangles(&mut self, size: Vec2, scale: Scalar, data: &ImageBoxShape) {
+        // Edges fade out over a single pixel, which is enough to hide aliasing without blurring.
+        const FEATHER: Scalar = 1.0;
+
+        let corner_radius = Self::scaled_corners(&data.corner_radius, scale, size);
+        let segments = Self::shape_segments(
+            corner_radius
+                .top_left
+                .max(corner_radius.top_right)
+                .max(corner_radius.bottom_right)
+                .max(corner_radius.bottom_left),
+        );
+        let stroke_width = (data.stroke_width * scale).max(0.0);
+        let transparent = |color: Color| Color { a: 0.0, ..color };
+        let rings = if stroke_width > 0.0 && data.stroke_color.a > 0.0 {
+            let feather = FEATHER.min(stroke_width * 0.5);
+            vec![
+                (0.0, transparent(data.stroke_color)),
+                (feather, data.stroke_color),
+                (stroke_width - feather, data.stroke_color),
+                (stroke_width + feather, data.fill),
+            ]
+        } else {
+            vec![(0.0, transparent(data.fill)), (FEATHER, data.fill)]
+        };
+        self.produce_contour_rings(
+            size,
+            &corner_radius,
+            segments,
+            vek::Vec2::zero(),
+            &rings,
+            (data.fill.a > 0.0).then_some(data.fill),
+        );
+    }
+
+    /// Approximates blurred shadow of (rounded) rectangle with rings fading out along
+    /// smoothstep curve, going from shadow outer edge to where it becomes fully opaque.
+    fn produce_shadow_triangles(
+        &mut self,
+        size: Vec2,
+        scale: Scalar,
+        corner_radius: &ImageBoxShapeCorners,
+        data: &Shadow,
+    ) {
+        const RINGS: usize = 5;
+
+        if data.color.a <= 0.0 {
+            return;
+        }
+        let corner_radius = Self::scaled_corners(corner_radius, scale, size);
+        let blur = (data.blur * scale).max(1.0);
+        let spread = data.spread * scale;
+        let outer = -spread - blur * 0.5;
+        let inner = -spread + blur * 0.5;
+        let segments = Self::shape_segments(
+            corner_radius
+                .top_left
+                .max(corner_radius.top_right)
+                .max(corner_radius.bottom_right)
+                .max(corner_radius.bottom_left)
+                - outer,
+        );
+        let rings = (0..RINGS)
+            .map(|index| {
+                let factor = index as Scalar / (RINGS - 1) as Scalar;
+                let alpha = factor * factor * (3.0 - 2.0 * factor);
+                (
+                    lerp(outer, inner, factor),
+                    Color {
+                        a: data.color.a * alpha,
+                        ..data.color
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        self.produce_contour_rings(
+            size,
+            &corner_radius,
+            segments,
+            vek::Vec2::new(data.offset.x * scale, data.offset.y * scale),
+            &rings,
+            Some(data.color),
+        );
+    }
+
+    /// Offsets and opacity factors of glyph copies that together imitate blurred text shadow.
+    fn text_shadow_samples(data: &Shadow, scale: Scalar) -> Vec<(vek::Vec2<Scalar>, Scalar)> {
+        const DIRECTIONS: usize = 8;
+
+        let offset = vek::Vec2::new(data.offset.x * scale, data.offset.y * scale);
+        let radius = (data.spread + data.blur * 0.5).max(0.0) * scale;
+        if radius <= 0.0 {
+            return vec![(offset, 1.0)];
+        }
+        std::iter::once((offset, 1.0 / 3.0))
+            .chain((0..DIRECTIONS).map(|index| {
+                let (y, x) =
+                    (index as Scalar / DIRECTIONS as Scalar * std::f32::consts::TAU).sin_cos();
+                (offset + vek::Vec2::new(x, y) * radius, 1.0 / 3.0)
+            }))
+            .collect()
+    }
+
+    fn produce_gradient_triangles(&mut self, size: Vec2, data: &Gradient) {
+        match data.kind {
+            GradientKind::Conic { center, angle }
+                if (0.0..=1.0).contains(&center.x) && (0.0..=1.0).contains(&center.y) =>
+            {
+                self.produce_conic_gradient_triangles(size, data, center, angle)
+            }
+            _ => self.produce_grid_gradient_triangles(size, data),
+        }
+    }
+
+    /// Samples gradient on a grid of vertices and lets colors interpolate between them.
+    fn produce_grid_gradient_triangles(&mut self, size: Vec2, data: &Gradient) {
+        // Vertex colors interpolate linearly, so two-stop linear gradient needs no subdivision.
+        const CELL_SIZE: Scalar = 8.0;
+        const MAX_CELLS: Scalar = 64.0;
+
+        let matrix = self.top_transform();
+        let (cols, rows) = match data.kind {
+            GradientKind::Linear { .. } if data.stops.len() <= 2 => (1, 1),
+            _ => (
+                (size.x / CELL_SIZE).ceil().clamp(1.0, MAX_CELLS) as usize,
+                (size.y / CELL_SIZE).ceil().clamp(1.0, MAX_CELLS) as usize,
+            ),
+        };
+        let vertices = (0..=rows).flat_map(|row| {
+            (0..=cols).map(move |col| {
+                let point = Vec2 {
+                    x: col as Scalar / cols as Scalar,
+                    y: row as Scalar / rows as Scalar,
+                };
+                let position = matrix.mul_point(vek::Vec2::new(point.x * size.x, point.y * size.y));
+                Self::make_vertex(
+                    vec2_to_raui(position),
+                    Default::default(),
+                    0.0,
+                    data.color_at(point),
+                )
+            })
+        });
+        let triangles = (0..rows).flat_map(|row| {
+            (0..cols).flat_map(move |col| {
+                let tl = (row * (cols + 1) + col) as u32;
+                let tr = tl + 1;
+                let bl = tl + cols as u32 + 1;
+                let br = bl + 1;
+                [
+                    Triangle {
+                        a: tl,
+                        b: tr,
+                        c: br,
+                    },
+                    Triangle {
+                        a: br,
+                        b: bl,
+                        c: tl,
+                    },
+                ]
+            })
+        });
+        if let Some(batch) = self.converter.convert(TesselateBatch::Color) {
+            self.stream.batch_optimized(batch);
+            self.stream.extend(vertices, triangles);
+        }
+    }
+
+    /// Sweeps triangle fan around center, starting at gradient angle so the seam between last
+    /// and first stop stays sharp instead of being smeared across grid cells.
+    fn produce_conic_gradient_triangles(
+        &mut self,
+        size: Vec2,
+        data: &Gradient,
+        center: Vec2,
+        angle: Scalar,
+    ) {
+        const SEGMENTS: usize = 64;
+
+        let matrix = self.top_transform();
+        let tau = std::f32::consts::TAU;
+        let mut angles = (0..=SEGMENTS)
+            .map(|index| index as Scalar / SEGMENTS as Scalar)
+            .chain(
+                [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+                    .into_iter()
+                    .map(|(x, y)| {
+                        ((y - center.y).atan2(x - center.x) - angle).rem_euclid(tau) / tau
+                    }),
+            )
+            .collect::<Vec<_>>();
+        angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let boundary = |factor: Scalar| {
+            let (dy, dx) = (angle + factor * tau).sin_cos();
+            let tx = if dx > 0.0 {
+                (1.0 - center.x) / dx
+            } else if dx < 0.0 {
+                -center.x / dx
+            } else {
+                Scalar::INFINITY
+            };
+            let ty = if dy > 0.0 {
+                (1.0 - center.y) / dy
+            } else if dy < 0.0 {
+                -center.y / dy
+            } else {
+                Scalar::INFINITY
+            };
+            let distance = tx.min(ty);
+            vek::Vec2::new(
+                (center.x + dx * distance) * size.x,
+                (center.y + dy * distance) * size.y,
+            )
+        };
+        let origin =
+            vec2_to_raui(matrix.mul_point(vek::Vec2::new(center.x * size.x, center.y * size.y)));
+        let mut vertices = Vec::with_capacity(angles.len() * 3);
+        for pair in angles.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if to - from <= Scalar::EPSILON {
+                continue;
+            }
+            vertices.push(Self::make_vertex(
+                origin,
+                Default::default(),
+                0.0,
+                data.sample((from + to) * 0.5),
+            ));
+            vertices.push(Self::make_vertex(
+                vec2_to_raui(matrix.mul_point(boundary(from))),
+                Default::default(),
+                0.0,
+                data.sample(from),
+            ));
+            vertices.push(Self::make_vertex(
+                vec2_to_raui(matrix.mul_point(boundary(to))),
+                Default::default(),
+                0.0,
+                data.sample(to),
+            ));
+        }
+        let triangles = (0..(vertices.len() / 3)).map(|index| {
+            let offset = index as u32 * 3;
+            Triangle {
+                a: offset,
+                b: offset + 1,
+                c: offset + 2,
+            }
+        });
+        if let Some(batch) = self.converter.convert(TesselateBatch::Color) {
+            self.stream.batch_optimized(batch);
+            self.stream.extend(vertices, triangles);
+        }
+    }
+
+    /// Frame edges size in real coordinates, shrunk to fit in `rect`.
+    fn frame_destination(frame: &ImageBoxFrame, size: Vec2, rect: Rect, scale: Vec2) -> Rect {
+        let mut d = frame.destination;
+        d.left *= scale.x;
+        d.right *= scale.x;
+        d.top *= scale.y;
+        d.bottom *= scale.y;
+        if frame.frame_keep_aspect_ratio {
+            d.left = (frame.source.left * rect.height()) / size.y;
+            d.right = (frame.source.right * rect.height()) / size.y;
+            d.top = (frame.source.top * rect.width()) / size.x;
+            d.bottom = (frame.source.bottom * rect.width()) / size.x;
+        }
+        if d.left + d.right > rect.width() {
+            let m = d.left + d.right;
+            d.left = rect.width() * d.left / m;
+            d.right = rect.width() * d.right / m;
+        }
+        if d.top + d.bottom > rect.height() {
+            let m = d.top + d.bottom;
+            d.top = rect.height() * d.top / m;
+            d.bottom = rect.height() * d.bottom / m;
+        }
+        d
+    }
+
+    /// Splits `from..to` range into tiles of `tile` size, aligned to `from + offset`. Tiles cut
+    /// by range bounds get matching part of `uv_from..uv_to` range.
+    fn tile_spans(
+        from: Scalar,
+        to: Scalar,
+        offset: Scalar,
+        tile: Scalar,
+        uv_from: Scalar,
+        uv_to: Scalar,
+    ) -> Vec<ImageSpan> {
+        if tile <= 0.0 || to <= from {
+            return vec![(from, to, uv_from, uv_to)];
+        }
+        let tile = tile.max((to - from) / MAX_IMAGE_TILES as Scalar);
+        let mut start = from + offset.rem_euclid(tile);
+        if start > from {
+            start -= tile;
+        }
+        let mut result = Vec::with_capacity(((to - start) / tile).ceil() as usize);
+        while start < to {
+            let end = start + tile;
+            let a = start.max(from);
+            let b = end.min(to);
+            if b > a {
+                result.push((
+                    a,
+                    b,
+                    lerp(uv_from, uv_to, (a - start) / tile),
+                    lerp(uv_from, uv_to, (b - start) / tile),
+                ));
+            }
+            start = end;
+        }
+        result
+    }
+
+    /// Splits `from..to` range into whole number of tiles, each sized as close to `tile` as
+    /// possible, so no tile gets cut.
+    fn repeat_spans(
+        from: Scalar,
+        to: Scalar,
+        tile: Scalar,
+        uv_from: Scalar,
+        uv_to: Scalar,
+    ) -> Vec<ImageSpan> {
+        let count = if tile > 0.0 {
+            ((to - from) / tile)
+                .round()
+                .clamp(1.0, MAX_IMAGE_TILES as Scalar) as usize
+        } else {
+            1
+        };
+        let step = (to - from) / count as Scalar;
+        (0..count)
+            .map(|index| {
+                let a = from + step * index as Scalar;
+                (a, a + step, uv_from, uv_to)
+            })
+            .collect()
+    }
+
+    /// Produces image quad for every pair of column and row spans, unless `skip` says otherwise.
+    fn produce_image_spans(
+        &mut self,
+        id: String,
+        columns: &[ImageSpan],
+        rows: &[ImageSpan],
+        color: Color,
+        skip: impl Fn(usize, usize) -> bool,
+    ) {
+        let Some(batch) = self.converter.convert(TesselateBatch::Image { id }) else {
+            return;
+        };
+        let matrix = self.top_transform();
+        let point = |x, y| vec2_to_raui(matrix.mul_point(vek::Vec2::new(x, y)));
+        self.stream.batch_optimized(batch);
+        for (row, (top, bottom, v_top, v_bottom)) in rows.iter().copied().enumerate() {
+            for (column, (left, right, u_left, u_right)) in columns.iter().copied().enumerate() {
+                if right <= left || bottom <= top || skip(column, row) {
+                    continue;
+                }
+                self.stream.quad([
+                    Self::make_vertex(
+                        point(left, top),
+                        Vec2 {
+                            x: u_left,
+                            y: v_top,
+                        },
+                        0.0,
+                        color,
+                    ),
+                    Self::make_vertex(
+                        point(right, top),
+                        Vec2 {
+                            x: u_right,
+                            y: v_top,
+                        },
+                        0.0,
+                        color,
+                    ),
+                    Self::make_vertex(
+                        point(right, bottom),
+                        Vec2 {
+                            x: u_right,
+                            y: v_bottom,
+                        },
+                        0.0,
+                        color,
+                    ),
+                    Self::make_vertex(
+                        point(left, bottom),
+                        Vec2 {
+                            x: u_left,
+                            y: v_bottom,
+                        },
+                        0.0,
+                        color,
+                    ),
+                ]);
+            }
+        }
+    }
+
+    /// Produces image quads, where `size` is size of image part of texture covered by `uvs`.
     fn produce_image_triangles(
         &mut self,
         id: String,
@@ -317,7 +916,103 @@ where
             y: uvs.bottom,
         };
         let c = data.tint;
+        let texel = Vec2 {
+            x: uvs.width() / size.x,
+            y: uvs.height() / size.y,
+        };
         match &data.scaling {
+            ImageBoxImageScaling::FrameRepeat(frame) => {
+                let d = Self::frame_destination(frame, size, rect, scale);
+                let source = frame.source;
+                let u_left = uvs.left + source.left * texel.x;
+                let u_right = uvs.right - source.right * texel.x;
+                let v_top = uvs.top + source.top * texel.y;
+                let v_bottom = uvs.bottom - source.bottom * texel.y;
+                // Middle parts are repeated at the same scale edges are drawn with.
+                let factor_x = if source.left > 0.0 {
+                    d.left / source.left
+                } else if source.right > 0.0 {
+                    d.right / source.right
+                } else {
+                    scale.x
+                };
+                let factor_y = if source.top > 0.0 {
+                    d.top / source.top
+                } else if source.bottom > 0.0 {
+                    d.bottom / source.bottom
+                } else {
+                    scale.y
+                };
+                let mut columns = vec![(rect.left, rect.left + d.left, uvs.left, u_left)];
+                columns.extend(Self::repeat_spans(
+                    rect.left + d.left,
+                    rect.right - d.right,
+                    (size.x - source.left - source.right) * factor_x,
+                    u_left,
+                    u_right,
+                ));
+                columns.push((rect.right - d.right, rect.right, u_right, uvs.right));
+                let mut rows = vec![(rect.top, rect.top + d.top, uvs.top, v_top)];
+                rows.extend(Self::repeat_spans(
+                    rect.top + d.top,
+                    rect.bottom - d.bottom,
+                    (size.y - source.top - source.bottom) * factor_y,
+                    v_top,
+                    v_bottom,
+                ));
+                rows.push((rect.bottom - d.bottom, rect.bottom, v_bottom, uvs.bottom));
+                let last_column = columns.len() - 1;
+                let last_row = rows.len() - 1;
+                self.produce_image_spans(id, &columns, &rows, c, |column, row| {
+                    frame.frame_only
+                        && column > 0
+                        && column < last_column
+                        && row > 0
+                        && row < last_row
+                });
+            }
+            ImageBoxImageScaling::Tile(tile) => {
+                let columns = Self::tile_spans(
+                    rect.left,
+                    rect.right,
+                    tile.offset.x * scale.x,
+                    size.x * tile.scale.x * scale.x,
+                    uvs.left,
+                    uvs.right,
+                );
+                let rows = Self::tile_spans(
+                    rect.top,
+                    rect.bottom,
+                    tile.offset.y * scale.y,
+                    size.y * tile.scale.y * scale.y,
+                    uvs.top,
+                    uvs.bottom,
+                );
+                self.produce_image_spans(id, &columns, &rows, c, |_, _| false);
+            }
+            ImageBoxImageScaling::Fit(fit) => {
+                let fit = ImageBoxFit {
+                    offset: Vec2 {
+                        x: fit.offset.x * scale.x,
+                        y: fit.offset.y * scale.y,
+                    },
+                    ..*fit
+                };
+                let (visible, factors) = fit.fit(size, rect);
+                let column = (
+                    visible.left,
+                    visible.right,
+                    lerp(uvs.left, uvs.right, factors.left),
+                    lerp(uvs.left, uvs.right, factors.right),
+                );
+                let row = (
+                    visible.top,
+                    visible.bottom,
+                    lerp(uvs.top, uvs.bottom, factors.top),
+                    lerp(uvs.top, uvs.bottom, factors.bottom),
+                );
+                self.produce_image_spans(id, &[column], &[row], c, |_, _| false);
+            }
             ImageBoxImageScaling::Stretch => {
                 if let Some(batch) = self.converter.convert(TesselateBatch::Image { id }) {
                     self.stream.batch_optimized(batch);
@@ -330,31 +1025,7 @@ where
                 }
             }
             ImageBoxImageScaling::Frame(frame) => {
-                let inv_size = Vec2 {
-                    x: 1.0 / size.x,
-                    y: 1.0 / size.y,
-                };
-                let mut d = frame.destination;
-                d.left *= scale.x;
-                d.right *= scale.x;
-                d.top *= scale.y;
-                d.bottom *= scale.y;
-                if frame.frame_keep_aspect_ratio {
-                    d.left = (frame.source.left * rect.height()) / size.y;
-                    d.right = (frame.source.right * rect.height()) / size.y;
-                    d.top = (frame.source.top * rect.width()) / size.x;
-                    d.bottom = (frame.source.bottom * rect.width()) / size.x;
-                }
-                if d.left + d.right > rect.width() {
-                    let m = d.left + d.right;
-                    d.left = rect.width() * d.left / m;
-                    d.right = rect.width() * d.right / m;
-                }
-                if d.top + d.bottom > rect.height() {
-                    let m = d.top + d.bottom;
-                    d.top = rect.height() * d.top / m;
-                    d.bottom = rect.height() * d.bottom / m;
-                }
+                let d = Self::frame_destination(frame, size, rect, scale);
                 let til =
                     vec2_to_raui(matrix.mul_point(vek::Vec2::new(rect.left + d.left, rect.top)));
                 let tir =
@@ -387,52 +1058,52 @@ where
                     matrix.mul_point(vek::Vec2::new(rect.left + d.left, rect.bottom - d.bottom)),
                 );
                 let ctil = Vec2 {
-                    x: uvs.left + frame.source.left * inv_size.x,
+                    x: uvs.left + frame.source.left * texel.x,
                     y: uvs.top,
                 };
                 let ctir = Vec2 {
-                    x: uvs.right - frame.source.right * inv_size.x,
+                    x: uvs.right - frame.source.right * texel.x,
                     y: uvs.top,
                 };
                 let citr = Vec2 {
                     x: uvs.right,
-                    y: uvs.top + frame.source.top * inv_size.y,
+                    y: uvs.top + frame.source.top * texel.y,
                 };
                 let cibr = Vec2 {
                     x: uvs.right,
-                    y: uvs.bottom - frame.source.bottom * inv_size.y,
+                    y: uvs.bottom - frame.source.bottom * texel.y,
                 };
                 let cbir = Vec2 {
-                    x: uvs.right - frame.source.right * inv_size.x,
+                    x: uvs.right - frame.source.right * texel.x,
                     y: uvs.bottom,
                 };
                 let cbil = Vec2 {
-                    x: uvs.left + frame.source.left * inv_size.x,
+                    x: uvs.left + frame.source.left * texel.x,
                     y: uvs.bottom,
                 };
                 let cibl = Vec2 {
                     x: uvs.left,
-                    y: uvs.bottom - frame.source.bottom * inv_size.y,
+                    y: uvs.bottom - frame.source.bottom * texel.y,
                 };
                 let citl = Vec2 {
                     x: uvs.left,
-                    y: uvs.top + frame.source.top * inv_size.y,
+                    y: uvs.top + frame.source.top * texel.y,
                 };
                 let citil = Vec2 {
-                    x: uvs.left + frame.source.left * inv_size.x,
-                    y: uvs.top + frame.source.top * inv_size.y,
+                    x: uvs.left + frame.source.left * texel.x,
+                    y: uvs.top + frame.source.top * texel.y,
                 };
                 let citir = Vec2 {
-                    x: uvs.right - frame.source.right * inv_size.x,
-                    y: uvs.top + frame.source.top * inv_size.y,
+                    x: uvs.right - frame.source.right * texel.x,
+                    y: uvs.top + frame.source.top * texel.y,
                 };
                 let cibir = Vec2 {
-                    x: uvs.right - frame.source.right * inv_size.x,
-                    y: uvs.bottom - frame.source.bottom * inv_size.y,
+                    x: uvs.right - frame.source.right * texel.x,
+                    y: uvs.bottom - frame.source.bottom * texel.y,
                 };
                 let cibil = Vec2 {
-                    x: uvs.left + frame.source.left * inv_size.x,
-                    y: uvs.bottom - frame.source.bottom * inv_size.y,
+                    x: uvs.left + frame.source.left * texel.x,
+                    y: uvs.bottom - frame.source.bottom * texel.y,
                 };
                 if let Some(batch) = self.converter.convert(TesselateBatch::Image { id }) {
                     self.stream.batch_optimized(batch);
@@ -505,6 +1176,559 @@ where
         }
     }
 
+    /// Renders image, optionally limited to `region` (in percentage) of its atlas area.
+    fn render_image(
+        &mut self,
+        unit: &ImageBox,
+        image: &ImageBoxImage,
+        region: Option<Rect>,
+        mapping: &CoordsMapping,
+        layout: &Layout,
+        local: bool,
+    ) -> Result<(), Error> {
+        if let Some(item) = layout.items.get(&unit.id) {
+            let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+            let rect = Rect {
+                left: 0.0,
+                right: local_space.width(),
+                top: 0.0,
+                bottom: local_space.height(),
+            };
+            let (id, mut uvs, size) = match self
+                .provider
+                .image_id_and_uv_and_size_by_atlas_id(&image.id)
+            {
+                Some(result) => result,
+                None => return Err(Error::ImageNotFound(image.id.to_owned())),
+            };
+            if let Some(region) = region {
+                uvs = Rect {
+                    left: lerp(uvs.left, uvs.right, region.left),
+                    right: lerp(uvs.left, uvs.right, region.right),
+                    top: lerp(uvs.top, uvs.bottom, region.top),
+                    bottom: lerp(uvs.top, uvs.bottom, region.bottom),
+                };
+            }
+            // Provider reports size of whole texture, while image covers only `uvs` part of it.
+            let size = Vec2 {
+                x: size.x * uvs.width(),
+                y: size.y * uvs.height(),
+            };
+            let rect = if let Some(aspect) = unit.content_keep_aspect_ratio {
+                let ox = rect.left;
+                let oy = rect.top;
+                let iw = rect.width();
+                let ih = rect.height();
+                let ra = size.x / size.y;
+                let ia = iw / ih;
+                let scale = if (ra >= ia) != aspect.outside {
+                    iw / size.x
+                } else {
+                    ih / size.y
+                };
+                let w = size.x * scale;
+                let h = size.y * scale;
+                let ow = lerp(0.0, iw - w, aspect.horizontal_alignment);
+                let oh = lerp(0.0, ih - h, aspect.vertical_alignment);
+                Rect {
+                    left: ox + ow,
+                    right: ox + ow + w,
+                    top: oy + oh,
+                    bottom: oy + oh + h,
+                }
+            } else {
+                rect
+            };
+            self.push_transform(&unit.transform, local_space);
+            self.produce_image_triangles(id, uvs, size, rect, mapping.scale(), image);
+            self.pop_transform();
+            Ok(())
+        } else {
+            Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+        }
+    }
+
+    fn render_image_box_material(
+        &mut self,
+        unit: &ImageBox,
+        mapping: &CoordsMapping,
+        layout: &Layout,
+        local: bool,
+    ) -> Result<(), Error> {
+        match &unit.material {
+            ImageBoxMaterial::Color(color) => {
+                if let Some(item) = layout.items.get(&unit.id) {
+                    let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+                    self.push_transform(&unit.transform, local_space);
+                    self.produce_color_triangles(local_space.size(), mapping.scale(), color);
+                    self.pop_transform();
+                    Ok(())
+                } else {
+                    Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+                }
+            }
+            ImageBoxMaterial::Gradient(gradient) => {
+                if let Some(item) = layout.items.get(&unit.id) {
+                    let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+                    self.push_transform(&unit.transform, local_space);
+                    self.produce_gradient_triangles(local_space.size(), gradient);
+                    self.pop_transform();
+                    Ok(())
+                } else {
+                    Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+                }
+            }
+            ImageBoxMaterial::Shape(shape) => {
+                if let Some(item) = layout.items.get(&unit.id) {
+                    let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+                    self.push_transform(&unit.transform, local_space);
+                    self.produce_shape_triangles(
+                        local_space.size(),
+                        mapping.scalar_scale(false),
+                        shape,
+                    );
+                    self.pop_transform();
+                    Ok(())
+                } else {
+                    Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+                }
+            }
+            ImageBoxMaterial::Image(image) => {
+                self.render_image(unit, image, None, mapping, layout, local)
+            }
+            ImageBoxMaterial::Flipbook(flipbook) => {
+                let Some((id, region)) = flipbook.frame_image() else {
+                    return Ok(());
+                };
+                let image = ImageBoxImage {
+                    id: id.to_owned(),
+                    source_rect: None,
+                    scaling: flipbook.scaling.clone(),
+                    tint: flipbook.tint,
+                };
+                self.render_image(unit, &image, Some(region), mapping, layout, local)
+            }
+            ImageBoxMaterial::Procedural(procedural) => {
+                if let Some(item) = layout.items.get(&unit.id) {
+                    let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+                    self.push_transform(&unit.transform, local_space);
+                    if let Some(batch) = self.converter.convert(TesselateBatch::Procedural {
+                        id: procedural.id.to_owned(),
+                        images: procedural.images.to_owned(),
+                        parameters: procedural.parameters.to_owned(),
+                    }) {
+                        let image_mapping =
+                            CoordsMapping::new_scaling(local_space, procedural.vertex_mapping);
+                        self.stream.batch_optimized(batch);
+                        match &procedural.mesh {
+                            ImageBoxProceduralMesh::Owned(mesh) => {
+                                self.stream.extend(
+                                    mesh.vertices.iter().map(|vertex| {
+                                        Self::make_vertex(
+                                            image_mapping
+                                                .virtual_to_real_vec2(vertex.position, false),
+                                            vertex.tex_coord,
+                                            vertex.page,
+                                            vertex.color,
+                                        )
+                                    }),
+                                    mesh.triangles.iter().map(|triangle| Triangle {
+                                        a: triangle[0],
+                                        b: triangle[1],
+                                        c: triangle[2],
+                                    }),
+                                );
+                            }
+                            ImageBoxProceduralMesh::Shared(mesh) => {
+                                self.stream.extend(
+                                    mesh.vertices.iter().map(|vertex| {
+                                        Self::make_vertex(
+                                            image_mapping
+                                                .virtual_to_real_vec2(vertex.position, false),
+                                            vertex.tex_coord,
+                                            vertex.page,
+                                            vertex.color,
+                                        )
+                                    }),
+                                    mesh.triangles.iter().map(|triangle| Triangle {
+                                        a: triangle[0],
+                                        b: triangle[1],
+                                        c: triangle[2],
+                                    }),
+                                );
+                            }
+                            ImageBoxProceduralMesh::Generator(generator) => {
+                                let mesh = (generator)(local_space, &procedural.parameters);
+                                self.stream.extend(
+                                    mesh.vertices.into_iter().map(|vertex| {
+                                        Self::make_vertex(
+                                            image_mapping
+                                                .virtual_to_real_vec2(vertex.position, false),
+                                            vertex.tex_coord,
+                                            vertex.page,
+                                            vertex.color,
+                                        )
+                                    }),
+                                    mesh.triangles.into_iter().map(|triangle| Triangle {
+                                        a: triangle[0],
+                                        b: triangle[1],
+                                        c: triangle[2],
+                                    }),
+                                );
+                            }
+                        }
+                    }
+                    self.pop_transform();
+                    Ok(())
+                } else {
+                    Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+                }
+            }
+        }
+    }
+
+    /// Outline of clip shape in local space, or `None` for shapes without one.
+    fn clip_shape_polygon(
+        shape: &ContentBoxClipShape,
+        size: Vec2,
+        scale: Scalar,
+    ) -> Option<Vec<vek::Vec2<Scalar>>> {
+        let result = match shape {
+            ContentBoxClipShape::Rectangle => vec![
+                vek::Vec2::new(0.0, 0.0),
+                vek::Vec2::new(size.x, 0.0),
+                vek::Vec2::new(size.x, size.y),
+                vek::Vec2::new(0.0, size.y),
+            ],
+            ContentBoxClipShape::RoundedRectangle { corner_radius } => {
+                let radius = Self::scaled_corners(corner_radius, scale, size);
+                let segments = Self::shape_segments(
+                    radius
+                        .top_left
+                        .max(radius.top_right)
+                        .max(radius.bottom_right)
+                        .max(radius.bottom_left),
+                );
+                let mut result = Vec::<vek::Vec2<Scalar>>::with_capacity(4 * (segments + 1));
+                for point in Self::shape_contour(size, &radius, segments, 0.0) {
+                    if result
+                        .last()
+                        .map(|last| last.distance_squared(point) > 1.0e-6)
+                        .unwrap_or(true)
+                    {
+                        result.push(point);
+                    }
+                }
+                result
+            }
+            ContentBoxClipShape::Circle => {
+                let rx = size.x * 0.5;
+                let ry = size.y * 0.5;
+                let segments = (rx.max(ry).sqrt() * 8.0).ceil().clamp(16.0, 128.0) as usize;
+                (0..segments)
+                    .map(|index| {
+                        let angle = index as Scalar / segments as Scalar * std::f32::consts::TAU;
+                        vek::Vec2::new(rx + rx * angle.cos(), ry + ry * angle.sin())
+                    })
+                    .collect()
+            }
+            ContentBoxClipShape::Quad { points } => points
+                .iter()
+                .map(|point| vek::Vec2::new(point.x * size.x, point.y * size.y))
+                .collect(),
+            ContentBoxClipShape::Mask { .. } => return None,
+        };
+        Some(result)
+    }
+
+    fn polygon_orientation(polygon: &[vek::Vec2<Scalar>]) -> Scalar {
+        let area = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|(a, b)| cross(*a, *b))
+            .sum::<Scalar>();
+        area.signum()
+    }
+
+    fn is_polygon_convex(polygon: &[vek::Vec2<Scalar>]) -> bool {
+        let orientation = Self::polygon_orientation(polygon);
+        let count = polygon.len();
+        count >= 3
+            && (0..count).all(|index| {
+                let a = polygon[index];
+                let b = polygon[(index + 1) % count];
+                let c = polygon[(index + 2) % count];
+                cross(b - a, c - b) * orientation >= -1.0e-4
+            })
+    }
+
+    fn is_polygon_axis_aligned_rect(polygon: &[vek::Vec2<Scalar>]) -> bool {
+        polygon.len() == 4
+            && (0..4).all(|index| {
+                let a = polygon[index];
+                let b = polygon[(index + 1) % 4];
+                (a.x - b.x).abs() < 1.0e-3 || (a.y - b.y).abs() < 1.0e-3
+            })
+    }
+
+    /// Clips polygon made of vertices against convex clip polygon (Sutherland-Hodgman).
+    fn clip_vertices(
+        vertices: Vec<V>,
+        polygon: &[vek::Vec2<Scalar>],
+        orientation: Scalar,
+    ) -> Vec<V> {
+        let mut result = vertices;
+        for (index, from) in polygon.iter().enumerate() {
+            if result.len() < 3 {
+                return vec![];
+            }
+            let to = polygon[(index + 1) % polygon.len()];
+            let edge = to - *from;
+            let distance = |vertex: &V| {
+                let [x, y] = vertex.position();
+                cross(edge, vek::Vec2::new(x, y) - *from) * orientation
+            };
+            let input = std::mem::take(&mut result);
+            for (index, current) in input.iter().enumerate() {
+                let next = &input[(index + 1) % input.len()];
+                let a = distance(current);
+                let b = distance(next);
+                if a >= 0.0 {
+                    result.push(*current);
+                }
+                if (a >= 0.0) != (b >= 0.0) {
+                    result.push(current.lerp(next, a / (a - b)));
+                }
+            }
+        }
+        result
+    }
+
+    fn clip_stream(&mut self, mut contents: VertexStream<V, B>, polygon: &[vek::Vec2<Scalar>]) {
+        let orientation = Self::polygon_orientation(polygon);
+        let (vertices, triangles, batches) = contents.drain();
+        let vertices = vertices.collect::<Vec<_>>();
+        let triangles = triangles.collect::<Vec<_>>();
+        let batches = batches.collect::<Vec<_>>();
+        for (batch, range) in batches {
+            self.stream.batch(batch);
+            for triangle in &triangles[range] {
+                let clipped = Self::clip_vertices(
+                    vec![
+                        vertices[triangle.a as usize],
+                        vertices[triangle.b as usize],
+                        vertices[triangle.c as usize],
+                    ],
+                    polygon,
+                    orientation,
+                );
+                self.stream.triangle_fan(clipped);
+            }
+            self.stream.batch_end();
+        }
+    }
+
+    fn clip_mask_geometry(
+        &self,
+        shape: &ContentBoxClipShape,
+        size: Vec2,
+        polygon: Option<&[vek::Vec2<Scalar>]>,
+    ) -> Result<ClipMask<V>, Error> {
+        let matrix = self.top_transform();
+        if let Some(polygon) = polygon {
+            // Fanning from reflex corner keeps triangles from overlapping for concave quads.
+            let count = polygon.len();
+            let orientation = Self::polygon_orientation(polygon);
+            let start = (0..count)
+                .find(|index| {
+                    let a = polygon[(index + count - 1) % count];
+                    let b = polygon[*index];
+                    let c = polygon[(index + 1) % count];
+                    cross(b - a, c - b) * orientation < 0.0
+                })
+                .unwrap_or_default();
+            let vertices = (0..count)
+                .map(|index| {
+                    let point = matrix.mul_point(polygon[(start + index) % count]);
+                    Self::make_vertex(
+                        vec2_to_raui(point),
+                        Default::default(),
+                        0.0,
+                        Default::default(),
+                    )
+                })
+                .collect();
+            let triangles = (1..count.saturating_sub(1))
+                .map(|index| Triangle {
+                    a: 0,
+                    b: index as u32,
+                    c: index as u32 + 1,
+                })
+                .collect();
+            return Ok(ClipMask {
+                image: None,
+                vertices,
+                triangles,
+            });
+        }
+        let ContentBoxClipShape::Mask { id } = shape else {
+            return Ok(ClipMask {
+                image: None,
+                vertices: vec![],
+                triangles: vec![],
+            });
+        };
+        let (id, uvs, _) = match self.provider.image_id_and_uv_and_size_by_atlas_id(id) {
+            Some(result) => result,
+            None => return Err(Error::ImageNotFound(id.to_owned())),
+        };
+        let vertices = [
+            (0.0, 0.0, uvs.left, uvs.top),
+            (size.x, 0.0, uvs.right, uvs.top),
+            (size.x, size.y, uvs.right, uvs.bottom),
+            (0.0, size.y, uvs.left, uvs.bottom),
+        ]
+        .into_iter()
+        .map(|(x, y, u, v)| {
+            Self::make_vertex(
+                vec2_to_raui(matrix.mul_point(vek::Vec2::new(x, y))),
+                Vec2 { x: u, y: v },
+                0.0,
+                Default::default(),
+            )
+        })
+        .collect();
+        let triangles = vec![Triangle { a: 0, b: 1, c: 2 }, Triangle { a: 2, b: 3, c: 0 }];
+        Ok(ClipMask {
+            image: Some(id),
+            vertices,
+            triangles,
+        })
+    }
+
+    fn render_scissor(
+        &mut self,
+        points: impl IntoIterator<Item = vek::Vec2<Scalar>>,
+        f: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let (min, max) = points.into_iter().fold(
+            (
+                vek::Vec2::broadcast(Scalar::INFINITY),
+                vek::Vec2::broadcast(Scalar::NEG_INFINITY),
+            ),
+            |(min, max), point| (min.map2(point, Scalar::min), max.map2(point, Scalar::max)),
+        );
+        let x = min.x.round();
+        let y = min.y.round();
+        let w = max.x.round() - x;
+        let h = max.y.round() - y;
+        if let Some(batch) = self
+            .converter
+            .convert(TesselateBatch::ClipPush { x, y, w, h })
+        {
+            self.stream.batch(batch);
+            self.stream.batch_end();
+        }
+        let result = f(self);
+        if let Some(batch) = self.converter.convert(TesselateBatch::ClipPop) {
+            self.stream.batch(batch);
+            self.stream.batch_end();
+        }
+        result
+    }
+
+    fn render_clip(
+        &mut self,
+        shape: &ContentBoxClipShape,
+        size: Vec2,
+        scale: Scalar,
+        f: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let matrix = self.top_transform();
+        let polygon = Self::clip_shape_polygon(shape, size, scale);
+        let bounds = polygon.clone().unwrap_or_else(|| {
+            Self::clip_shape_polygon(&ContentBoxClipShape::Rectangle, size, scale)
+                .unwrap_or_default()
+        });
+        let bounds = bounds
+            .into_iter()
+            .map(|point| matrix.mul_point(point))
+            .collect::<Vec<_>>();
+        // Scissor is the cheapest way to clip and works for all converters.
+        if shape.is_rectangle() && Self::is_polygon_axis_aligned_rect(&bounds) {
+            return self.render_scissor(bounds, f);
+        }
+        let ClipMask {
+            image,
+            vertices,
+            triangles,
+        } = self.clip_mask_geometry(shape, size, polygon.as_deref())?;
+        if let Some(batch) = self.converter.convert(TesselateBatch::ClipMaskPush {
+            image: image.clone(),
+        }) {
+            self.stream.batch(batch);
+            self.stream
+                .extend(vertices.iter().copied(), triangles.iter().copied());
+            self.stream.batch_end();
+            let result = f(self);
+            if let Some(batch) = self
+                .converter
+                .convert(TesselateBatch::ClipMaskPop { image })
+            {
+                self.stream.batch(batch);
+                self.stream.extend(vertices, triangles);
+                self.stream.batch_end();
+            }
+            return result;
+        }
+        if V::CAN_CLIP && polygon.is_some() && Self::is_polygon_convex(&bounds) {
+            let mut contents = self.stream.fork();
+            std::mem::swap(self.stream, &mut contents);
+            let result = f(self);
+            std::mem::swap(self.stream, &mut contents);
+            self.clip_stream(contents, &bounds);
+            result
+        } else {
+            self.render_scissor(bounds, f)
+        }
+    }
+
+    fn render_layer(
+        &mut self,
+        layer: &UnitLayer,
+        f: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        if layer.is_passthrough() {
+            return f(self);
+        }
+        let result = if let Some(batch) = self.converter.convert(TesselateBatch::LayerPush {
+            opacity: layer.opacity,
+            blend: layer.blend,
+        }) {
+            self.stream.batch(batch);
+            self.stream.batch_end();
+            f(self)
+        } else if V::CAN_MULTIPLY_ALPHA {
+            let mut contents = self.stream.fork();
+            std::mem::swap(self.stream, &mut contents);
+            let result = f(self);
+            std::mem::swap(self.stream, &mut contents);
+            let opacity = layer.opacity.clamp(0.0, 1.0);
+            self.stream.transformed(
+                |stream| stream.append(&mut contents),
+                |vertex| vertex.multiply_alpha(opacity),
+            );
+            result
+        } else {
+            f(self)
+        };
+        if let Some(batch) = self.converter.convert(TesselateBatch::LayerPop) {
+            self.stream.batch(batch);
+            self.stream.batch_end();
+        }
+        result
+    }
+
     fn render_node(
         &mut self,
         unit: &WidgetUnit,
@@ -535,35 +1759,23 @@ where
                     items.sort_unstable_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
                     let local_space = mapping.virtual_to_real_rect(item.local_space, local);
                     self.push_transform(&unit.transform, local_space);
+                    let render = |this: &mut Self| {
+                        this.render_layer(&unit.layer, |this| {
+                            for (_, item) in items {
+                                this.render_node(&item.slot, mapping, layout, true)?;
+                            }
+                            Ok(())
+                        })
+                    };
                     if unit.clipping {
-                        let size = local_space.size();
-                        let matrix = self.top_transform();
-                        let tl = matrix.mul_point(vek::Vec2::new(0.0, 0.0));
-                        let tr = matrix.mul_point(vek::Vec2::new(size.x, 0.0));
-                        let br = matrix.mul_point(vek::Vec2::new(size.x, size.y));
-                        let bl = matrix.mul_point(vek::Vec2::new(0.0, size.y));
-                        let x = tl.x.min(tr.x).min(br.x).min(bl.x).round();
-                        let y = tl.y.min(tr.y).min(br.y).min(bl.y).round();
-                        let x2 = tl.x.max(tr.x).max(br.x).max(bl.x).round();
-                        let y2 = tl.y.max(tr.y).max(br.y).max(bl.y).round();
-                        let w = x2 - x;
-                        let h = y2 - y;
-                        if let Some(batch) =
-                            self.converter
-                                .convert(TesselateBatch::ClipPush { x, y, w, h })
-                        {
-                            self.stream.batch(batch);
-                            self.stream.batch_end();
-                        }
-                    }
-                    for (_, item) in items {
-                        self.render_node(&item.slot, mapping, layout, true)?;
-                    }
-                    if unit.clipping
-                        && let Some(batch) = self.converter.convert(TesselateBatch::ClipPop)
-                    {
-                        self.stream.batch(batch);
-                        self.stream.batch_end();
+                        self.render_clip(
+                            &unit.clip_shape,
+                            local_space.size(),
+                            mapping.scalar_scale(false),
+                            render,
+                        )?;
+                    } else {
+                        render(self)?;
                     }
                     self.pop_transform();
                     Ok(())
@@ -575,9 +1787,12 @@ where
                 if let Some(item) = layout.items.get(&unit.id) {
                     let local_space = mapping.virtual_to_real_rect(item.local_space, local);
                     self.push_transform(&unit.transform, local_space);
-                    for item in &unit.items {
-                        self.render_node(&item.slot, mapping, layout, true)?;
-                    }
+                    self.render_layer(&unit.layer, |this| {
+                        for item in &unit.items {
+                            this.render_node(&item.slot, mapping, layout, true)?;
+                        }
+                        Ok(())
+                    })?;
                     self.pop_transform();
                     Ok(())
                 } else {
@@ -588,9 +1803,12 @@ where
                 if let Some(item) = layout.items.get(&unit.id) {
                     let local_space = mapping.virtual_to_real_rect(item.local_space, local);
                     self.push_transform(&unit.transform, local_space);
-                    for item in &unit.items {
-                        self.render_node(&item.slot, mapping, layout, true)?;
-                    }
+                    self.render_layer(&unit.layer, |this| {
+                        for item in &unit.items {
+                            this.render_node(&item.slot, mapping, layout, true)?;
+                        }
+                        Ok(())
+                    })?;
                     self.pop_transform();
                     Ok(())
                 } else {
@@ -608,144 +1826,28 @@ where
                     Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
                 }
             }
-            WidgetUnit::ImageBox(unit) => match &unit.material {
-                ImageBoxMaterial::Color(color) => {
-                    if let Some(item) = layout.items.get(&unit.id) {
-                        let local_space = mapping.virtual_to_real_rect(item.local_space, local);
-                        self.push_transform(&unit.transform, local_space);
-                        self.produce_color_triangles(local_space.size(), mapping.scale(), color);
-                        self.pop_transform();
-                        Ok(())
-                    } else {
-                        Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
-                    }
-                }
-                ImageBoxMaterial::Image(image) => {
-                    if let Some(item) = layout.items.get(&unit.id) {
-                        let local_space = mapping.virtual_to_real_rect(item.local_space, local);
-                        let rect = Rect {
-                            left: 0.0,
-                            right: local_space.width(),
-                            top: 0.0,
-                            bottom: local_space.height(),
-                        };
-                        let (id, uvs, size) = match self
-                            .provider
-                            .image_id_and_uv_and_size_by_atlas_id(&image.id)
-                        {
-                            Some(result) => result,
-                            None => return Err(Error::ImageNotFound(image.id.to_owned())),
-                        };
-                        let rect = if let Some(aspect) = unit.content_keep_aspect_ratio {
-                            let ox = rect.left;
-                            let oy = rect.top;
-                            let iw = rect.width();
-                            let ih = rect.height();
-                            let ra = size.x / size.y;
-                            let ia = iw / ih;
-                            let scale = if (ra >= ia) != aspect.outside {
-                                iw / size.x
-                            } else {
-                                ih / size.y
-                            };
-                            let w = size.x * scale;
-                            let h = size.y * scale;
-                            let ow = lerp(0.0, iw - w, aspect.horizontal_alignment);
-                            let oh = lerp(0.0, ih - h, aspect.vertical_alignment);
-                            Rect {
-                                left: ox + ow,
-                                right: ox + ow + w,
-                                top: oy + oh,
-                                bottom: oy + oh + h,
-                            }
-                        } else {
-                            rect
-                        };
-                        self.push_transform(&unit.transform, local_space);
-                        self.produce_image_triangles(id, uvs, size, rect, mapping.scale(), image);
-                        self.pop_transform();
-                        Ok(())
-                    } else {
-                        Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
-                    }
-                }
-                ImageBoxMaterial::Procedural(procedural) => {
-                    if let Some(item) = layout.items.get(&unit.id) {
-                        let local_space = mapping.virtual_to_real_rect(item.local_space, local);
-                        self.push_transform(&unit.transform, local_space);
-                        if let Some(batch) = self.converter.convert(TesselateBatch::Procedural {
-                            id: procedural.id.to_owned(),
-                            images: procedural.images.to_owned(),
-                            parameters: procedural.parameters.to_owned(),
-                        }) {
-                            let image_mapping =
-                                CoordsMapping::new_scaling(local_space, procedural.vertex_mapping);
-                            self.stream.batch_optimized(batch);
-                            match &procedural.mesh {
-                                ImageBoxProceduralMesh::Owned(mesh) => {
-                                    self.stream.extend(
-                                        mesh.vertices.iter().map(|vertex| {
-                                            Self::make_vertex(
-                                                image_mapping
-                                                    .virtual_to_real_vec2(vertex.position, false),
-                                                vertex.tex_coord,
-                                                vertex.page,
-                                                vertex.color,
-                                            )
-                                        }),
-                                        mesh.triangles.iter().map(|triangle| Triangle {
-                                            a: triangle[0],
-                                            b: triangle[1],
-                                            c: triangle[2],
-                                        }),
-                                    );
-                                }
-                                ImageBoxProceduralMesh::Shared(mesh) => {
-                                    self.stream.extend(
-                                        mesh.vertices.iter().map(|vertex| {
-                                            Self::make_vertex(
-                                                image_mapping
-                                                    .virtual_to_real_vec2(vertex.position, false),
-                                                vertex.tex_coord,
-                                                vertex.page,
-                                                vertex.color,
-                                            )
-                                        }),
-                                        mesh.triangles.iter().map(|triangle| Triangle {
-                                            a: triangle[0],
-                                            b: triangle[1],
-                                            c: triangle[2],
-                                        }),
-                                    );
-                                }
-                                ImageBoxProceduralMesh::Generator(generator) => {
-                                    let mesh = (generator)(local_space, &procedural.parameters);
-                                    self.stream.extend(
-                                        mesh.vertices.into_iter().map(|vertex| {
-                                            Self::make_vertex(
-                                                image_mapping
-                                                    .virtual_to_real_vec2(vertex.position, false),
-                                                vertex.tex_coord,
-                                                vertex.page,
-                                                vertex.color,
-                                            )
-                                        }),
-                                        mesh.triangles.into_iter().map(|triangle| Triangle {
-                                            a: triangle[0],
-                                            b: triangle[1],
-                                            c: triangle[2],
-                                        }),
-                                    );
-                                }
-                            }
-                        }
-                        self.pop_transform();
-                        Ok(())
-                    } else {
-                        Err(Error::WidgetHasNoLayout(unit.id.to_owned()))
+            WidgetUnit::ImageBox(unit) => {
+                if !unit.shadows.is_empty()
+                    && let Some(item) = layout.items.get(&unit.id)
+                {
+                    let local_space = mapping.virtual_to_real_rect(item.local_space, local);
+                    let corner_radius = match &unit.material {
+                        ImageBoxMaterial::Shape(shape) => shape.corner_radius,
+                        _ => Default::default(),
+                    };
+                    self.push_transform(&unit.transform, local_space);
+                    for shadow in &unit.shadows {
+                        self.produce_shadow_triangles(
+                            local_space.size(),
+                            mapping.scalar_scale(false),
+                            &corner_radius,
+                            shadow,
+                        );
                     }
+                    self.pop_transform();
                 }
-            },
+                self.render_image_box_material(unit, mapping, layout, local)
+            }
             WidgetUnit::TextBox(unit) => {
                 let font_index = match self.provider.font_index_by_id(&unit.font.name) {
                     Some(index) => index,
@@ -784,7 +1886,63 @@ where
                                 });
                                 layout.append(self.provider.fonts(), &text);
                                 self.text_renderer.include(self.provider.fonts(), &layout);
-                                self.text_renderer.render_to_stream(stream);
+                                if unit.gradient.is_none() && unit.shadows.is_empty() {
+                                    self.text_renderer.render_to_stream(stream);
+                                    return;
+                                }
+                                let mut glyphs = VertexStream::<GlyphVertex, ()>::default();
+                                self.text_renderer.render_to_stream(&mut glyphs);
+                                let scale = mapping.scalar_scale(false);
+                                for shadow in &unit.shadows {
+                                    for (offset, factor) in Self::text_shadow_samples(shadow, scale)
+                                    {
+                                        let color = [
+                                            shadow.color.r,
+                                            shadow.color.g,
+                                            shadow.color.b,
+                                            shadow.color.a * factor,
+                                        ];
+                                        stream.extend(
+                                            glyphs.vertices().iter().map(|glyph| {
+                                                let mut vertex = V::default();
+                                                TesselateVertex::apply(
+                                                    &mut vertex,
+                                                    [
+                                                        glyph.position[0] + offset.x,
+                                                        glyph.position[1] + offset.y,
+                                                    ],
+                                                    glyph.tex_coord,
+                                                    color,
+                                                );
+                                                vertex
+                                            }),
+                                            glyphs.triangles().iter().copied(),
+                                        );
+                                    }
+                                }
+                                stream.extend(
+                                    glyphs.vertices().iter().map(|glyph| {
+                                        let color = match &unit.gradient {
+                                            Some(gradient) => {
+                                                let color = gradient.color_at(Vec2 {
+                                                    x: glyph.position[0] / size.x.max(1.0),
+                                                    y: glyph.position[1] / size.y.max(1.0),
+                                                });
+                                                [color.r, color.g, color.b, color.a]
+                                            }
+                                            None => glyph.color,
+                                        };
+                                        let mut vertex = V::default();
+                                        TesselateVertex::apply(
+                                            &mut vertex,
+                                            glyph.position,
+                                            glyph.tex_coord,
+                                            color,
+                                        );
+                                        vertex
+                                    }),
+                                    glyphs.triangles().iter().copied(),
+                                );
                             },
                             |vertex| {
                                 vertex.transform(matrix);
@@ -922,6 +2080,10 @@ where
     }
 }
 
+fn cross(a: vek::Vec2<Scalar>, b: vek::Vec2<Scalar>) -> Scalar {
+    a.x * b.y - a.y * b.x
+}
+
 fn raui_to_vec2(v: Vec2) -> vek::Vec2<Scalar> {
     vek::Vec2::new(v.x, v.y)
 }
@@ -929,3 +2091,841 @@ fn raui_to_vec2(v: Vec2) -> vek::Vec2<Scalar> {
 fn vec2_to_raui(v: vek::Vec2<Scalar>) -> Vec2 {
     Vec2 { x: v.x, y: v.y }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raui_core::{
+        application::Application,
+        layout::default_layout_engine::DefaultLayoutEngine,
+        make_widget,
+        widget::{
+            component::{
+                containers::content_box::{ContentBoxProps, content_box},
+                image_box::{ImageBoxProps, image_box},
+            },
+            node::WidgetNode,
+            unit::image::{ImageBoxFlipbook, ImageBoxFlipbookFrames, ImageBoxTile},
+        },
+    };
+
+    /// Passes all batches except the ones listed.
+    struct Rejecting(fn(&TesselateBatch) -> bool);
+
+    impl TesselateBatchConverter<TesselateBatch> for Rejecting {
+        fn convert(&mut self, batch: TesselateBatch) -> Option<TesselateBatch> {
+            (!(self.0)(&batch)).then_some(batch)
+        }
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Pod, Zeroable)]
+    struct TestVertex {
+        position: [f32; 2],
+        tex_coord: [f32; 3],
+        color: [f32; 4],
+    }
+
+    impl TesselateVertex for TestVertex {
+        const CAN_MULTIPLY_ALPHA: bool = true;
+        const CAN_CLIP: bool = true;
+
+        fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: [f32; 4]) {
+            self.position = position;
+            self.tex_coord = tex_coord;
+            self.color = color;
+        }
+
+        fn transform(&mut self, matrix: vek::Mat4<f32>) {
+            let result = matrix.mul_point(vek::Vec2::from(self.position));
+            self.position = [result.x, result.y];
+        }
+
+        fn multiply_alpha(&mut self, factor: f32) {
+            self.color[3] *= factor;
+        }
+
+        fn position(&self) -> [f32; 2] {
+            self.position
+        }
+
+        fn lerp(&self, other: &Self, factor: f32) -> Self {
+            fn lerp<const N: usize>(from: [f32; N], to: [f32; N], factor: f32) -> [f32; N] {
+                std::array::from_fn(|index| from[index] + (to[index] - from[index]) * factor)
+            }
+
+            Self {
+                position: lerp(self.position, other.position, factor),
+                tex_coord: lerp(self.tex_coord, other.tex_coord, factor),
+                color: lerp(self.color, other.color, factor),
+            }
+        }
+    }
+
+    impl TextVertex<Color> for TestVertex {
+        fn apply(&mut self, position: [f32; 2], tex_coord: [f32; 3], color: Color) {
+            self.position = position;
+            self.tex_coord = tex_coord;
+            self.color = [color.r, color.g, color.b, color.a];
+        }
+    }
+
+    struct TestProvider;
+
+    impl TesselateResourceProvider for TestProvider {
+        fn image_id_and_uv_and_size_by_atlas_id(&self, id: &str) -> Option<(String, Rect, Vec2)> {
+            Some((
+                id.to_owned(),
+                Rect {
+                    left: 0.0,
+                    right: 1.0,
+                    top: 0.0,
+                    bottom: 1.0,
+                },
+                Vec2 { x: 10.0, y: 10.0 },
+            ))
+        }
+
+        fn fonts(&self) -> &[Font] {
+            &[]
+        }
+
+        fn font_index_by_id(&self, _: &str) -> Option<usize> {
+            None
+        }
+    }
+
+    /// Vertex without optional capabilities.
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone, Pod, Zeroable)]
+    struct PlainVertex {
+        position: [f32; 2],
+    }
+
+    impl TesselateVertex for PlainVertex {
+        fn apply(&mut self, position: [f32; 2], _: [f32; 3], _: [f32; 4]) {
+            self.position = position;
+        }
+
+        fn transform(&mut self, _: vek::Mat4<f32>) {}
+    }
+
+    impl TextVertex<Color> for PlainVertex {
+        fn apply(&mut self, position: [f32; 2], _: [f32; 3], _: Color) {
+            self.position = position;
+        }
+    }
+
+    fn render(
+        tree: impl Into<WidgetNode>,
+        converter: &mut impl TesselateBatchConverter<TesselateBatch>,
+    ) -> VertexStream<TestVertex, TesselateBatch> {
+        render_as(tree, converter)
+    }
+
+    fn render_as<V: TesselateVertex + TextVertex<Color> + Default>(
+        tree: impl Into<WidgetNode>,
+        converter: &mut impl TesselateBatchConverter<TesselateBatch>,
+    ) -> VertexStream<V, TesselateBatch> {
+        let mut application = Application::default();
+        application.setup(raui_core::widget::setup);
+        application.apply(tree);
+        application.forced_process();
+        let mapping = CoordsMapping::new(Rect {
+            left: 0.0,
+            right: 10.0,
+            top: 0.0,
+            bottom: 10.0,
+        });
+        application
+            .layout(&mapping, &mut DefaultLayoutEngine::<()>::default())
+            .unwrap();
+        let mut stream = VertexStream::default();
+        let mut text_renderer = TextRenderer::new(64, 64);
+        TesselateRenderer::new(
+            &TestProvider,
+            converter,
+            &mut stream,
+            &mut text_renderer,
+            None,
+        )
+        .render(
+            application.rendered_tree(),
+            &mapping,
+            application.layout_data(),
+        )
+        .unwrap();
+        stream
+    }
+
+    fn bounds(vertices: &[TestVertex]) -> [f32; 4] {
+        vertices.iter().fold(
+            [
+                Scalar::INFINITY,
+                Scalar::INFINITY,
+                Scalar::NEG_INFINITY,
+                Scalar::NEG_INFINITY,
+            ],
+            |[left, top, right, bottom], vertex| {
+                [
+                    left.min(vertex.position[0]),
+                    top.min(vertex.position[1]),
+                    right.max(vertex.position[0]),
+                    bottom.max(vertex.position[1]),
+                ]
+            },
+        )
+    }
+
+    fn assert_bounds(vertices: &[TestVertex], expected: [f32; 4]) {
+        let bounds = bounds(vertices);
+        for (value, expected) in bounds.into_iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 1.0e-3,
+                "{bounds:?} != {expected:?}"
+            );
+        }
+    }
+
+    fn shadow_box(shadows: Vec<Shadow>) -> impl Into<WidgetNode> {
+        make_widget!(image_box).with_props(ImageBoxProps {
+            shadows,
+            ..ImageBoxProps::colored(Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            })
+        })
+    }
+
+    #[test]
+    fn test_shadow() {
+        let material = render(shadow_box(vec![]), &mut ());
+        let stream = render(
+            shadow_box(vec![Shadow {
+                offset: Vec2 { x: 2.0, y: 3.0 },
+                blur: 2.0,
+                spread: 1.0,
+                color: Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.5,
+                },
+            }]),
+            &mut (),
+        );
+        let count = material.vertices().len();
+        let (shadow, rest) = stream.vertices().split_at(stream.vertices().len() - count);
+        assert_eq!(rest, material.vertices());
+        // Outer edge is grown by spread and half of blur, then moved by offset.
+        assert_bounds(shadow, [0.0, 1.0, 14.0, 15.0]);
+        assert!(shadow.iter().all(|vertex| vertex.color[3] <= 0.5));
+        assert!(shadow.iter().any(|vertex| vertex.color[3] == 0.0));
+
+        let stream = render(
+            shadow_box(vec![Shadow {
+                blur: 4.0,
+                color: Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                },
+                ..Default::default()
+            }]),
+            &mut (),
+        );
+        assert_eq!(stream.vertices(), material.vertices());
+        assert_eq!(stream.triangles().len(), material.triangles().len());
+    }
+
+    #[test]
+    fn test_text_shadow_samples() {
+        type Renderer<'a> = TesselateRenderer<'a, TestVertex, TesselateBatch, TestProvider, ()>;
+
+        let shadow = Shadow {
+            offset: Vec2 { x: 1.0, y: 2.0 },
+            ..Default::default()
+        };
+        assert_eq!(
+            Renderer::text_shadow_samples(&shadow, 2.0),
+            vec![(vek::Vec2::new(2.0, 4.0), 1.0)]
+        );
+        let samples = Renderer::text_shadow_samples(
+            &Shadow {
+                spread: 1.0,
+                blur: 2.0,
+                ..shadow
+            },
+            1.0,
+        );
+        assert_eq!(samples.len(), 9);
+        for (offset, factor) in &samples[1..] {
+            assert!((offset.distance(vek::Vec2::new(1.0, 2.0)) - 2.0).abs() < 1.0e-4);
+            assert_eq!(*factor, 1.0 / 3.0);
+        }
+    }
+
+    fn layer_box(layer: UnitLayer) -> impl Into<WidgetNode> {
+        make_widget!(content_box)
+            .with_props(ContentBoxProps {
+                layer,
+                ..Default::default()
+            })
+            .listed_slot(shadow_box(vec![]))
+    }
+
+    fn batches<V: TesselateVertex>(
+        stream: &VertexStream<V, TesselateBatch>,
+    ) -> Vec<TesselateBatch> {
+        stream
+            .batches()
+            .iter()
+            .map(|(batch, _)| batch.to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_layer() {
+        let layer = UnitLayer {
+            opacity: 0.5,
+            blend: BlendMode::Additive,
+        };
+        let stream = render(layer_box(layer), &mut ());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::LayerPush {
+                    opacity: 0.5,
+                    blend: BlendMode::Additive,
+                },
+                TesselateBatch::Color,
+                TesselateBatch::LayerPop,
+            ]
+        );
+        assert!(
+            stream
+                .vertices()
+                .iter()
+                .all(|vertex| vertex.color[3] == 1.0)
+        );
+
+        let stream = render(layer_box(Default::default()), &mut ());
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+    }
+
+    #[test]
+    fn test_layer_fallback() {
+        let mut converter = Rejecting(|batch| {
+            matches!(
+                batch,
+                TesselateBatch::LayerPush { .. } | TesselateBatch::LayerPop
+            )
+        });
+        let stream = render(
+            layer_box(UnitLayer {
+                opacity: 0.5,
+                blend: BlendMode::Normal,
+            }),
+            &mut converter,
+        );
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        assert!(!stream.vertices().is_empty());
+        assert!(
+            stream
+                .vertices()
+                .iter()
+                .all(|vertex| vertex.color[3] == 0.5)
+        );
+    }
+
+    fn clip_box(clip_shape: ContentBoxClipShape) -> impl Into<WidgetNode> {
+        make_widget!(content_box)
+            .with_props(ContentBoxProps {
+                clipping: true,
+                clip_shape,
+                ..Default::default()
+            })
+            .listed_slot(shadow_box(vec![]))
+    }
+
+    fn rejecting_clip_mask() -> Rejecting {
+        Rejecting(|batch| matches!(batch, TesselateBatch::ClipMaskPush { .. }))
+    }
+
+    fn diamond() -> ContentBoxClipShape {
+        ContentBoxClipShape::quad([
+            Vec2 { x: 0.5, y: 0.0 },
+            Vec2 { x: 1.0, y: 0.5 },
+            Vec2 { x: 0.5, y: 1.0 },
+            Vec2 { x: 0.0, y: 0.5 },
+        ])
+    }
+
+    #[test]
+    fn test_clip_quad() {
+        let stream = render(clip_box(diamond()), &mut ());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::ClipMaskPush { image: None },
+                TesselateBatch::Color,
+                TesselateBatch::ClipMaskPop { image: None },
+            ]
+        );
+        let (_, range) = &stream.batches()[0];
+        assert_eq!(range.len(), 2);
+
+        let stream = render(clip_box(diamond()), &mut rejecting_clip_mask());
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        assert!(stream.triangles().len() > 2);
+        assert_bounds(stream.vertices(), [0.0, 0.0, 10.0, 10.0]);
+        assert!(stream.vertices().iter().all(|vertex| {
+            let [x, y] = vertex.position;
+            (x - 5.0).abs() + (y - 5.0).abs() <= 5.0 + 1.0e-3
+        }));
+
+        let stream = render_as::<PlainVertex>(clip_box(diamond()), &mut rejecting_clip_mask());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::ClipPush {
+                    x: 0.0,
+                    y: 0.0,
+                    w: 10.0,
+                    h: 10.0,
+                },
+                TesselateBatch::Color,
+                TesselateBatch::ClipPop,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_rounded() {
+        let shape = || ContentBoxClipShape::rounded_rectangle(5.0);
+        let stream = render(clip_box(shape()), &mut ());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::ClipMaskPush { image: None },
+                TesselateBatch::Color,
+                TesselateBatch::ClipMaskPop { image: None },
+            ]
+        );
+
+        let stream = render(clip_box(shape()), &mut rejecting_clip_mask());
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        assert_bounds(stream.vertices(), [0.0, 0.0, 10.0, 10.0]);
+        assert!(stream.vertices().iter().all(|vertex| {
+            vek::Vec2::from(vertex.position).distance(vek::Vec2::new(5.0, 5.0)) <= 5.0 + 1.0e-3
+        }));
+    }
+
+    #[test]
+    fn test_clip_mask() {
+        let shape = || ContentBoxClipShape::Mask {
+            id: "mask".to_owned(),
+        };
+        let stream = render(clip_box(shape()), &mut ());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::ClipMaskPush {
+                    image: Some("mask".to_owned()),
+                },
+                TesselateBatch::Color,
+                TesselateBatch::ClipMaskPop {
+                    image: Some("mask".to_owned()),
+                },
+            ]
+        );
+        for index in [0, 2] {
+            let (_, range) = &stream.batches()[index];
+            let triangles = &stream.triangles()[range.to_owned()];
+            assert_eq!(triangles.len(), 2);
+            let vertices = triangles
+                .iter()
+                .flat_map(|triangle| [triangle.a, triangle.b, triangle.c])
+                .map(|index| stream.vertices()[index as usize])
+                .collect::<Vec<_>>();
+            assert_bounds(&vertices, [0.0, 0.0, 10.0, 10.0]);
+        }
+
+        let stream = render(clip_box(shape()), &mut rejecting_clip_mask());
+        assert_eq!(
+            batches(&stream),
+            vec![
+                TesselateBatch::ClipPush {
+                    x: 0.0,
+                    y: 0.0,
+                    w: 10.0,
+                    h: 10.0,
+                },
+                TesselateBatch::Color,
+                TesselateBatch::ClipPop,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_outside() {
+        let shape = ContentBoxClipShape::quad([
+            Vec2 { x: 2.0, y: 0.0 },
+            Vec2 { x: 3.0, y: 0.5 },
+            Vec2 { x: 2.0, y: 1.0 },
+            Vec2 { x: 1.5, y: 0.5 },
+        ]);
+        let stream = render(clip_box(shape), &mut rejecting_clip_mask());
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        assert!(stream.triangles().is_empty());
+    }
+
+    fn assert_vec(value: &[f32], expected: &[f32]) {
+        assert_eq!(value.len(), expected.len(), "{value:?} != {expected:?}");
+        for (value_item, expected_item) in value.iter().zip(expected) {
+            assert!(
+                (value_item - expected_item).abs() < 1.0e-3,
+                "{value:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_shape_rings() {
+        let fill = Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        };
+        let stroke = Color {
+            r: 0.0,
+            g: 0.0,
+            b: 1.0,
+            a: 1.0,
+        };
+        let transparent = |color: Color| [color.r, color.g, color.b, 0.0];
+        let opaque = |color: Color| [color.r, color.g, color.b, color.a];
+
+        // Radius of 2 gets 3 segments per corner, so every ring has 4 * (3 + 1) points.
+        let stream = render(
+            make_widget!(image_box).with_props(ImageBoxProps::shape(
+                ImageBoxShape::new(fill).corner_radius(2.0),
+            )),
+            &mut (),
+        );
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        let vertices = stream.vertices();
+        assert_eq!(vertices.len(), 2 * 16 + 1);
+        assert_eq!(stream.triangles().len(), 16 * 2 + 16);
+        let (outer, rest) = vertices.split_at(16);
+        let (inner, center) = rest.split_at(16);
+        assert_bounds(outer, [0.0, 0.0, 10.0, 10.0]);
+        assert_bounds(inner, [1.0, 1.0, 9.0, 9.0]);
+        assert!(outer.iter().all(|vertex| vertex.color == transparent(fill)));
+        assert!(inner.iter().all(|vertex| vertex.color == opaque(fill)));
+        assert!(
+            vertices
+                .iter()
+                .all(|vertex| vertex.tex_coord == [0.0, 0.0, 0.0])
+        );
+        // Top-left corner arc starts at its left end and ends at its top end.
+        assert_vec(&outer[0].position, &[0.0, 2.0]);
+        assert_vec(&outer[3].position, &[2.0, 0.0]);
+        assert_vec(&inner[0].position, &[1.0, 2.0]);
+        assert_vec(&inner[3].position, &[2.0, 1.0]);
+        assert_eq!(center[0].position, [5.0, 5.0]);
+        assert_eq!(center[0].color, opaque(fill));
+
+        let stream = render(
+            make_widget!(image_box).with_props(ImageBoxProps::shape(
+                ImageBoxShape::new(fill)
+                    .corner_radius(2.0)
+                    .stroke(2.0, stroke),
+            )),
+            &mut (),
+        );
+        let vertices = stream.vertices();
+        assert_eq!(vertices.len(), 4 * 16 + 1);
+        let rings = vertices[..64].chunks(16).collect::<Vec<_>>();
+        for (ring, (inset, color)) in rings.iter().zip([
+            (0.0, transparent(stroke)),
+            (1.0, opaque(stroke)),
+            (1.0, opaque(stroke)),
+            (3.0, opaque(fill)),
+        ]) {
+            assert_bounds(ring, [inset, inset, 10.0 - inset, 10.0 - inset]);
+            assert!(ring.iter().all(|vertex| vertex.color == color));
+        }
+        assert_eq!(vertices[64].position, [5.0, 5.0]);
+        assert_eq!(vertices[64].color, opaque(fill));
+    }
+
+    #[test]
+    fn test_gradient_vertices() {
+        let red = Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        };
+        let blue = Color {
+            r: 0.0,
+            g: 0.0,
+            b: 1.0,
+            a: 1.0,
+        };
+        let rgba = |color: Color| [color.r, color.g, color.b, color.a];
+        let gradient_box = |gradient: Gradient| {
+            make_widget!(image_box).with_props(ImageBoxProps::gradient(gradient))
+        };
+
+        // Two-stop linear gradient interpolates exactly between corners.
+        let stream = render(
+            gradient_box(Gradient::linear(0.0).stop(0.0, red).stop(1.0, blue)),
+            &mut (),
+        );
+        assert_eq!(batches(&stream), vec![TesselateBatch::Color]);
+        let vertices = stream.vertices();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(stream.triangles().len(), 2);
+        for (vertex, (position, color)) in vertices.iter().zip([
+            ([0.0, 0.0], red),
+            ([10.0, 0.0], blue),
+            ([0.0, 10.0], red),
+            ([10.0, 10.0], blue),
+        ]) {
+            assert_eq!(vertex.position, position);
+            assert_vec(&vertex.color, &rgba(color));
+            assert_eq!(vertex.tex_coord, [0.0, 0.0, 0.0]);
+        }
+
+        // Anything else is sampled on a grid of cells no bigger than 8 units.
+        let gradient = Gradient::radial(0.5, 0.5).stop(0.0, red).stop(1.0, blue);
+        let stream = render(gradient_box(gradient.clone()), &mut ());
+        let vertices = stream.vertices();
+        assert_eq!(vertices.len(), 3 * 3);
+        assert_eq!(stream.triangles().len(), 2 * 2 * 2);
+        for (index, vertex) in vertices.iter().enumerate() {
+            let point = Vec2 {
+                x: (index % 3) as Scalar * 0.5,
+                y: (index / 3) as Scalar * 0.5,
+            };
+            assert_eq!(vertex.position, [point.x * 10.0, point.y * 10.0]);
+            assert_vec(&vertex.color, &rgba(gradient.color_at(point)));
+        }
+        assert_vec(&vertices[4].color, &rgba(red));
+        assert_vec(&vertices[0].color, &rgba(blue));
+
+        // Conic gradient is a fan of separate triangles around its center.
+        let gradient = Gradient::conic(0.5, 0.0).stop(0.0, red).stop(1.0, blue);
+        let stream = render(gradient_box(gradient.clone()), &mut ());
+        let vertices = stream.vertices();
+        assert_eq!(vertices.len() % 3, 0);
+        assert!(vertices.len() / 3 >= 64);
+        assert_eq!(stream.triangles().len(), vertices.len() / 3);
+        for triangle in vertices.chunks(3) {
+            assert_eq!(triangle[0].position, [5.0, 5.0]);
+            for vertex in &triangle[1..] {
+                let [x, y] = vertex.position;
+                assert!(
+                    [x, y, 10.0 - x, 10.0 - y]
+                        .iter()
+                        .any(|distance| distance.abs() < 1.0e-3),
+                    "{:?} is not on box edge",
+                    vertex.position
+                );
+            }
+            let from = gradient.factor(Vec2 {
+                x: triangle[1].position[0] / 10.0,
+                y: triangle[1].position[1] / 10.0,
+            });
+            assert_vec(&triangle[1].color, &rgba(gradient.sample(from)));
+        }
+        // Seam between last and first stop stays sharp.
+        assert_vec(&vertices[1].position, &[10.0, 5.0]);
+        assert_vec(&vertices[1].color, &rgba(red));
+        assert_vec(&vertices[vertices.len() - 1].position, &[10.0, 5.0]);
+        assert_vec(&vertices[vertices.len() - 1].color, &rgba(blue));
+    }
+
+    /// Image quads as pairs of `[left, top, right, bottom]` positions and tex coords.
+    fn image_quads(stream: &VertexStream<TestVertex, TesselateBatch>) -> Vec<[[f32; 4]; 2]> {
+        stream
+            .vertices()
+            .chunks(4)
+            .map(|quad| {
+                assert_eq!(quad[1].position, [quad[2].position[0], quad[0].position[1]]);
+                assert_eq!(quad[3].position, [quad[0].position[0], quad[2].position[1]]);
+                assert!(quad.iter().all(|vertex| vertex.color == [1.0; 4]));
+                assert!(quad.iter().all(|vertex| vertex.tex_coord[2] == 0.0));
+                [
+                    [
+                        quad[0].position[0],
+                        quad[0].position[1],
+                        quad[2].position[0],
+                        quad[2].position[1],
+                    ],
+                    [
+                        quad[0].tex_coord[0],
+                        quad[0].tex_coord[1],
+                        quad[2].tex_coord[0],
+                        quad[2].tex_coord[1],
+                    ],
+                ]
+            })
+            .collect()
+    }
+
+    /// Expected quads for every row and column `(from, to, uv_from, uv_to)` span.
+    fn span_quads(columns: &[[f32; 4]], rows: &[[f32; 4]]) -> Vec<[[f32; 4]; 2]> {
+        rows.iter()
+            .flat_map(|[top, bottom, v_top, v_bottom]| {
+                columns.iter().map(move |[left, right, u_left, u_right]| {
+                    [
+                        [*left, *top, *right, *bottom],
+                        [*u_left, *v_top, *u_right, *v_bottom],
+                    ]
+                })
+            })
+            .collect()
+    }
+
+    fn assert_quads(value: &[[[f32; 4]; 2]], expected: &[[[f32; 4]; 2]]) {
+        assert_eq!(value.len(), expected.len(), "{value:?} != {expected:?}");
+        for (value, expected) in value.iter().zip(expected) {
+            assert_vec(value.as_flattened(), expected.as_flattened());
+        }
+    }
+
+    #[test]
+    fn test_image_spans() {
+        let image_box = |scaling: ImageBoxImageScaling| {
+            make_widget!(image_box).with_props(ImageBoxProps {
+                material: ImageBoxMaterial::Image(ImageBoxImage {
+                    id: "image".to_owned(),
+                    scaling,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+        };
+
+        // Half-sized tiles shifted by half a tile get cut at both box edges.
+        let stream = render(
+            image_box(ImageBoxImageScaling::Tile(ImageBoxTile {
+                scale: 0.5.into(),
+                offset: Vec2 { x: 2.5, y: 0.0 },
+            })),
+            &mut (),
+        );
+        assert_eq!(
+            batches(&stream),
+            vec![TesselateBatch::Image {
+                id: "image".to_owned(),
+            }]
+        );
+        assert_eq!(stream.triangles().len(), 6 * 2);
+        assert_quads(
+            &image_quads(&stream),
+            &span_quads(
+                &[
+                    [0.0, 2.5, 0.5, 1.0],
+                    [2.5, 7.5, 0.0, 1.0],
+                    [7.5, 10.0, 0.0, 0.5],
+                ],
+                &[[0.0, 5.0, 0.0, 1.0], [5.0, 10.0, 0.0, 1.0]],
+            ),
+        );
+
+        // Frame edges are drawn at half size, so middle part (6 units wide at that scale)
+        // repeats 3 times over remaining 8 units, stretched to avoid cutting any of them.
+        let frame = ImageBoxFrame {
+            source: 2.0.into(),
+            destination: 1.0.into(),
+            ..Default::default()
+        };
+        let third = 8.0 / 3.0;
+        let spans = [
+            [0.0, 1.0, 0.0, 0.2],
+            [1.0, 1.0 + third, 0.2, 0.8],
+            [1.0 + third, 1.0 + third * 2.0, 0.2, 0.8],
+            [1.0 + third * 2.0, 9.0, 0.2, 0.8],
+            [9.0, 10.0, 0.8, 1.0],
+        ];
+        let stream = render(
+            image_box(ImageBoxImageScaling::FrameRepeat(frame.clone())),
+            &mut (),
+        );
+        assert_quads(&image_quads(&stream), &span_quads(&spans, &spans));
+
+        let stream = render(
+            image_box(ImageBoxImageScaling::FrameRepeat(ImageBoxFrame {
+                frame_only: true,
+                ..frame
+            })),
+            &mut (),
+        );
+        let expected = span_quads(&spans, &spans)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                let (column, row) = (index % 5, index / 5);
+                column == 0 || column == 4 || row == 0 || row == 4
+            })
+            .map(|(_, quad)| quad)
+            .collect::<Vec<_>>();
+        assert_eq!(expected.len(), 16);
+        assert_quads(&image_quads(&stream), &expected);
+    }
+
+    #[test]
+    fn test_flipbook_frame() {
+        let flipbook_box = |scaling: ImageBoxImageScaling| {
+            make_widget!(image_box).with_props(ImageBoxProps::flipbook(ImageBoxFlipbook {
+                frames: ImageBoxFlipbookFrames::Grid {
+                    id: "sheet".to_owned(),
+                    columns: 2,
+                    rows: 2,
+                    count: None,
+                },
+                fps: 0.0,
+                frame: 3,
+                scaling,
+                ..Default::default()
+            }))
+        };
+
+        let stream = render(flipbook_box(ImageBoxImageScaling::Stretch), &mut ());
+        assert_eq!(
+            batches(&stream),
+            vec![TesselateBatch::Image {
+                id: "sheet".to_owned(),
+            }]
+        );
+        assert_quads(
+            &image_quads(&stream),
+            &[[[0.0, 0.0, 10.0, 10.0], [0.5, 0.5, 1.0, 1.0]]],
+        );
+
+        // Frame is 5x5 part of 10x10 sheet, so keeping frame aspect ratio on 10x10 box
+        // draws its 1 pixel edges twice as big, as does repeating its middle part.
+        let stream = render(
+            flipbook_box(ImageBoxImageScaling::FrameRepeat(ImageBoxFrame {
+                source: 1.0.into(),
+                frame_keep_aspect_ratio: true,
+                ..Default::default()
+            })),
+            &mut (),
+        );
+        let spans = [
+            [0.0, 2.0, 0.5, 0.6],
+            [2.0, 8.0, 0.6, 0.9],
+            [8.0, 10.0, 0.9, 1.0],
+        ];
+        assert_quads(&image_quads(&stream), &span_quads(&spans, &spans));
+    }
+}
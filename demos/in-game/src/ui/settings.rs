@@ -116,6 +116,7 @@ pub fn settings(mut context: WidgetContext) -> WidgetNode {
                                     from: 0.0,
                                     to: 100.0,
                                     direction: SliderViewDirection::LeftToRight,
+                                    ..Default::default()
                                 })
                                 .with_props(NumericSliderPaperProps {
                                     fractional_digits_count: Some(0),